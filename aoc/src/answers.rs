@@ -0,0 +1,126 @@
+use anyhow::{Context, Result};
+use aoc_common::Answer;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use std::fs;
+
+#[derive(Deserialize)]
+struct AnswersFile {
+    #[serde(default)]
+    answer: Vec<RawEntry>,
+}
+
+#[derive(Deserialize)]
+struct RawEntry {
+    year: u32,
+    day: u32,
+    input: String,
+    part_one: Option<toml::Value>,
+    part_two: Option<toml::Value>,
+}
+
+/// The known answers for one input to one day's puzzle.
+pub struct Entry {
+    year: u32,
+    day: u32,
+    input: String,
+    pub part_one: Option<Answer>,
+    pub part_two: Option<Answer>,
+}
+
+/// Known answers for every input, loaded from a TOML file with one
+/// `[[answer]]` table per year, day and input hash.
+pub struct Answers {
+    entries: Vec<Entry>,
+}
+
+impl Answers {
+    pub fn load(path: &str) -> Result<Self> {
+        let contents =
+            fs::read_to_string(path).with_context(|| format!("Failed to read {path}"))?;
+        Self::parse(&contents).with_context(|| format!("Failed to parse {path}"))
+    }
+
+    fn parse(contents: &str) -> Result<Self> {
+        let file: AnswersFile = toml::from_str(contents)?;
+        let entries = file
+            .answer
+            .into_iter()
+            .map(|entry| {
+                Ok(Entry {
+                    year: entry.year,
+                    day: entry.day,
+                    input: entry.input,
+                    part_one: entry.part_one.map(Answer::try_from).transpose()?,
+                    part_two: entry.part_two.map(Answer::try_from).transpose()?,
+                })
+            })
+            .collect::<Result<_>>()?;
+        Ok(Self { entries })
+    }
+
+    pub fn get(&self, year: u32, day: u32, input: &str) -> Option<&Entry> {
+        let input_hash = hash_input(input);
+        self.entries
+            .iter()
+            .find(|entry| entry.year == year && entry.day == day && entry.input == input_hash)
+    }
+}
+
+/// The hex encoded SHA-256 hash of an input, used to tell inputs apart
+/// without storing them in the answers file.
+pub fn hash_input(input: &str) -> String {
+    Sha256::digest(input)
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_get() {
+        let input = "mjqjpqmgbljsphdztnvjfqwrcgsmlb\n";
+        let contents = format!(
+            r#"
+            [[answer]]
+            year = 2022
+            day = 5
+            input = "{0}"
+            part_one = "CMZ"
+
+            [[answer]]
+            year = 2022
+            day = 6
+            input = "{0}"
+            part_one = 7
+            part_two = 19
+            "#,
+            hash_input(input)
+        );
+        let answers = Answers::parse(&contents).unwrap();
+        let entry = answers.get(2022, 6, input).unwrap();
+        assert_eq!(entry.part_one, Some(Answer::Integer(7)));
+        assert_eq!(entry.part_two, Some(Answer::Integer(19)));
+        let entry = answers.get(2022, 5, input).unwrap();
+        assert_eq!(entry.part_one, Some(Answer::from("CMZ")));
+        assert_eq!(entry.part_two, None);
+        assert!(answers
+            .get(2022, 6, "bvwbjplbgvbhsrlpgdmjqwftvncz\n")
+            .is_none());
+    }
+
+    #[test]
+    fn test_parse_invalid_answer() {
+        let contents = r#"
+            [[answer]]
+            year = 2022
+            day = 1
+            input = "0"
+            part_one = 1.5
+        "#;
+        assert!(Answers::parse(contents).is_err());
+    }
+}
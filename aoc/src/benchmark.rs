@@ -0,0 +1,183 @@
+mod history;
+mod summary;
+
+use crate::registry::Solution;
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::SystemTime;
+use std::{env, fs};
+
+pub use history::Regression;
+pub use summary::describe;
+
+use history::{find_regressions, History};
+use summary::write_summaries;
+
+/// The root of the workspace. Benchmarks are run, read and recorded relative
+/// to it, so `aoc bench` works whichever directory it is run from.
+const WORKSPACE_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+const BENCHMARKS_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../benchmarks");
+
+/// The mean time of one criterion benchmark, such as
+/// `year_2022_day_16::part_two`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Measurement {
+    pub year: u32,
+    pub day: u32,
+    pub name: String,
+    pub nanoseconds: f64,
+}
+
+/// Every measurement taken at one commit.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BenchmarkRun {
+    pub commit: String,
+    pub measurements: Vec<Measurement>,
+}
+
+/// Runs the criterion benchmarks of every solution and collects the results.
+pub fn run(solutions: &[&Solution]) -> Result<BenchmarkRun> {
+    let start = SystemTime::now();
+    let mut command = Command::new(env::var("CARGO").unwrap_or_else(|_| String::from("cargo")));
+    command.arg("bench").current_dir(WORKSPACE_PATH);
+    for solution in solutions {
+        let crate_name = crate_name(solution);
+        command.args(["-p", &crate_name, "--bench", &crate_name]);
+    }
+    let status = command.status().context("Failed to run cargo bench")?;
+    if !status.success() {
+        bail!("cargo bench failed with {status}");
+    }
+    let measurements = collect(&criterion_path(), solutions, start)?;
+    Ok(BenchmarkRun {
+        commit: current_commit()?,
+        measurements,
+    })
+}
+
+/// Adds the run to the history, merging it with any earlier run from the
+/// same commit, and writes the per-year summaries of the merged run. Returns
+/// the benchmarks of this run that got slower by more than `threshold`, a
+/// fraction of the time in the last stored run from a different commit.
+pub fn record(run: &BenchmarkRun, threshold: f64) -> Result<Vec<Regression>> {
+    let benchmarks_path = Path::new(BENCHMARKS_PATH);
+    fs::create_dir_all(benchmarks_path)?;
+    let history_path = benchmarks_path.join("history.json");
+    let mut history = History::load(&history_path)?;
+    history.insert(run.clone());
+    let merged_run = history.latest().context("The history has no runs")?;
+    let (regressions, merged_regressions) = match history.baseline(&run.commit) {
+        Some(baseline) => (
+            find_regressions(baseline, run, threshold),
+            find_regressions(baseline, merged_run, threshold),
+        ),
+        None => (vec![], vec![]),
+    };
+    write_summaries(benchmarks_path, merged_run, &merged_regressions)?;
+    history.save(&history_path)?;
+    Ok(regressions)
+}
+
+fn crate_name(solution: &Solution) -> String {
+    format!("year_{}_day_{:02}", solution.year, solution.day)
+}
+
+fn criterion_path() -> PathBuf {
+    let target_path = env::var_os("CARGO_TARGET_DIR")
+        .map_or_else(|| Path::new(WORKSPACE_PATH).join("target"), PathBuf::from);
+    target_path.join("criterion")
+}
+
+#[derive(Deserialize)]
+struct BenchmarkId {
+    full_id: String,
+}
+
+#[derive(Deserialize)]
+struct Estimates {
+    mean: Estimate,
+}
+
+#[derive(Deserialize)]
+struct Estimate {
+    point_estimate: f64,
+}
+
+/// Reads the results criterion wrote after `start` for the benchmarks of the
+/// given solutions. Older results are skipped so that benchmarks which have
+/// since been renamed or removed don't show up.
+fn collect(
+    criterion_path: &Path,
+    solutions: &[&Solution],
+    start: SystemTime,
+) -> Result<Vec<Measurement>> {
+    let mut measurements = vec![];
+    let entries = fs::read_dir(criterion_path)
+        .with_context(|| format!("Failed to read {}", criterion_path.display()))?;
+    for entry in entries {
+        let new_path = entry?.path().join("new");
+        let estimates_path = new_path.join("estimates.json");
+        let Ok(metadata) = fs::metadata(&estimates_path) else {
+            continue;
+        };
+        if metadata.modified()? < start {
+            continue;
+        }
+        let id: BenchmarkId = read_json(&new_path.join("benchmark.json"))?;
+        let Some((crate_name, name)) = id.full_id.split_once("::") else {
+            continue;
+        };
+        let Some(solution) = solutions
+            .iter()
+            .find(|solution| self::crate_name(solution) == crate_name)
+        else {
+            continue;
+        };
+        let estimates: Estimates = read_json(&estimates_path)?;
+        measurements.push(Measurement {
+            year: solution.year,
+            day: solution.day,
+            name: name.to_owned(),
+            nanoseconds: estimates.mean.point_estimate,
+        });
+    }
+    sort_measurements(&mut measurements);
+    Ok(measurements)
+}
+
+fn sort_measurements(measurements: &mut [Measurement]) {
+    measurements.sort_by(|a, b| (a.year, a.day, &a.name).cmp(&(b.year, b.day, &b.name)));
+}
+
+fn read_json<T: for<'de> Deserialize<'de>>(path: &Path) -> Result<T> {
+    let contents =
+        fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    serde_json::from_str(&contents).with_context(|| format!("Failed to parse {}", path.display()))
+}
+
+/// The abbreviated hash of `HEAD`, suffixed with `-dirty` if the working tree
+/// has uncommitted changes.
+fn current_commit() -> Result<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .current_dir(WORKSPACE_PATH)
+        .output()
+        .context("Failed to run git")?;
+    if !output.status.success() {
+        bail!("Failed to find the current commit");
+    }
+    let mut commit = String::from_utf8(output.stdout)?.trim().to_owned();
+    let is_clean = Command::new("git")
+        .args(["diff", "--quiet", "HEAD"])
+        .current_dir(WORKSPACE_PATH)
+        .status()
+        .context("Failed to run git")?
+        .success();
+    if !is_clean {
+        commit.push_str("-dirty");
+    }
+    Ok(commit)
+}
@@ -0,0 +1,203 @@
+use super::{read_json, sort_measurements, BenchmarkRun, Measurement};
+use anyhow::{Context, Result};
+use std::fs;
+use std::path::Path;
+
+/// Every stored benchmark run, oldest first, with at most one run per
+/// commit.
+pub struct History {
+    runs: Vec<BenchmarkRun>,
+}
+
+impl History {
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self { runs: vec![] });
+        }
+        Ok(Self {
+            runs: read_json(path)?,
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let contents = serde_json::to_string_pretty(&self.runs)?;
+        fs::write(path, contents + "\n")
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    /// The most recent run from a commit other than `commit`.
+    pub fn baseline(&self, commit: &str) -> Option<&BenchmarkRun> {
+        self.runs.iter().rev().find(|run| run.commit != commit)
+    }
+
+    /// The most recently inserted run.
+    pub fn latest(&self) -> Option<&BenchmarkRun> {
+        self.runs.last()
+    }
+
+    /// Adds a run as the latest one. An earlier run from the same commit is
+    /// merged into it, so benchmarking a single day keeps the results of the
+    /// other days, and only the benchmarks measured again are replaced.
+    pub fn insert(&mut self, mut run: BenchmarkRun) {
+        if let Some(index) = self
+            .runs
+            .iter()
+            .position(|stored_run| stored_run.commit == run.commit)
+        {
+            let mut measurements = self.runs.remove(index).measurements;
+            measurements.retain(|stored| {
+                !run.measurements
+                    .iter()
+                    .any(|measurement| same_benchmark(stored, measurement))
+            });
+            run.measurements.extend(measurements);
+            sort_measurements(&mut run.measurements);
+        }
+        self.runs.push(run);
+    }
+}
+
+/// A benchmark that got slower since the baseline run.
+#[derive(Debug, PartialEq)]
+pub struct Regression {
+    pub year: u32,
+    pub day: u32,
+    pub name: String,
+    pub baseline_nanoseconds: f64,
+    pub nanoseconds: f64,
+}
+
+impl Regression {
+    /// How much slower the benchmark got, as a fraction of the baseline time.
+    pub fn change(&self) -> f64 {
+        self.nanoseconds / self.baseline_nanoseconds - 1.0
+    }
+}
+
+/// Finds the benchmarks in `run` that are slower than in `baseline` by more
+/// than `threshold`, a fraction of the baseline time. Benchmarks that only
+/// appear in one of the runs are ignored.
+pub fn find_regressions(
+    baseline: &BenchmarkRun,
+    run: &BenchmarkRun,
+    threshold: f64,
+) -> Vec<Regression> {
+    run.measurements
+        .iter()
+        .filter_map(|measurement| {
+            let baseline_measurement = baseline
+                .measurements
+                .iter()
+                .find(|m| same_benchmark(m, measurement))?;
+            let regression = Regression {
+                year: measurement.year,
+                day: measurement.day,
+                name: measurement.name.clone(),
+                baseline_nanoseconds: baseline_measurement.nanoseconds,
+                nanoseconds: measurement.nanoseconds,
+            };
+            (regression.change() > threshold).then_some(regression)
+        })
+        .collect()
+}
+
+fn same_benchmark(a: &Measurement, b: &Measurement) -> bool {
+    (a.year, a.day, &a.name) == (b.year, b.day, &b.name)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn measurement(day: u32, name: &str, nanoseconds: f64) -> Measurement {
+        Measurement {
+            year: 2022,
+            day,
+            name: name.to_owned(),
+            nanoseconds,
+        }
+    }
+
+    fn run(commit: &str, measurements: Vec<Measurement>) -> BenchmarkRun {
+        BenchmarkRun {
+            commit: commit.to_owned(),
+            measurements,
+        }
+    }
+
+    #[test]
+    fn test_baseline() {
+        let mut history = History { runs: vec![] };
+        assert_eq!(history.baseline("b"), None);
+        history.insert(run("a", vec![measurement(1, "solve", 100.0)]));
+        history.insert(run("b", vec![measurement(1, "solve", 200.0)]));
+        history.insert(run("b", vec![measurement(1, "solve", 300.0)]));
+        assert_eq!(history.runs.len(), 2);
+        assert_eq!(history.latest().unwrap().commit, "b");
+        assert_eq!(history.baseline("b").unwrap().commit, "a");
+        assert_eq!(history.baseline("c").unwrap().commit, "b");
+        assert_eq!(
+            history.baseline("c").unwrap().measurements[0].nanoseconds,
+            300.0
+        );
+    }
+
+    #[test]
+    fn test_insert_merges_runs_from_the_same_commit() {
+        let mut history = History { runs: vec![] };
+        history.insert(run(
+            "a",
+            vec![
+                measurement(1, "solve", 100.0),
+                measurement(8, "part_one", 200.0),
+            ],
+        ));
+        history.insert(run("b", vec![measurement(1, "solve", 110.0)]));
+        history.insert(run("a", vec![measurement(8, "part_one", 150.0)]));
+        assert_eq!(history.runs.len(), 2);
+        assert_eq!(
+            history.latest().unwrap(),
+            &run(
+                "a",
+                vec![
+                    measurement(1, "solve", 100.0),
+                    measurement(8, "part_one", 150.0)
+                ],
+            )
+        );
+        assert_eq!(history.baseline("a").unwrap().commit, "b");
+    }
+
+    #[test]
+    fn test_find_regressions() {
+        let baseline = run(
+            "a",
+            vec![
+                measurement(16, "parser::parse", 40_000.0),
+                measurement(16, "part_one", 2_000_000.0),
+                measurement(16, "part_two", 12_000_000.0),
+            ],
+        );
+        let current = run(
+            "b",
+            vec![
+                measurement(16, "parser::parse", 43_000.0),
+                measurement(16, "part_one", 1_000_000.0),
+                measurement(16, "part_two", 15_000_000.0),
+                measurement(17, "part_one", 800_000.0),
+            ],
+        );
+        let regressions = find_regressions(&baseline, &current, 0.1);
+        assert_eq!(
+            regressions,
+            [Regression {
+                year: 2022,
+                day: 16,
+                name: String::from("part_two"),
+                baseline_nanoseconds: 12_000_000.0,
+                nanoseconds: 15_000_000.0,
+            }]
+        );
+        assert_eq!(regressions[0].change(), 0.25);
+    }
+}
@@ -0,0 +1,218 @@
+use super::{BenchmarkRun, Measurement, Regression};
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Stage {
+    Parse,
+    Preprocess,
+    PartOne,
+    PartTwo,
+}
+
+impl Stage {
+    /// Classifies a benchmark by its function name. Days that solve both
+    /// parts together count towards part one, like the runner's table, and
+    /// any other step that isn't parsing counts as preprocessing.
+    fn of(name: &str) -> Self {
+        match name {
+            "part_one" | "solve" => Stage::PartOne,
+            "part_two" => Stage::PartTwo,
+            name if name.contains("parse") => Stage::Parse,
+            _ => Stage::Preprocess,
+        }
+    }
+}
+
+/// The stage timings of one day, in nanoseconds.
+#[derive(Debug, PartialEq, Serialize)]
+struct DaySummary {
+    day: u32,
+    parse_nanoseconds: Option<f64>,
+    preprocess_nanoseconds: Option<f64>,
+    part_one_nanoseconds: Option<f64>,
+    part_two_nanoseconds: Option<f64>,
+    total_nanoseconds: f64,
+    regressions: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct YearSummary<'a> {
+    year: u32,
+    commit: &'a str,
+    days: Vec<DaySummary>,
+}
+
+/// Writes `<year>.md` and `<year>.json` into `path` for every year in the
+/// run.
+pub fn write_summaries(path: &Path, run: &BenchmarkRun, regressions: &[Regression]) -> Result<()> {
+    let mut years = BTreeMap::<u32, Vec<&Measurement>>::new();
+    for measurement in &run.measurements {
+        years.entry(measurement.year).or_default().push(measurement);
+    }
+    for (year, measurements) in years {
+        let year_regressions = regressions
+            .iter()
+            .filter(|regression| regression.year == year)
+            .collect::<Vec<_>>();
+        let summary = YearSummary {
+            year,
+            commit: &run.commit,
+            days: summarize_days(&measurements, &year_regressions),
+        };
+        let markdown = render_markdown(&summary, &year_regressions);
+        let json = serde_json::to_string_pretty(&summary)? + "\n";
+        for (extension, contents) in [("md", markdown), ("json", json)] {
+            let file_path = path.join(format!("{year}.{extension}"));
+            fs::write(&file_path, contents)
+                .with_context(|| format!("Failed to write {}", file_path.display()))?;
+        }
+    }
+    Ok(())
+}
+
+fn summarize_days(measurements: &[&Measurement], regressions: &[&Regression]) -> Vec<DaySummary> {
+    let mut days = BTreeMap::<u32, DaySummary>::new();
+    for measurement in measurements {
+        let summary = days.entry(measurement.day).or_insert_with(|| DaySummary {
+            day: measurement.day,
+            parse_nanoseconds: None,
+            preprocess_nanoseconds: None,
+            part_one_nanoseconds: None,
+            part_two_nanoseconds: None,
+            total_nanoseconds: 0.0,
+            regressions: vec![],
+        });
+        let stage_nanoseconds = match Stage::of(&measurement.name) {
+            Stage::Parse => &mut summary.parse_nanoseconds,
+            Stage::Preprocess => &mut summary.preprocess_nanoseconds,
+            Stage::PartOne => &mut summary.part_one_nanoseconds,
+            Stage::PartTwo => &mut summary.part_two_nanoseconds,
+        };
+        *stage_nanoseconds.get_or_insert(0.0) += measurement.nanoseconds;
+        summary.total_nanoseconds += measurement.nanoseconds;
+    }
+    for regression in regressions {
+        if let Some(summary) = days.get_mut(&regression.day) {
+            summary.regressions.push(regression.name.clone());
+        }
+    }
+    days.into_values().collect()
+}
+
+fn render_markdown(summary: &YearSummary, regressions: &[&Regression]) -> String {
+    let mut output = String::new();
+    writeln!(output, "# {} benchmarks\n", summary.year).unwrap();
+    writeln!(output, "Mean times at commit `{}`.\n", summary.commit).unwrap();
+    writeln!(
+        output,
+        "| Day | Parse | Preprocess | Part one | Part two | Total |"
+    )
+    .unwrap();
+    writeln!(output, "| --- | ---: | ---: | ---: | ---: | ---: |").unwrap();
+    for day in &summary.days {
+        let marker = if day.regressions.is_empty() {
+            ""
+        } else {
+            " (!)"
+        };
+        writeln!(
+            output,
+            "| {:02}{marker} | {} | {} | {} | {} | {} |",
+            day.day,
+            time_cell(day.parse_nanoseconds),
+            time_cell(day.preprocess_nanoseconds),
+            time_cell(day.part_one_nanoseconds),
+            time_cell(day.part_two_nanoseconds),
+            time_cell(Some(day.total_nanoseconds)),
+        )
+        .unwrap();
+    }
+    if !regressions.is_empty() {
+        writeln!(output, "\n## Regressions\n").unwrap();
+        for regression in regressions {
+            writeln!(output, "- {}", describe(regression)).unwrap();
+        }
+    }
+    output
+}
+
+/// Describes a regression on one line, such as
+/// `2022 day 16 part_two: 12.00ms -> 15.00ms (+25.0%)`.
+pub fn describe(regression: &Regression) -> String {
+    format!(
+        "{} day {:02} {}: {} -> {} ({:+.1}%)",
+        regression.year,
+        regression.day,
+        regression.name,
+        time_cell(Some(regression.baseline_nanoseconds)),
+        time_cell(Some(regression.nanoseconds)),
+        regression.change() * 100.0
+    )
+}
+
+fn time_cell(nanoseconds: Option<f64>) -> String {
+    nanoseconds.map_or_else(
+        || String::from("-"),
+        |nanoseconds| format!("{:.2?}", Duration::from_secs_f64(nanoseconds / 1e9)),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_summarize_days() {
+        let measurements = [
+            ("parser::parse", 40.0),
+            ("preprocess", 300.0),
+            ("part_one", 2_000.0),
+            ("part_two", 12_000.0),
+        ]
+        .map(|(name, nanoseconds)| Measurement {
+            year: 2022,
+            day: 16,
+            name: name.to_owned(),
+            nanoseconds,
+        });
+        let regression = Regression {
+            year: 2022,
+            day: 16,
+            name: String::from("part_two"),
+            baseline_nanoseconds: 10_000.0,
+            nanoseconds: 12_000.0,
+        };
+        let summaries = summarize_days(&measurements.iter().collect::<Vec<_>>(), &[&regression]);
+        assert_eq!(
+            summaries,
+            [DaySummary {
+                day: 16,
+                parse_nanoseconds: Some(40.0),
+                preprocess_nanoseconds: Some(300.0),
+                part_one_nanoseconds: Some(2_000.0),
+                part_two_nanoseconds: Some(12_000.0),
+                total_nanoseconds: 14_340.0,
+                regressions: vec![String::from("part_two")],
+            }]
+        );
+        assert_eq!(
+            describe(&regression),
+            "2022 day 16 part_two: 10.00µs -> 12.00µs (+20.0%)"
+        );
+    }
+
+    #[test]
+    fn test_stage_of() {
+        assert_eq!(Stage::of("parse_input"), Stage::Parse);
+        assert_eq!(Stage::of("parser::parse"), Stage::Parse);
+        assert_eq!(Stage::of("find_pipe_tiles"), Stage::Preprocess);
+        assert_eq!(Stage::of("solve"), Stage::PartOne);
+        assert_eq!(Stage::of("part_two"), Stage::PartTwo);
+    }
+}
@@ -0,0 +1,196 @@
+mod answers;
+mod benchmark;
+mod registry;
+mod run;
+mod table;
+mod verify;
+
+use answers::Answers;
+use anyhow::{anyhow, bail, ensure, Context, Result};
+use aoc_common::read_input;
+use registry::{Solution, SOLUTIONS};
+use run::{Part, Run};
+use std::{env, process};
+use verify::Status;
+
+/// The answers live at the root of the workspace, so `aoc verify` finds them
+/// whichever directory it is run from.
+const ANSWERS_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.toml");
+
+/// The default slowdown, in percent, beyond which `aoc bench` reports a
+/// benchmark as regressed.
+const DEFAULT_THRESHOLD: f64 = 10.0;
+
+const USAGE: &str = "\
+Usage:
+  aoc run <year> [<day> [all|parse|one|two] [--input <path>|-]]
+  aoc run --all
+  aoc verify <year> [<day> [all|one|two] [--input <path>|-]]
+  aoc verify --all
+  aoc bench <year> [<day>] [--threshold <percent>]
+  aoc bench --all [--threshold <percent>]";
+
+enum Command {
+    Run,
+    Verify,
+    Bench { threshold: f64 },
+}
+
+enum Selection {
+    All,
+    Year(u32),
+    Day(u32, u32, Part, Option<String>),
+}
+
+fn main() -> Result<()> {
+    let (command, selection) = read_args()?;
+    let solutions = select(&selection)?;
+    if let Command::Bench { threshold } = command {
+        return bench(&solutions, threshold);
+    }
+    let (part, input) = match selection {
+        Selection::Day(_, _, part, Some(path)) => (part, Some(read_input(&path)?)),
+        Selection::Day(_, _, part, None) => (part, None),
+        _ => (Part::All, None),
+    };
+    let results = solutions
+        .into_iter()
+        .map(|solution| {
+            let input = input.as_deref().unwrap_or(solution.input);
+            let run = solution.run(input, part).with_context(|| {
+                format!("Failed to parse {} day {}", solution.year, solution.day)
+            })?;
+            Ok((solution, input, run))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    match command {
+        Command::Run => {
+            let results = results
+                .into_iter()
+                .map(|(solution, _, run)| (solution, run))
+                .collect::<Vec<_>>();
+            print!("{}", table::render(&results));
+        }
+        Command::Verify => verify(results)?,
+        Command::Bench { .. } => unreachable!(),
+    }
+    Ok(())
+}
+
+fn verify(results: Vec<(&Solution, &str, Run)>) -> Result<()> {
+    let answers = Answers::load(ANSWERS_PATH)?;
+    let results = results
+        .into_iter()
+        .map(|(solution, input, run)| {
+            let entry = answers.get(solution.year, solution.day, input);
+            (solution, run, entry)
+        })
+        .collect::<Vec<_>>();
+    let outcomes = verify::check(&results);
+    print!("{}", verify::render(&outcomes));
+    if outcomes
+        .iter()
+        .any(|outcome| matches!(outcome.status, Status::Fail { .. }))
+    {
+        process::exit(1);
+    }
+    Ok(())
+}
+
+fn bench(solutions: &[&Solution], threshold: f64) -> Result<()> {
+    let run = benchmark::run(solutions)?;
+    let regressions = benchmark::record(&run, threshold / 100.0)?;
+    if regressions.is_empty() {
+        println!("No benchmarks regressed by more than {threshold}%");
+        return Ok(());
+    }
+    println!("Benchmarks that regressed by more than {threshold}%:");
+    for regression in &regressions {
+        println!("  {}", benchmark::describe(regression));
+    }
+    process::exit(1);
+}
+
+fn read_args() -> Result<(Command, Selection)> {
+    let mut args = env::args().skip(1);
+    let mut command = match args.next().as_deref() {
+        Some("run") => Command::Run,
+        Some("verify") => Command::Verify,
+        Some("bench") => Command::Bench {
+            threshold: DEFAULT_THRESHOLD,
+        },
+        Some(command) => bail!("Unknown command {command}\n{USAGE}"),
+        None => bail!("No command provided\n{USAGE}"),
+    };
+    let mut positional = vec![];
+    let mut input_path = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" if !matches!(command, Command::Bench { .. }) => {
+                let path = args
+                    .next()
+                    .ok_or_else(|| anyhow!("No input path provided\n{USAGE}"))?;
+                input_path = Some(path);
+            }
+            "--threshold" => {
+                let Command::Bench { threshold } = &mut command else {
+                    bail!("--threshold can only be used with bench\n{USAGE}");
+                };
+                *threshold = args
+                    .next()
+                    .ok_or_else(|| anyhow!("No threshold provided\n{USAGE}"))?
+                    .parse()
+                    .context("Threshold must be a number")?;
+            }
+            _ => positional.push(arg),
+        }
+    }
+    let mut positional = positional.into_iter();
+    let year_str = positional
+        .next()
+        .ok_or_else(|| anyhow!("No year provided\n{USAGE}"))?;
+    if year_str == "--all" {
+        return Ok((command, Selection::All));
+    }
+    let mut year = year_str.parse().context("Year must be a number")?;
+    if year < 100 {
+        year += 2000;
+    }
+    let Some(day_str) = positional.next() else {
+        ensure!(input_path.is_none(), "--input can only be used with a day");
+        return Ok((command, Selection::Year(year)));
+    };
+    let day = day_str.parse().context("Day must be a number")?;
+    ensure!((1..=25).contains(&day), "Day must be between 1 and 25");
+    let part = match positional.next() {
+        Some(part_str) if !matches!(command, Command::Bench { .. }) => Part::from_arg(&part_str)
+            .ok_or_else(|| anyhow!("Part must be one of all, parse, one, or two"))?,
+        Some(arg) => bail!("Unexpected argument {arg}\n{USAGE}"),
+        None => Part::All,
+    };
+    if let Some(arg) = positional.next() {
+        bail!("Unexpected argument {arg}\n{USAGE}");
+    }
+    Ok((command, Selection::Day(year, day, part, input_path)))
+}
+
+fn select(selection: &Selection) -> Result<Vec<&'static Solution>> {
+    let solutions = SOLUTIONS
+        .iter()
+        .filter(|solution| match *selection {
+            Selection::All => true,
+            Selection::Year(year) => solution.year == year,
+            Selection::Day(year, day, _, _) => solution.year == year && solution.day == day,
+        })
+        .collect::<Vec<_>>();
+    if solutions.is_empty() {
+        match *selection {
+            Selection::All => bail!("No solutions are registered"),
+            Selection::Year(year) => bail!("No solutions are registered for {year}"),
+            Selection::Day(year, day, _, _) => {
+                bail!("No solution is registered for {year} day {day}")
+            }
+        }
+    }
+    Ok(solutions)
+}
@@ -0,0 +1,65 @@
+use crate::run::{self, Part, Run};
+use aoc_common::Result;
+
+pub struct Solution {
+    pub year: u32,
+    pub day: u32,
+    /// The input embedded in the day's crate.
+    pub input: &'static str,
+    run: fn(&str, Part) -> Result<Run>,
+}
+
+impl Solution {
+    const fn new<S: aoc_common::Solution>(input: &'static str) -> Self {
+        Self {
+            year: S::YEAR,
+            day: S::DAY,
+            input,
+            run: run::run::<S>,
+        }
+    }
+
+    pub fn run(&self, input: &str, part: Part) -> Result<Run> {
+        (self.run)(input, part)
+    }
+}
+
+/// Every solution the runner knows about, ordered by year and day.
+pub const SOLUTIONS: &[Solution] = &[
+    Solution::new::<year_2022_day_01::Day01>(year_2022_day_01::INPUT),
+    Solution::new::<year_2022_day_02::Day02>(year_2022_day_02::INPUT),
+    Solution::new::<year_2022_day_03::Day03>(year_2022_day_03::INPUT),
+    Solution::new::<year_2022_day_04::Day04>(year_2022_day_04::INPUT),
+    Solution::new::<year_2022_day_05::Day05>(year_2022_day_05::INPUT),
+    Solution::new::<year_2022_day_06::Day06>(year_2022_day_06::INPUT),
+    Solution::new::<year_2022_day_07::Day07>(year_2022_day_07::INPUT),
+    Solution::new::<year_2022_day_08::Day08>(year_2022_day_08::INPUT),
+    Solution::new::<year_2022_day_09::Day09>(year_2022_day_09::INPUT),
+    Solution::new::<year_2022_day_10::Day10>(year_2022_day_10::INPUT),
+    Solution::new::<year_2022_day_11::Day11>(year_2022_day_11::INPUT),
+    Solution::new::<year_2022_day_12::Day12>(year_2022_day_12::INPUT),
+    Solution::new::<year_2022_day_13::Day13>(year_2022_day_13::INPUT),
+    Solution::new::<year_2022_day_14::Day14>(year_2022_day_14::INPUT),
+    Solution::new::<year_2022_day_15::Day15>(year_2022_day_15::INPUT),
+    Solution::new::<year_2022_day_16::Day16>(year_2022_day_16::INPUT),
+    Solution::new::<year_2022_day_17::Day17>(year_2022_day_17::INPUT),
+    Solution::new::<year_2022_day_18::Day18>(year_2022_day_18::INPUT),
+    Solution::new::<year_2022_day_19::Day19>(year_2022_day_19::INPUT),
+    Solution::new::<year_2022_day_20::Day20>(year_2022_day_20::INPUT),
+    Solution::new::<year_2022_day_21::Day21>(year_2022_day_21::INPUT),
+    Solution::new::<year_2022_day_22::Day22>(year_2022_day_22::INPUT),
+    Solution::new::<year_2023_day_01::Day01>(year_2023_day_01::INPUT),
+    Solution::new::<year_2023_day_02::Day02>(year_2023_day_02::INPUT),
+    Solution::new::<year_2023_day_03::Day03>(year_2023_day_03::INPUT),
+    Solution::new::<year_2023_day_04::Day04>(year_2023_day_04::INPUT),
+    Solution::new::<year_2023_day_05::Day05>(year_2023_day_05::INPUT),
+    Solution::new::<year_2023_day_06::Day06>(year_2023_day_06::INPUT),
+    Solution::new::<year_2023_day_07::Day07>(year_2023_day_07::INPUT),
+    Solution::new::<year_2023_day_08::Day08>(year_2023_day_08::INPUT),
+    Solution::new::<year_2023_day_09::Day09>(year_2023_day_09::INPUT),
+    Solution::new::<year_2023_day_10::Day10>(year_2023_day_10::INPUT),
+    Solution::new::<year_2023_day_11::Day11>(year_2023_day_11::INPUT),
+    // 2023 day 12 is left out until it's finished: its part two is still a
+    // `todo!()` and would abort the whole run.
+    Solution::new::<year_2024_day_01::Day01>(year_2024_day_01::INPUT),
+];
@@ -0,0 +1,88 @@
+use aoc_common::{Answer, Result, Solution};
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    All,
+    Parse,
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn from_arg(arg: &str) -> Option<Self> {
+        match arg {
+            "all" => Some(Part::All),
+            "parse" => Some(Part::Parse),
+            "one" => Some(Part::One),
+            "two" => Some(Part::Two),
+            _ => None,
+        }
+    }
+
+    fn includes_one(self) -> bool {
+        matches!(self, Part::All | Part::One)
+    }
+
+    fn includes_two(self) -> bool {
+        matches!(self, Part::All | Part::Two)
+    }
+}
+
+/// Answers and per-stage timings collected while running one day.
+///
+/// Stages that were not run are left as `None`.
+#[derive(Debug, Default)]
+pub struct Run {
+    pub part_one: Option<Answer>,
+    pub part_two: Option<Answer>,
+    pub parse_time: Option<Duration>,
+    pub preprocess_time: Option<Duration>,
+    pub part_one_time: Option<Duration>,
+    pub part_two_time: Option<Duration>,
+}
+
+impl Run {
+    pub fn total_time(&self) -> Duration {
+        [
+            self.parse_time,
+            self.preprocess_time,
+            self.part_one_time,
+            self.part_two_time,
+        ]
+        .into_iter()
+        .flatten()
+        .sum()
+    }
+}
+
+/// Parses `input` and runs the stages of `S` selected by `part`, timing each
+/// one.
+pub fn run<S: Solution>(input: &str, part: Part) -> Result<Run> {
+    let mut run = Run::default();
+    let (parsed, duration) = time(|| S::parse(input));
+    let mut parsed = parsed?;
+    run.parse_time = Some(duration);
+    if part == Part::Parse {
+        return Ok(run);
+    }
+    let ((), duration) = time(|| S::preprocess(&mut parsed));
+    run.preprocess_time = Some(duration);
+    if part.includes_one() {
+        let (answer, duration) = time(|| S::part_one(&parsed));
+        run.part_one = Some(answer);
+        run.part_one_time = Some(duration);
+    }
+    if part.includes_two() {
+        let (answer, duration) = time(|| S::part_two(&parsed));
+        run.part_two = Some(answer);
+        run.part_two_time = Some(duration);
+    }
+    Ok(run)
+}
+
+fn time<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let value = f();
+    (value, start.elapsed())
+}
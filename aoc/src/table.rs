@@ -0,0 +1,90 @@
+use crate::registry::Solution;
+use crate::run::Run;
+use aoc_common::Answer;
+use std::fmt::Write;
+use std::time::Duration;
+
+const HEADERS: [&str; 9] = [
+    "Year",
+    "Day",
+    "Part one",
+    "Part two",
+    "Parse",
+    "Preprocess",
+    "Part one time",
+    "Part two time",
+    "Total",
+];
+
+/// Renders the answers and timings of each run as a plain text table with a
+/// totals row at the bottom. Answers that span multiple lines (such as the
+/// letters drawn by 2022 day 10) are listed after the table.
+pub fn render(results: &[(&Solution, Run)]) -> String {
+    let mut rows = vec![];
+    let mut multi_line_answers = vec![];
+    for (solution, run) in results {
+        let mut answer_cell = |name: &str, answer: &Option<Answer>| match answer {
+            Some(Answer::String(answer)) if answer.contains('\n') => {
+                multi_line_answers.push(format!(
+                    "{} day {:02} {name}:\n{answer}",
+                    solution.year, solution.day
+                ));
+                String::from("(see below)")
+            }
+            Some(answer) => answer.to_string(),
+            None => String::from("-"),
+        };
+        let part_one = answer_cell("part one", &run.part_one);
+        let part_two = answer_cell("part two", &run.part_two);
+        rows.push([
+            solution.year.to_string(),
+            format!("{:02}", solution.day),
+            part_one,
+            part_two,
+            duration_cell(run.parse_time),
+            duration_cell(run.preprocess_time),
+            duration_cell(run.part_one_time),
+            duration_cell(run.part_two_time),
+            duration_cell(Some(run.total_time())),
+        ]);
+    }
+    let total_time = results.iter().map(|(_, run)| run.total_time()).sum();
+    let mut totals = [(); 9].map(|_| String::new());
+    totals[0] = String::from("Total");
+    totals[8] = duration_cell(Some(total_time));
+
+    let mut widths = HEADERS.map(str::len);
+    for row in rows.iter().chain([&totals]) {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+
+    let mut output = String::new();
+    write_row(&mut output, &HEADERS.map(String::from), &widths);
+    let separator = widths.map(|width| "-".repeat(width));
+    write_row(&mut output, &separator, &widths);
+    for row in &rows {
+        write_row(&mut output, row, &widths);
+    }
+    write_row(&mut output, &separator, &widths);
+    write_row(&mut output, &totals, &widths);
+    for answer in multi_line_answers {
+        writeln!(output, "\n{answer}").unwrap();
+    }
+    output
+}
+
+fn write_row(output: &mut String, cells: &[String; 9], widths: &[usize; 9]) {
+    let line = cells
+        .iter()
+        .zip(widths)
+        .map(|(cell, &width)| format!("{cell:<width$}"))
+        .collect::<Vec<_>>()
+        .join(" | ");
+    writeln!(output, "{}", line.trim_end()).unwrap();
+}
+
+fn duration_cell(duration: Option<Duration>) -> String {
+    duration.map_or_else(|| String::from("-"), |d| format!("{d:.2?}"))
+}
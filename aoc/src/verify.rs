@@ -0,0 +1,98 @@
+use crate::answers::Entry;
+use crate::registry::Solution;
+use crate::run::Run;
+use aoc_common::Answer;
+use std::fmt::Write;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail { expected: Answer },
+    Missing,
+}
+
+/// Compares the answers of each run against the known answers for its input,
+/// skipping parts that were not run.
+pub fn check<'a>(results: &'a [(&Solution, Run, Option<&Entry>)]) -> Vec<Outcome<'a>> {
+    let mut outcomes = vec![];
+    for (solution, run, entry) in results {
+        let parts = [
+            (
+                "part one",
+                &run.part_one,
+                entry.and_then(|e| e.part_one.as_ref()),
+            ),
+            (
+                "part two",
+                &run.part_two,
+                entry.and_then(|e| e.part_two.as_ref()),
+            ),
+        ];
+        for (part, actual, expected) in parts {
+            let Some(actual) = actual else {
+                continue;
+            };
+            let status = match expected {
+                Some(expected) if expected == actual => Status::Pass,
+                Some(expected) => Status::Fail {
+                    expected: expected.clone(),
+                },
+                None => Status::Missing,
+            };
+            outcomes.push(Outcome {
+                year: solution.year,
+                day: solution.day,
+                part,
+                actual,
+                status,
+            });
+        }
+    }
+    outcomes
+}
+
+pub struct Outcome<'a> {
+    year: u32,
+    day: u32,
+    part: &'static str,
+    actual: &'a Answer,
+    pub status: Status,
+}
+
+/// Renders one line per outcome followed by a summary line.
+pub fn render(outcomes: &[Outcome]) -> String {
+    let mut output = String::new();
+    for outcome in outcomes {
+        let Outcome {
+            year,
+            day,
+            part,
+            actual,
+            ..
+        } = outcome;
+        write!(output, "{year} day {day:02} {part}: ").unwrap();
+        match &outcome.status {
+            Status::Pass => writeln!(output, "pass"),
+            Status::Fail { expected } => {
+                writeln!(output, "FAIL (expected {expected}, got {actual})")
+            }
+            Status::Missing => writeln!(output, "missing (got {actual})"),
+        }
+        .unwrap();
+    }
+    let count = |status: fn(&Status) -> bool| {
+        outcomes
+            .iter()
+            .filter(|outcome| status(&outcome.status))
+            .count()
+    };
+    writeln!(
+        output,
+        "\n{} passed, {} failed, {} missing",
+        count(|status| *status == Status::Pass),
+        count(|status| matches!(status, Status::Fail { .. })),
+        count(|status| *status == Status::Missing),
+    )
+    .unwrap();
+    output
+}
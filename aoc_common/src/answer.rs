@@ -0,0 +1,123 @@
+use anyhow::{bail, Error, Result};
+use std::fmt::{self, Display};
+
+/// The answer to one part of a puzzle. Most answers are integers but some
+/// puzzles ask for a string, such as the crate labels in 2022 day 5.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    /// Wide enough for any `u64` or `i64` answer.
+    Integer(i128),
+    String(String),
+    /// The input has no answer, for the given reason, such as a path that
+    /// never reaches its goal. It matches no expected answer.
+    None(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(value) => write!(f, "{value}"),
+            Answer::String(value) => write!(f, "{value}"),
+            Answer::None(reason) => write!(f, "no answer: {reason}"),
+        }
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    // Lossless, since none of the types is wider than 64 bits.
+                    Answer::Integer(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::String(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::String(value.to_owned())
+    }
+}
+
+/// Turns the error of a part that can fail on valid input into
+/// [`Answer::None`].
+impl<T: Into<Answer>> From<Result<T>> for Answer {
+    fn from(result: Result<T>) -> Self {
+        result.map_or_else(|err| Answer::None(format!("{err:#}")), Into::into)
+    }
+}
+
+impl TryFrom<toml::Value> for Answer {
+    type Error = Error;
+
+    fn try_from(value: toml::Value) -> Result<Self> {
+        match value {
+            toml::Value::Integer(value) => Ok(Answer::Integer(value.into())),
+            toml::Value::String(value) => Ok(Answer::String(value)),
+            value => bail!("Answers must be integers or strings, found {value}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_from() {
+        assert_eq!(Answer::from(2320u32), Answer::Integer(2320));
+        assert_eq!(Answer::from(-966i32), Answer::Integer(-966));
+        assert_eq!(
+            Answer::from(22_103_062_509_257u64),
+            Answer::Integer(22_103_062_509_257)
+        );
+        assert_eq!(
+            Answer::from(u64::MAX),
+            Answer::Integer(18_446_744_073_709_551_615)
+        );
+        assert_eq!(Answer::from(u64::MAX).to_string(), "18446744073709551615");
+        assert_eq!(
+            Answer::from("PSNRGBTFT"),
+            Answer::String(String::from("PSNRGBTFT"))
+        );
+        assert_eq!(Answer::from(Ok::<_, Error>(6u64)), Answer::Integer(6));
+        assert_eq!(
+            Answer::from(Err::<u64, _>(Error::msg("the ghosts never meet"))),
+            Answer::None(String::from("the ghosts never meet"))
+        );
+    }
+
+    #[test]
+    fn test_try_from_toml() {
+        assert_eq!(
+            Answer::try_from(toml::Value::Integer(7)).unwrap(),
+            Answer::Integer(7)
+        );
+        assert_eq!(
+            Answer::try_from(toml::Value::from("CMZ")).unwrap(),
+            Answer::from("CMZ")
+        );
+        assert!(Answer::try_from(toml::Value::Float(1.5)).is_err());
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::Integer(19_573_408_701).to_string(), "19573408701");
+        assert_eq!(Answer::from("BNTZFPMMW").to_string(), "BNTZFPMMW");
+        assert_eq!(
+            Answer::None(String::from("no marker")).to_string(),
+            "no answer: no marker"
+        );
+    }
+}
@@ -0,0 +1,45 @@
+use crate::{read_input, Solution};
+use std::{env, process};
+
+/// Runs a solution from a day's `main`. The first command line argument picks
+/// what to run: `all`, `parse`, `preprocess`, `one` or `two`. The second is an
+/// optional path to read the input from, or `-` for stdin; without it the
+/// day's embedded `default_input` is used.
+pub fn run<S: Solution>(default_input: &str) {
+    let mut args = env::args().skip(1);
+    let mode = args.next();
+    let mode = match mode.as_deref() {
+        Some(mode @ ("all" | "parse" | "preprocess" | "one" | "two")) => mode,
+        _ => {
+            eprintln!("Invalid argument: must be one of all, parse, preprocess, one, or two");
+            process::exit(2);
+        }
+    };
+    let input = match args.next() {
+        Some(path) => match read_input(&path) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("{e:#}");
+                process::exit(1);
+            }
+        },
+        None => default_input.to_owned(),
+    };
+    let mut input = match S::parse(&input) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Parse error: {e:#}");
+            process::exit(1);
+        }
+    };
+    if mode == "parse" {
+        return;
+    }
+    S::preprocess(&mut input);
+    if matches!(mode, "all" | "one") {
+        println!("{}", S::part_one(&input));
+    }
+    if matches!(mode, "all" | "two") {
+        println!("{}", S::part_two(&input));
+    }
+}
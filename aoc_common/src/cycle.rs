@@ -0,0 +1,218 @@
+use rustc_hash::FxHashMap;
+use std::hash::Hash;
+
+/// A simulation whose states repeat: the state after `start + len` steps is
+/// the same as after `start` steps, and so on forever. Also holds a metric
+/// recorded after each of the first `start + len` steps, so that its value
+/// after any number of steps can be worked out without simulating them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle<M> {
+    /// The number of steps before the states start repeating.
+    pub start: usize,
+    /// The number of steps after which the states repeat.
+    pub len: usize,
+    history: Vec<M>,
+}
+
+impl<M> Cycle<M> {
+    /// The metric after each of the steps `0..=start + len`.
+    pub fn history(&self) -> &[M] {
+        &self.history
+    }
+
+    /// The earliest step with the same state as `step`.
+    pub fn equivalent_step(&self, step: usize) -> usize {
+        if step < self.start {
+            step
+        } else {
+            self.start + (step - self.start) % self.len
+        }
+    }
+
+    /// The metric after `step` steps, for metrics that only depend on the
+    /// state.
+    pub fn value_at(&self, step: usize) -> &M {
+        &self.history[self.equivalent_step(step)]
+    }
+
+    /// The value after `step` steps of a part of the metric that grows by
+    /// the same amount every cycle, such as a count of events so far.
+    pub fn extrapolate(&self, step: usize, mut value: impl FnMut(&M) -> u64) -> u64 {
+        if step <= self.start + self.len {
+            return value(&self.history[step]);
+        }
+        let cycles = ((step - self.start) / self.len) as u64;
+        let cycle_start = value(&self.history[self.start]);
+        let cycle_increase = value(&self.history[self.start + self.len]) - cycle_start;
+        value(&self.history[self.equivalent_step(step)]) + cycle_increase * cycles
+    }
+}
+
+/// Steps `state` until it is in a state it was in before, as told apart by
+/// `key`, recording `metric` after every step. `state` is left in the first
+/// repeated state. Gives up after `max_steps` steps without a repeat, leaving
+/// `state` after those steps, which is useful when the answer needed is
+/// after `max_steps` steps anyway.
+///
+/// This remembers the key of every state seen, so keys should be small.
+/// [`find_cycle_brent`] doesn't, but steps through the cycle several times.
+pub fn find_cycle<S, K, M>(
+    state: &mut S,
+    mut step: impl FnMut(&mut S),
+    mut key: impl FnMut(&S) -> K,
+    mut metric: impl FnMut(&S) -> M,
+    max_steps: usize,
+) -> Option<Cycle<M>>
+where
+    K: Eq + Hash,
+{
+    let mut seen = FxHashMap::default();
+    let mut history = vec![];
+    for steps in 0.. {
+        history.push(metric(state));
+        if let Some(start) = seen.insert(key(state), steps) {
+            return Some(Cycle {
+                start,
+                len: steps - start,
+                history,
+            });
+        }
+        if steps == max_steps {
+            break;
+        }
+        step(state);
+    }
+    None
+}
+
+/// Like [`find_cycle`], using Brent's algorithm, which only ever holds two
+/// keys at once. This steps copies of `initial` rather than `initial` itself,
+/// and gives up when looking for the cycle takes over `max_steps` steps.
+pub fn find_cycle_brent<S, K, M>(
+    initial: &S,
+    mut step: impl FnMut(&mut S),
+    mut key: impl FnMut(&S) -> K,
+    mut metric: impl FnMut(&S) -> M,
+    max_steps: usize,
+) -> Option<Cycle<M>>
+where
+    S: Clone,
+    K: Eq,
+{
+    // Find the length of the cycle by moving the hare one step at a time and
+    // the tortoise up to it whenever the distance between them doubles.
+    let mut hare = initial.clone();
+    let mut tortoise_key = key(&hare);
+    let mut power = 1;
+    let mut len = 1;
+    step(&mut hare);
+    for steps in 1.. {
+        if key(&hare) == tortoise_key {
+            break;
+        }
+        if steps == max_steps {
+            return None;
+        }
+        if power == len {
+            tortoise_key = key(&hare);
+            power *= 2;
+            len = 0;
+        }
+        step(&mut hare);
+        len += 1;
+    }
+
+    // With the hare `len` steps ahead, they first meet where the cycle
+    // starts.
+    let mut tortoise = initial.clone();
+    let mut hare = initial.clone();
+    for _ in 0..len {
+        step(&mut hare);
+    }
+    let mut history = vec![];
+    while key(&tortoise) != key(&hare) {
+        history.push(metric(&tortoise));
+        step(&mut tortoise);
+        step(&mut hare);
+    }
+    let start = history.len();
+    history.push(metric(&tortoise));
+    for _ in 0..len {
+        step(&mut tortoise);
+        history.push(metric(&tortoise));
+    }
+    Some(Cycle {
+        start,
+        len,
+        history,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Enters a cycle of length 4 after 3 steps: 0, 1, 2, 3, 4, 5, 6, 3, ...
+    fn step(state: &mut (u64, u64)) {
+        let (position, total) = state;
+        *position = if *position == 6 { 3 } else { *position + 1 };
+        *total += *position;
+    }
+
+    fn brute_force(steps: usize) -> (u64, u64) {
+        let mut state = (0, 0);
+        for _ in 0..steps {
+            step(&mut state);
+        }
+        state
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        let mut state = (0, 0);
+        let cycle = find_cycle(
+            &mut state,
+            step,
+            |&(position, _)| position,
+            |&state| state,
+            100,
+        );
+        let cycle = cycle.unwrap();
+        assert_eq!((cycle.start, cycle.len), (3, 4));
+        assert_eq!(state, (3, 24));
+        assert_eq!(cycle.history().len(), 8);
+        let brent = find_cycle_brent(
+            &(0, 0),
+            step,
+            |&(position, _)| position,
+            |&state| state,
+            100,
+        );
+        assert_eq!(brent, Some(cycle.clone()));
+        for steps in 0..50 {
+            let expected = brute_force(steps);
+            assert_eq!(cycle.value_at(steps).0, expected.0);
+            assert_eq!(cycle.extrapolate(steps, |&(_, total)| total), expected.1);
+        }
+    }
+
+    #[test]
+    fn test_find_cycle_at_start() {
+        let mut state = 0;
+        let cycle = find_cycle(&mut state, |n| *n = (*n + 1) % 3, |&n| n, |_| (), 3).unwrap();
+        assert_eq!((cycle.start, cycle.len), (0, 3));
+        let brent = find_cycle_brent(&0, |n| *n = (*n + 1) % 3, |&n| n, |_| (), 10).unwrap();
+        assert_eq!((brent.start, brent.len), (0, 3));
+        assert_eq!(cycle.equivalent_step(10), 1);
+    }
+
+    #[test]
+    fn test_find_cycle_gives_up() {
+        let mut state = (0, 0);
+        let cycle = find_cycle(&mut state, step, |&(position, _)| position, |_| (), 6);
+        assert_eq!(cycle, None);
+        assert_eq!(state, brute_force(6));
+        let brent = find_cycle_brent(&(0, 0), step, |&(position, _)| position, |_| (), 2);
+        assert_eq!(brent, None);
+    }
+}
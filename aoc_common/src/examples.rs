@@ -0,0 +1,167 @@
+use crate::{Answer, Solution};
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::fs;
+use std::path::Path;
+
+/// The name of the manifest in a day's `examples` directory that lists the
+/// expected answers for each example.
+pub const MANIFEST_NAME: &str = "expected.toml";
+
+#[derive(Deserialize)]
+struct Manifest {
+    #[serde(default)]
+    example: Vec<RawExample>,
+}
+
+#[derive(Deserialize)]
+struct RawExample {
+    file: String,
+    part_one: Option<toml::Value>,
+    part_two: Option<toml::Value>,
+}
+
+/// An example input with the answers expected for it. Examples often only
+/// apply to one of the parts, so either answer may be missing.
+pub struct Example {
+    pub file: String,
+    pub input: String,
+    pub part_one: Option<Answer>,
+    pub part_two: Option<Answer>,
+}
+
+/// Loads the examples listed in the manifest of `examples_path`, or none if
+/// there is no manifest.
+pub fn load_examples(examples_path: &Path) -> Result<Vec<Example>> {
+    let manifest_path = examples_path.join(MANIFEST_NAME);
+    if !manifest_path.exists() {
+        return Ok(vec![]);
+    }
+    let contents = fs::read_to_string(&manifest_path)
+        .with_context(|| format!("Failed to read {}", manifest_path.display()))?;
+    let manifest: Manifest = toml::from_str(&contents)
+        .with_context(|| format!("Failed to parse {}", manifest_path.display()))?;
+    manifest
+        .example
+        .into_iter()
+        .map(|example| {
+            let input_path = examples_path.join(&example.file);
+            let input = fs::read_to_string(&input_path)
+                .with_context(|| format!("Failed to read {}", input_path.display()))?;
+            Ok(Example {
+                file: example.file,
+                input,
+                part_one: example.part_one.map(Answer::try_from).transpose()?,
+                part_two: example.part_two.map(Answer::try_from).transpose()?,
+            })
+        })
+        .collect()
+}
+
+/// Runs a solution on every example in `examples_path` that has an expected
+/// answer, panicking with every mismatch. Meant to be called from a day's
+/// tests.
+pub fn check_examples<S: Solution>(examples_path: impl AsRef<Path>) {
+    let examples = load_examples(examples_path.as_ref()).unwrap_or_else(|e| panic!("{e:#}"));
+    let mut failures = vec![];
+    for example in examples {
+        if example.part_one.is_none() && example.part_two.is_none() {
+            continue;
+        }
+        let mut input = S::parse(&example.input)
+            .unwrap_or_else(|e| panic!("Failed to parse {}: {e:#}", example.file));
+        S::preprocess(&mut input);
+        let parts = [
+            (
+                "part one",
+                example.part_one,
+                S::part_one as fn(&_) -> Answer,
+            ),
+            ("part two", example.part_two, S::part_two),
+        ];
+        for (part, expected, solve) in parts {
+            let Some(expected) = expected else {
+                continue;
+            };
+            let actual = solve(&input);
+            if actual != expected {
+                failures.push(format!(
+                    "{} {part}: expected {expected}, got {actual}",
+                    example.file
+                ));
+            }
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Result;
+    use tempfile::TempDir;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const YEAR: u32 = 2022;
+        const DAY: u32 = 1;
+
+        type Input<'a> = Vec<i64>;
+
+        fn parse(input: &str) -> Result<Self::Input<'_>> {
+            Ok(input.lines().map(str::parse).collect::<Result<_, _>>()?)
+        }
+
+        fn part_one(input: &Self::Input<'_>) -> Answer {
+            input.iter().sum::<i64>().into()
+        }
+
+        fn part_two(input: &Self::Input<'_>) -> Answer {
+            input.iter().max().copied().unwrap_or_default().into()
+        }
+    }
+
+    fn examples_dir(manifest: &str) -> TempDir {
+        let examples_path = tempfile::tempdir().unwrap();
+        fs::write(examples_path.path().join("1.txt"), "1\n2\n3\n").unwrap();
+        fs::write(examples_path.path().join("2.txt"), "4\n").unwrap();
+        fs::write(examples_path.path().join(MANIFEST_NAME), manifest).unwrap();
+        examples_path
+    }
+
+    #[test]
+    fn test_check_examples() {
+        let examples_path = examples_dir(
+            r#"
+            [[example]]
+            file = "1.txt"
+            part_one = 6
+            part_two = 3
+
+            [[example]]
+            file = "2.txt"
+            # part_one =
+            "#,
+        );
+        check_examples::<Sum>(examples_path.path());
+        check_examples::<Sum>(examples_path.path().join("missing"));
+    }
+
+    #[test]
+    #[should_panic(expected = "2.txt part two: expected 5, got 4")]
+    fn test_check_examples_mismatch() {
+        let examples_path = examples_dir(
+            r#"
+            [[example]]
+            file = "1.txt"
+            part_one = 6
+
+            [[example]]
+            file = "2.txt"
+            part_two = 5
+            "#,
+        );
+        check_examples::<Sum>(examples_path.path());
+    }
+}
@@ -0,0 +1,412 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A point or vector on the plane. As in [`Grid`](crate::Grid), `y` grows
+/// downwards, so [`Direction::Up`] is `(0, -1)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+/// A point or vector in space.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point2 {
+    pub const ORIGIN: Self = Self::new(0, 0);
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    /// The number of steps between the points when moving orthogonally.
+    #[inline]
+    pub fn manhattan_distance(self, other: Self) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// The number of steps between the points when diagonal moves are also
+    /// allowed.
+    #[inline]
+    pub fn chebyshev_distance(self, other: Self) -> u64 {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// The vector with each component replaced by its sign, which is a
+    /// single step towards the same octant.
+    #[inline]
+    pub fn signum(self) -> Self {
+        Self::new(self.x.signum(), self.y.signum())
+    }
+
+    /// Rotates the vector a quarter turn clockwise, as drawn with `y`
+    /// growing downwards.
+    #[inline]
+    pub fn rotate_clockwise(self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+
+    #[inline]
+    pub fn rotate_counterclockwise(self) -> Self {
+        Self::new(self.y, -self.x)
+    }
+
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_add(other.x)?,
+            self.y.checked_add(other.y)?,
+        ))
+    }
+
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_sub(other.x)?,
+            self.y.checked_sub(other.y)?,
+        ))
+    }
+
+    pub fn checked_mul(self, factor: i64) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_mul(factor)?,
+            self.y.checked_mul(factor)?,
+        ))
+    }
+
+    /// The four orthogonal neighbours, in the order of [`Direction::ALL`].
+    pub fn neighbours4(self) -> [Self; 4] {
+        Direction::ALL.map(|direction| self + direction.offset())
+    }
+
+    /// The eight neighbours including diagonals, in the order of
+    /// [`Direction8::ALL`].
+    pub fn neighbours8(self) -> [Self; 8] {
+        Direction8::ALL.map(|direction| self + direction.offset())
+    }
+}
+
+impl Point3 {
+    pub const ORIGIN: Self = Self::new(0, 0, 0);
+
+    pub const fn new(x: i64, y: i64, z: i64) -> Self {
+        Self { x, y, z }
+    }
+
+    #[inline]
+    pub fn manhattan_distance(self, other: Self) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y) + self.z.abs_diff(other.z)
+    }
+
+    #[inline]
+    pub fn chebyshev_distance(self, other: Self) -> u64 {
+        self.x
+            .abs_diff(other.x)
+            .max(self.y.abs_diff(other.y))
+            .max(self.z.abs_diff(other.z))
+    }
+
+    #[inline]
+    pub fn signum(self) -> Self {
+        Self::new(self.x.signum(), self.y.signum(), self.z.signum())
+    }
+
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_add(other.x)?,
+            self.y.checked_add(other.y)?,
+            self.z.checked_add(other.z)?,
+        ))
+    }
+
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_sub(other.x)?,
+            self.y.checked_sub(other.y)?,
+            self.z.checked_sub(other.z)?,
+        ))
+    }
+
+    pub fn checked_mul(self, factor: i64) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_mul(factor)?,
+            self.y.checked_mul(factor)?,
+            self.z.checked_mul(factor)?,
+        ))
+    }
+
+    /// The six neighbours sharing a face.
+    pub fn neighbours6(self) -> [Self; 6] {
+        [
+            Self::new(-1, 0, 0),
+            Self::new(1, 0, 0),
+            Self::new(0, -1, 0),
+            Self::new(0, 1, 0),
+            Self::new(0, 0, -1),
+            Self::new(0, 0, 1),
+        ]
+        .map(|offset| self + offset)
+    }
+}
+
+impl From<(i64, i64)> for Point2 {
+    fn from((x, y): (i64, i64)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl From<Point2> for (i64, i64) {
+    fn from(point: Point2) -> Self {
+        (point.x, point.y)
+    }
+}
+
+impl From<(i64, i64, i64)> for Point3 {
+    fn from((x, y, z): (i64, i64, i64)) -> Self {
+        Self::new(x, y, z)
+    }
+}
+
+impl From<Point3> for (i64, i64, i64) {
+    fn from(point: Point3) -> Self {
+        (point.x, point.y, point.z)
+    }
+}
+
+/// Implements component-wise `+` and `-`, negation and scaling, which panic
+/// on overflow in debug builds like the integer operators.
+macro_rules! impl_vector_ops {
+    ($t:ident { $($field:ident),* }) => {
+        impl Add for $t {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, other: Self) -> Self {
+                Self { $($field: self.$field + other.$field),* }
+            }
+        }
+
+        impl Sub for $t {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, other: Self) -> Self {
+                Self { $($field: self.$field - other.$field),* }
+            }
+        }
+
+        impl Neg for $t {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self {
+                Self { $($field: -self.$field),* }
+            }
+        }
+
+        impl Mul<i64> for $t {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, factor: i64) -> Self {
+                Self { $($field: self.$field * factor),* }
+            }
+        }
+
+        impl AddAssign for $t {
+            #[inline]
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl SubAssign for $t {
+            #[inline]
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+    };
+}
+
+impl_vector_ops!(Point2 { x, y });
+impl_vector_ops!(Point3 { x, y, z });
+
+/// One of the four orthogonal directions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Direction {
+    #[default]
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Every direction, clockwise from [`Direction::Up`].
+    pub const ALL: [Self; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+    #[inline]
+    pub fn turn_left(self) -> Self {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Left => Direction::Down,
+            Direction::Down => Direction::Right,
+            Direction::Right => Direction::Up,
+        }
+    }
+
+    #[inline]
+    pub fn turn_right(self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    #[inline]
+    pub fn reverse(self) -> Self {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+        }
+    }
+
+    /// A single step in this direction.
+    #[inline]
+    pub fn offset(self) -> Point2 {
+        match self {
+            Direction::Up => Point2::new(0, -1),
+            Direction::Right => Point2::new(1, 0),
+            Direction::Down => Point2::new(0, 1),
+            Direction::Left => Point2::new(-1, 0),
+        }
+    }
+}
+
+/// One of the four orthogonal or four diagonal directions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Direction8 {
+    #[default]
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    /// Every direction, clockwise from [`Direction8::Up`].
+    pub const ALL: [Self; 8] = [
+        Self::Up,
+        Self::UpRight,
+        Self::Right,
+        Self::DownRight,
+        Self::Down,
+        Self::DownLeft,
+        Self::Left,
+        Self::UpLeft,
+    ];
+
+    /// Turns an eighth of a full turn counterclockwise.
+    #[inline]
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    /// Turns an eighth of a full turn clockwise.
+    #[inline]
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    #[inline]
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    #[inline]
+    pub fn offset(self) -> Point2 {
+        let (x, y) = match self {
+            Direction8::Up => (0, -1),
+            Direction8::UpRight => (1, -1),
+            Direction8::Right => (1, 0),
+            Direction8::DownRight => (1, 1),
+            Direction8::Down => (0, 1),
+            Direction8::DownLeft => (-1, 1),
+            Direction8::Left => (-1, 0),
+            Direction8::UpLeft => (-1, -1),
+        };
+        Point2::new(x, y)
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Direction8::Up,
+            Direction::Right => Direction8::Right,
+            Direction::Down => Direction8::Down,
+            Direction::Left => Direction8::Left,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_points() {
+        let a = Point2::new(1, -2);
+        let b = Point2::new(-3, 4);
+        assert_eq!(a + b, Point2::new(-2, 2));
+        assert_eq!(a - b, Point2::new(4, -6));
+        assert_eq!(-a * 3, Point2::new(-3, 6));
+        assert_eq!(a.manhattan_distance(b), 10);
+        assert_eq!(a.chebyshev_distance(b), 6);
+        assert_eq!((b - a).signum(), Point2::new(-1, 1));
+        assert_eq!(Point2::new(i64::MAX, 0).checked_add(a), None);
+        assert_eq!(a.checked_mul(2), Some(Point2::new(2, -4)));
+        assert_eq!(Point2::ORIGIN.neighbours4()[0], Point2::new(0, -1));
+        assert_eq!(Point2::ORIGIN.neighbours8()[7], Point2::new(-1, -1));
+
+        let c = Point3::new(1, 2, 3);
+        assert_eq!(c.manhattan_distance(Point3::ORIGIN), 6);
+        assert_eq!(c.chebyshev_distance(Point3::ORIGIN), 3);
+        assert_eq!(c.checked_sub(Point3::new(0, i64::MIN, 0)), None);
+        assert!(c.neighbours6().contains(&Point3::new(1, 2, 4)));
+    }
+
+    #[test]
+    fn test_directions() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.reverse().offset(), -direction.offset());
+            assert_eq!(
+                direction.turn_right().offset(),
+                direction.offset().rotate_clockwise()
+            );
+            assert_eq!(
+                direction.turn_left().offset(),
+                direction.offset().rotate_counterclockwise()
+            );
+            assert_eq!(Direction8::from(direction).offset(), direction.offset());
+        }
+        for direction in Direction8::ALL {
+            assert_eq!(direction.turn_right().turn_left(), direction);
+            assert_eq!(direction.reverse().offset(), -direction.offset());
+        }
+        assert_eq!(Direction8::Up.turn_left(), Direction8::UpLeft);
+        assert_eq!(Direction8::UpLeft.turn_right(), Direction8::Up);
+    }
+}
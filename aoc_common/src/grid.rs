@@ -0,0 +1,453 @@
+use crate::{ParseError, ParseResult};
+use rayon::prelude::*;
+use std::fmt::{self, Display, Formatter};
+use std::iter::StepBy;
+use std::ops::{Index, IndexMut};
+use std::slice::{self, ChunksExact, ChunksExactMut};
+
+/// A position in a grid as `(x, y)`, where `y` counts rows from the top.
+pub type Position = (usize, usize);
+
+/// The offsets to all eight neighbours of a position, clockwise from the
+/// top left.
+const ALL_OFFSETS: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+];
+
+/// A rectangular grid of cells, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid with every cell set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Creates a grid from its cells, row by row.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of cells isn't a multiple of `width`.
+    pub fn from_cells(width: usize, cells: Vec<T>) -> Self {
+        assert!(
+            width > 0 && cells.len().is_multiple_of(width),
+            "{} cells don't make rows of {width}",
+            cells.len()
+        );
+        Self {
+            width,
+            height: cells.len() / width,
+            cells,
+        }
+    }
+
+    /// Creates a grid by calling `cell` with each position, row by row.
+    pub fn from_fn(width: usize, height: usize, mut cell: impl FnMut(Position) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(&mut cell)
+            .collect();
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses a grid with a line of text for each row, converting each byte
+    /// with `cell`.
+    pub fn parse(input: &str, mut cell: impl FnMut(u8) -> T) -> ParseResult<Self> {
+        let width = parse_width(input)?;
+        let mut cells = Vec::with_capacity(input.len());
+        for line in input.lines() {
+            ensure_row_width(input, line, width)?;
+            cells.extend(line.bytes().map(&mut cell));
+        }
+        Ok(Self::from_cells(width, cells))
+    }
+
+    /// Like [`Grid::parse`], for conversions that can fail. The error points
+    /// at the cell that couldn't be converted.
+    pub fn try_parse<E: Display>(
+        input: &str,
+        mut cell: impl FnMut(u8) -> Result<T, E>,
+    ) -> ParseResult<Self> {
+        let width = parse_width(input)?;
+        let mut cells = Vec::with_capacity(input.len());
+        for line in input.lines() {
+            ensure_row_width(input, line, width)?;
+            for (x, byte) in line.bytes().enumerate() {
+                let cell = cell(byte).map_err(|err| ParseError::in_span(input, line, x, err))?;
+                cells.push(cell);
+            }
+        }
+        Ok(Self::from_cells(width, cells))
+    }
+
+    #[inline]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    #[inline]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The cells, row by row.
+    #[inline]
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    #[inline]
+    pub fn cells_mut(&mut self) -> &mut [T] {
+        &mut self.cells
+    }
+
+    pub fn into_cells(self) -> Vec<T> {
+        self.cells
+    }
+
+    #[inline]
+    pub fn contains(&self, (x, y): Position) -> bool {
+        x < self.width && y < self.height
+    }
+
+    /// The index in [`Grid::cells`] of a position inside the grid.
+    #[inline]
+    pub fn index_of(&self, (x, y): Position) -> usize {
+        y * self.width + x
+    }
+
+    /// The position of an index in [`Grid::cells`].
+    #[inline]
+    pub fn position_of(&self, index: usize) -> Position {
+        (index % self.width, index / self.width)
+    }
+
+    #[inline]
+    pub fn get(&self, position: Position) -> Option<&T> {
+        if self.contains(position) {
+            Some(&self.cells[self.index_of(position)])
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        if self.contains(position) {
+            let index = self.index_of(position);
+            Some(&mut self.cells[index])
+        } else {
+            None
+        }
+    }
+
+    /// The position of the first cell, row by row, that matches `predicate`.
+    pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<Position> {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|index| self.position_of(index))
+    }
+
+    /// Every position in the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every position in the grid with its cell, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The position `(dx, dy)` away from `position`, if it's in the grid.
+    #[inline]
+    pub fn offset(&self, (x, y): Position, (dx, dy): (isize, isize)) -> Option<Position> {
+        let neighbour = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+        self.contains(neighbour).then_some(neighbour)
+    }
+
+    /// The up to four positions above, right of, below and left of
+    /// `position` that are in the grid.
+    #[inline]
+    pub fn neighbours4(&self, (x, y): Position) -> Neighbours<4> {
+        // Written out rather than with `offset`, since each direction can
+        // only leave the grid on one side and this is on hot paths.
+        Neighbours::new([
+            y.checked_sub(1).map(|y| (x, y)),
+            (x + 1 < self.width).then_some((x + 1, y)),
+            (y + 1 < self.height).then_some((x, y + 1)),
+            x.checked_sub(1).map(|x| (x, y)),
+        ])
+    }
+
+    /// The up to eight positions around `position`, including diagonally,
+    /// that are in the grid.
+    #[inline]
+    pub fn neighbours8(&self, position: Position) -> Neighbours<8> {
+        Neighbours::new(ALL_OFFSETS.map(|offset| self.offset(position, offset)))
+    }
+
+    #[inline]
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    #[inline]
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    #[inline]
+    pub fn rows(&self) -> ChunksExact<'_, T> {
+        self.cells.chunks_exact(self.width)
+    }
+
+    #[inline]
+    pub fn rows_mut(&mut self) -> ChunksExactMut<'_, T> {
+        self.cells.chunks_exact_mut(self.width)
+    }
+
+    /// The rows, to be processed in parallel.
+    pub fn par_rows(&self) -> rayon::slice::ChunksExact<'_, T>
+    where
+        T: Sync,
+    {
+        self.cells.par_chunks_exact(self.width)
+    }
+
+    /// The cells of column `x`, from top to bottom.
+    #[inline]
+    pub fn column(&self, x: usize) -> StepBy<slice::Iter<'_, T>> {
+        assert!(x < self.width, "column {x} is out of bounds");
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl DoubleEndedIterator<Item = StepBy<slice::Iter<'_, T>>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Converts every cell with `f`.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Swaps rows and columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn(self.height, self.width, |(x, y)| self[(y, x)].clone())
+    }
+
+    /// Rotates the grid a quarter turn clockwise.
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn(self.height, self.width, |(x, y)| {
+            self[(y, self.height - 1 - x)].clone()
+        })
+    }
+
+    /// Rotates the grid a quarter turn counterclockwise.
+    pub fn rotate_counterclockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn(self.height, self.width, |(x, y)| {
+            self[(self.width - 1 - y, x)].clone()
+        })
+    }
+}
+
+/// The neighbours of a position that are in the grid.
+///
+/// This is a plain loop over the candidates rather than `flatten`, which
+/// optimizes noticeably worse in breadth-first searches.
+#[derive(Debug, Clone)]
+pub struct Neighbours<const N: usize> {
+    candidates: [Option<Position>; N],
+    next: usize,
+}
+
+impl<const N: usize> Neighbours<N> {
+    fn new(candidates: [Option<Position>; N]) -> Self {
+        Self {
+            candidates,
+            next: 0,
+        }
+    }
+}
+
+impl<const N: usize> Iterator for Neighbours<N> {
+    type Item = Position;
+
+    #[inline]
+    fn next(&mut self) -> Option<Position> {
+        while let Some(&candidate) = self.candidates.get(self.next) {
+            self.next += 1;
+            if candidate.is_some() {
+                return candidate;
+            }
+        }
+        None
+    }
+}
+
+fn parse_width(input: &str) -> ParseResult<usize> {
+    match input.lines().next().map_or(0, str::len) {
+        0 => Err(ParseError::at(input, 0, "the grid is empty")),
+        width => Ok(width),
+    }
+}
+
+fn ensure_row_width(input: &str, line: &str, width: usize) -> ParseResult<()> {
+    if line.len() != width {
+        let message = format!("the row has {} cells instead of {width}", line.len());
+        return Err(ParseError::new(input, line, message));
+    }
+    Ok(())
+}
+
+/// Panics if the position is outside the grid. Only `x` needs checking
+/// here, since a `y` past the last row is also past the end of the cells.
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, (x, y): Position) -> &T {
+        assert!(
+            x < self.width,
+            "x = {x} is outside the grid of width {}",
+            self.width
+        );
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    #[inline]
+    fn index_mut(&mut self, (x, y): Position) -> &mut T {
+        assert!(
+            x < self.width,
+            "x = {x} is outside the grid of width {}",
+            self.width
+        );
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+/// Writes each row on its own line, with no space between cells.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        Grid::parse("abc\ndef\n", char::from).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 0)], 'c');
+        assert_eq!(grid.get((1, 1)), Some(&'e'));
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.position(|&c| c == 'd'), Some((0, 1)));
+        assert_eq!(grid.to_string(), "abc\ndef\n");
+        assert!(Grid::parse("abc\nde\n", char::from).is_err());
+        assert!(Grid::parse("", char::from).is_err());
+        let error = Grid::parse("abc\nde\n", char::from).unwrap_err();
+        assert_eq!((error.line(), error.column()), (2, 1));
+        let digit = |byte: u8| (byte as char).to_digit(10).ok_or("not a digit");
+        assert_eq!(Grid::try_parse("12\n34", digit).unwrap()[(0, 1)], 3);
+        let error = Grid::try_parse("12\n3a", digit).unwrap_err();
+        assert_eq!((error.line(), error.column()), (2, 2));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = grid();
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            [(1, 0), (0, 1)]
+        );
+        assert_eq!(
+            grid.neighbours4((1, 1)).collect::<Vec<_>>(),
+            [(1, 0), (2, 1), (0, 1)]
+        );
+        assert_eq!(
+            grid.neighbours8((1, 1)).collect::<Vec<_>>(),
+            [(0, 0), (1, 0), (2, 0), (2, 1), (0, 1)]
+        );
+        assert_eq!(grid.offset((2, 1), (-2, -1)), Some((0, 0)));
+        assert_eq!(grid.offset((2, 1), (1, 0)), None);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = grid();
+        assert_eq!(grid.rows().next_back(), Some(&['d', 'e', 'f'][..]));
+        assert_eq!(grid.par_rows().map(|row| row.len()).sum::<usize>(), 6);
+        let columns = grid
+            .columns()
+            .map(|column| column.collect::<String>())
+            .collect::<Vec<_>>();
+        assert_eq!(columns, ["ad", "be", "cf"]);
+        assert_eq!(grid.column(1).rev().collect::<String>(), "eb");
+        assert_eq!(grid.iter().nth(4), Some(((1, 1), &'e')));
+    }
+
+    #[test]
+    fn test_transform() {
+        let grid = grid();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.rotate_clockwise().rotate_counterclockwise(), grid);
+        assert_eq!(
+            grid.map(|c| c.to_ascii_uppercase()).to_string(),
+            "ABC\nDEF\n"
+        );
+    }
+}
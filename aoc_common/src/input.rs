@@ -0,0 +1,17 @@
+use anyhow::{Context, Result};
+use std::fs;
+use std::io::{self, Read};
+
+/// Reads puzzle input from the file at `path`, or from stdin if `path` is
+/// `-`.
+pub fn read_input(path: &str) -> Result<String> {
+    if path == "-" {
+        let mut input = String::new();
+        io::stdin()
+            .read_to_string(&mut input)
+            .context("Failed to read input from stdin")?;
+        Ok(input)
+    } else {
+        fs::read_to_string(path).with_context(|| format!("Failed to read input from {path}"))
+    }
+}
@@ -0,0 +1,358 @@
+use std::ops::{Add, Range, Sub};
+use std::{cmp, mem};
+
+/// An integer type that an [`IntervalSet`] can hold.
+pub trait Integer: Copy + Ord + Add<Output = Self> + Sub<Output = Self> {
+    const ZERO: Self;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                const ZERO: Self = 0;
+            }
+        )*
+    };
+}
+
+impl_integer!(u8, u16, u32, u64, usize, i32, i64);
+
+/// A set of integers stored as the half-open ranges it is made of, which
+/// are kept sorted, non-empty and apart from each other.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T: Integer> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Integer> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self { ranges: vec![] }
+    }
+
+    /// The ranges making up the set, in order.
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The number of integers in the set.
+    pub fn len(&self) -> T {
+        self.ranges
+            .iter()
+            .fold(T::ZERO, |len, range| len + (range.end - range.start))
+    }
+
+    /// The smallest integer in the set.
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(|range| range.start)
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let i = self.ranges.partition_point(|range| range.end <= value);
+        self.ranges.get(i).is_some_and(|range| range.start <= value)
+    }
+
+    /// Adds every integer in `range` to the set.
+    pub fn insert(&mut self, mut range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+        // Ranges that overlap or touch the new one are merged into it.
+        let first = self.ranges.partition_point(|r| r.end < range.start);
+        let last = self.ranges.partition_point(|r| r.start <= range.end);
+        if first < last {
+            range.start = cmp::min(range.start, self.ranges[first].start);
+            range.end = cmp::max(range.end, self.ranges[last - 1].end);
+        }
+        self.ranges.splice(first..last, [range]);
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        for range in &other.ranges {
+            union.insert(range.clone());
+        }
+        union
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let (mut i, mut j) = (0, 0);
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let overlap = cmp::max(a.start, b.start)..cmp::min(a.end, b.end);
+            if !overlap.is_empty() {
+                ranges.push(overlap);
+            }
+            // Whichever range ends first can't overlap anything else.
+            if a.end <= b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self { ranges }
+    }
+
+    /// The integers in this set that aren't in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let mut others = other.ranges.iter().peekable();
+        for range in &self.ranges {
+            let mut start = range.start;
+            while let Some(other) = others.next_if(|other| other.end <= range.end) {
+                if start < other.start {
+                    ranges.push(start..other.start);
+                }
+                start = cmp::max(start, other.end);
+            }
+            // The next range of `other` may stick out past this one, so it
+            // is left for the next range of this set too.
+            let end = match others.peek() {
+                Some(other) if other.start < range.end => cmp::max(start, other.start),
+                _ => range.end,
+            };
+            if start < end {
+                ranges.push(start..end);
+            }
+        }
+        Self { ranges }
+    }
+
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.ranges.iter().all(|range| {
+            let i = other.ranges.partition_point(|r| r.end < range.end);
+            other.ranges.get(i).is_some_and(|r| r.start <= range.start)
+        })
+    }
+
+    /// Whether the sets have any integers in common.
+    pub fn intersects(&self, other: &Self) -> bool {
+        let (mut i, mut j) = (0, 0);
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            if a.start < b.end && b.start < a.end {
+                return true;
+            }
+            if a.end <= b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        false
+    }
+
+    /// Splits the set into the integers inside `range` and those outside it.
+    pub fn split(&self, range: Range<T>) -> (Self, Self) {
+        let range = Self::from(range);
+        (self.intersection(&range), self.difference(&range))
+    }
+
+    /// Moves every integer in the set by the distance from `from` to `to`.
+    pub fn translate(&self, from: T, to: T) -> Self {
+        let ranges = self
+            .ranges
+            .iter()
+            .map(|range| shift(range.start, from, to)..shift(range.end, from, to))
+            .collect();
+        Self { ranges }
+    }
+
+    /// Moves the integers inside each source range of `mapping` so that the
+    /// source range would start at the paired destination, leaving the rest
+    /// where they are. The source ranges shouldn't overlap.
+    pub fn remap(&self, mapping: impl IntoIterator<Item = (Range<T>, T)>) -> Self {
+        let mut unmapped = self.ranges.clone();
+        let mut remaining = vec![];
+        let mut mapped = vec![];
+        for (source, destination) in mapping {
+            for range in unmapped.drain(..) {
+                let overlap = cmp::max(range.start, source.start)..cmp::min(range.end, source.end);
+                if overlap.is_empty() {
+                    remaining.push(range);
+                    continue;
+                }
+                if range.start < overlap.start {
+                    remaining.push(range.start..overlap.start);
+                }
+                if overlap.end < range.end {
+                    remaining.push(overlap.end..range.end);
+                }
+                let start = shift(overlap.start, source.start, destination);
+                let end = shift(overlap.end, source.start, destination);
+                mapped.push(start..end);
+            }
+            mem::swap(&mut unmapped, &mut remaining);
+        }
+        mapped.into_iter().chain(unmapped).collect()
+    }
+}
+
+/// Moves `value` by the distance from `from` to `to`, without going below
+/// zero on the way for unsigned types.
+fn shift<T: Integer>(value: T, from: T, to: T) -> T {
+    if from <= to {
+        value + (to - from)
+    } else {
+        value - (from - to)
+    }
+}
+
+impl<T: Integer> From<Range<T>> for IntervalSet<T> {
+    fn from(range: Range<T>) -> Self {
+        if range.is_empty() {
+            Self::new()
+        } else {
+            Self {
+                ranges: vec![range],
+            }
+        }
+    }
+}
+
+impl<T: Integer> FromIterator<Range<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        let mut ranges = iter
+            .into_iter()
+            .filter(|range| !range.is_empty())
+            .collect::<Vec<_>>();
+        ranges.sort_unstable_by_key(|range| range.start);
+        let mut merged: Vec<Range<T>> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            match merged.last_mut() {
+                Some(last) if range.start <= last.end => last.end = cmp::max(last.end, range.end),
+                _ => merged.push(range),
+            }
+        }
+        Self { ranges: merged }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Sets are checked against bit masks of which of 0..64 they contain.
+    const SIZE: u32 = 64;
+
+    /// Generates sets from a fixed seed, so failures can be reproduced.
+    struct Sets(u64);
+
+    impl Sets {
+        fn next_u32(&mut self, bound: u32) -> u32 {
+            // xorshift64
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            (self.0 % bound as u64) as u32
+        }
+
+        fn range(&mut self) -> Range<u32> {
+            let start = self.next_u32(SIZE);
+            start..cmp::min(start + self.next_u32(16), SIZE)
+        }
+
+        fn set(&mut self) -> IntervalSet<u32> {
+            let count = self.next_u32(6);
+            (0..count).map(|_| self.range()).collect()
+        }
+    }
+
+    fn mask(set: &IntervalSet<u32>) -> u64 {
+        (0..SIZE)
+            .filter(|&value| set.contains(value))
+            .fold(0, |mask, value| mask | 1 << value)
+    }
+
+    fn assert_normalized(set: &IntervalSet<u32>) {
+        for range in set.ranges() {
+            assert!(!range.is_empty(), "{set:?}");
+        }
+        for pair in set.ranges().windows(2) {
+            assert!(pair[0].end < pair[1].start, "{set:?}");
+        }
+    }
+
+    #[test]
+    fn test_interval_set() {
+        let mut set = IntervalSet::from(1..4);
+        set.insert(6..8);
+        set.insert(4..5);
+        assert_eq!(set.ranges(), [1..5, 6..8]);
+        assert_eq!(set.len(), 6);
+        assert_eq!(set.min(), Some(1));
+        assert!(set.contains(4) && !set.contains(5));
+        let (inside, outside) = set.split(3..7);
+        assert_eq!(inside.ranges(), [3..5, 6..7]);
+        assert_eq!(outside.ranges(), [1..3, 7..8]);
+        assert_eq!(set.translate(5, 2).ranges(), [-2..2, 3..5]);
+        assert!(IntervalSet::<u8>::new().is_subset(&IntervalSet::new()));
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let mut sets = Sets(0x2545_f491_4f6c_dd1d);
+        for _ in 0..2000 {
+            let a = sets.set();
+            let b = sets.set();
+            let union = a.union(&b);
+            let intersection = a.intersection(&b);
+            let difference = a.difference(&b);
+            for set in [&a, &union, &intersection, &difference] {
+                assert_normalized(set);
+            }
+            assert_eq!(mask(&union), mask(&a) | mask(&b));
+            assert_eq!(mask(&intersection), mask(&a) & mask(&b));
+            assert_eq!(mask(&difference), mask(&a) & !mask(&b));
+            assert_eq!(a.len(), mask(&a).count_ones());
+            assert_eq!(a.is_subset(&b), mask(&a) & !mask(&b) == 0);
+            assert_eq!(a.intersects(&b), mask(&a) & mask(&b) != 0);
+            let mut inserted = a.clone();
+            for range in b.ranges() {
+                inserted.insert(range.clone());
+            }
+            assert_eq!(inserted, union);
+        }
+    }
+
+    #[test]
+    fn test_remap() {
+        let mut sets = Sets(0x9e37_79b9_7f4a_7c15);
+        for _ in 0..2000 {
+            let set = sets.set();
+            // Non-overlapping sources, each moved somewhere within 0..64.
+            let mut sources = sets.set().ranges().to_vec();
+            sources.truncate(3);
+            let mapping = sources
+                .into_iter()
+                .map(|source| {
+                    let destination = sets.next_u32(SIZE - (source.end - source.start) + 1);
+                    (source, destination)
+                })
+                .collect::<Vec<_>>();
+            let remapped = set.remap(mapping.iter().cloned());
+            assert_normalized(&remapped);
+            let expected = (0..SIZE)
+                .filter(|&value| set.contains(value))
+                .map(|value| {
+                    mapping
+                        .iter()
+                        .find(|(source, _)| source.contains(&value))
+                        .map_or(value, |(source, destination)| {
+                            destination + (value - source.start)
+                        })
+                })
+                .fold(0u64, |mask, value| mask | 1 << value);
+            assert_eq!(mask(&remapped), expected);
+        }
+    }
+}
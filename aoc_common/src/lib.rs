@@ -0,0 +1,36 @@
+mod answer;
+mod cli;
+mod cycle;
+mod examples;
+mod geometry;
+mod grid;
+mod input;
+mod interval;
+mod math;
+mod ocr;
+mod parse;
+mod search;
+mod solution;
+mod sparse_grid;
+
+pub use answer::Answer;
+pub use anyhow::{Error, Result};
+pub use cli::run;
+pub use cycle::{find_cycle, find_cycle_brent, Cycle};
+pub use examples::{check_examples, load_examples, Example, MANIFEST_NAME};
+pub use geometry::{Direction, Direction8, Point2, Point3};
+pub use grid::{Grid, Neighbours, Position};
+pub use input::read_input;
+pub use interval::{Integer, IntervalSet};
+pub use math::{
+    checked_lcm, checked_lcm_all, crt, extended_gcd, gcd, gcd_all, lcm, lcm_all, mod_inverse,
+    mod_mul, mod_pow, Unsigned,
+};
+pub use ocr::ocr;
+pub use parse::{parse_str, ParseError, ParseResult};
+pub use search::{
+    astar, bfs, bfs_dense, dijkstra, dijkstra_dense, AllPairs, Cost, DenseMap, NodeMap, Search,
+    Visit,
+};
+pub use solution::Solution;
+pub use sparse_grid::{BitGrid, Bounds, SparseGrid};
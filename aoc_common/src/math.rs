@@ -0,0 +1,194 @@
+use std::ops::{Div, Mul, Rem};
+
+/// An unsigned integer type for [`gcd`] and [`lcm`].
+pub trait Unsigned:
+    Copy + Eq + Rem<Output = Self> + Div<Output = Self> + Mul<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_mul(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! impl_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl Unsigned for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, other)
+                }
+            }
+        )*
+    };
+}
+
+impl_unsigned!(u8, u16, u32, u64, u128, usize);
+
+/// The greatest common divisor, which is 0 only if both are 0.
+pub fn gcd<T: Unsigned>(mut a: T, mut b: T) -> T {
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// The least common multiple, which is 0 if either is 0. Panics on
+/// overflow in debug builds like the integer operators.
+pub fn lcm<T: Unsigned>(a: T, b: T) -> T {
+    if a == T::ZERO || b == T::ZERO {
+        return T::ZERO;
+    }
+    a / gcd(a, b) * b
+}
+
+/// Like [`lcm`], returning `None` on overflow.
+pub fn checked_lcm<T: Unsigned>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// The greatest common divisor of all the numbers, or 0 if there are none.
+pub fn gcd_all<T: Unsigned>(numbers: impl IntoIterator<Item = T>) -> T {
+    numbers.into_iter().fold(T::ZERO, gcd)
+}
+
+/// The least common multiple of all the numbers, or 1 if there are none.
+pub fn lcm_all<T: Unsigned>(numbers: impl IntoIterator<Item = T>) -> T {
+    numbers.into_iter().fold(T::ONE, lcm)
+}
+
+/// Like [`lcm_all`], returning `None` on overflow.
+pub fn checked_lcm_all<T: Unsigned>(numbers: impl IntoIterator<Item = T>) -> Option<T> {
+    numbers
+        .into_iter()
+        .try_fold(T::ONE, |multiple, n| checked_lcm(multiple, n))
+}
+
+/// The extended Euclidean algorithm: returns `(g, x, y)` where `g` is the
+/// greatest common divisor of `a` and `b`, and `a * x + b * y == g`.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// `a * b % modulus`, without overflowing.
+pub fn mod_mul(a: u64, b: u64, modulus: u64) -> u64 {
+    (a as u128 * b as u128 % modulus as u128) as u64
+}
+
+/// `base` to the power of `exponent`, modulo `modulus`.
+pub fn mod_pow(base: u64, mut exponent: u64, modulus: u64) -> u64 {
+    let mut result = 1 % modulus;
+    let mut base = base % modulus;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mod_mul(result, base, modulus);
+        }
+        base = mod_mul(base, base, modulus);
+        exponent >>= 1;
+    }
+    result
+}
+
+/// The number that gives 1 when multiplied by `a` modulo `modulus`, if
+/// there is one, which is when they have no common divisor.
+pub fn mod_inverse(a: u64, modulus: u64) -> Option<u64> {
+    let (g, x, _) = extended_gcd(a as i128, modulus as i128);
+    (g == 1).then(|| x.rem_euclid(modulus as i128) as u64)
+}
+
+/// Solves a system of congruences `x ≡ residue (mod modulus)` with the
+/// Chinese remainder theorem, returning `(x, m)` where every solution is `x`
+/// plus a multiple of `m`, and `x < m`. The moduli needn't be coprime, so
+/// there may be no solution, and `None` is also returned if `m` doesn't fit
+/// in a `u64`. Moduli must not be 0.
+pub fn crt(congruences: impl IntoIterator<Item = (u64, u64)>) -> Option<(u64, u64)> {
+    congruences
+        .into_iter()
+        .try_fold((0, 1), |(x, m), (residue, modulus)| {
+            // Find `k` so that `x + m * k` is also `residue` modulo `modulus`.
+            let residue = residue % modulus;
+            let g = gcd(m, modulus);
+            let difference = (residue as i128 - x as i128).rem_euclid(modulus as i128) as u64;
+            if !difference.is_multiple_of(g) {
+                return None;
+            }
+            let reduced_modulus = modulus / g;
+            let inverse = mod_inverse(m / g % reduced_modulus, reduced_modulus)?;
+            let k = mod_mul(difference / g, inverse, reduced_modulus);
+            let combined = (m / g).checked_mul(modulus)?;
+            let x = (x as u128 + m as u128 * k as u128) % combined as u128;
+            Some((x as u64, combined))
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(gcd(12u32, 18), 6);
+        assert_eq!(gcd(0u8, 7), 7);
+        assert_eq!(gcd(0u8, 0), 0);
+        assert_eq!(lcm(4u64, 6), 12);
+        assert_eq!(lcm(0u64, 6), 0);
+        assert_eq!(gcd_all([24u16, 36, 60]), 12);
+        assert_eq!(lcm_all([2usize, 3, 4, 5]), 60);
+        assert_eq!(lcm_all::<u32>([]), 1);
+        assert_eq!(checked_lcm(u64::MAX, u64::MAX - 1), None);
+        assert_eq!(checked_lcm_all([1u128 << 100, 3]), Some(3 << 100));
+        assert_eq!(checked_lcm_all([1u128 << 127, 3]), None);
+    }
+
+    #[test]
+    fn test_modular_arithmetic() {
+        for (a, b) in [(240, 46), (-7, 3), (0, 5), (17, 0)] {
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(g, gcd(a.unsigned_abs(), b.unsigned_abs()) as i128);
+            assert_eq!(a * x + b * y, g);
+        }
+        assert_eq!(mod_pow(4, 13, 497), 445);
+        assert_eq!(mod_pow(u64::MAX - 1, u64::MAX, u64::MAX), u64::MAX - 1);
+        assert_eq!(mod_pow(3, 0, 1), 0);
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(6, 9), None);
+        let prime = (1 << 61) - 1;
+        let inverse = mod_inverse(123_456_789, prime).unwrap();
+        assert_eq!(mod_mul(inverse, 123_456_789, prime), 1);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        // Moduli with common factors.
+        assert_eq!(crt([(3, 4), (5, 6)]), Some((11, 12)));
+        assert_eq!(crt([(1, 4), (2, 6)]), None);
+        assert_eq!(crt([(7, 6), (1, 6)]), Some((1, 6)));
+        assert_eq!(crt([]), Some((0, 1)));
+        // The combined modulus only just fits.
+        let (a, b) = (4_294_967_291, 4_294_967_279);
+        assert_eq!(crt([(1, a), (0, b)]).map(|(_, m)| m), Some(a * b));
+        assert_eq!(crt([(1, a), (0, b), (0, 5)]), None);
+        for x in 0..60 {
+            assert_eq!(crt([(x % 4, 4), (x % 6, 6), (x % 5, 5)]), Some((x, 60)));
+        }
+    }
+}
@@ -0,0 +1,241 @@
+use anyhow::{bail, Result};
+
+/// The letters of one size that puzzles draw answers with.
+struct Font {
+    width: usize,
+    height: usize,
+    /// The columns from the start of one letter to the start of the next.
+    spacing: usize,
+    glyphs: &'static [(char, &'static [&'static str])],
+}
+
+/// The font 2016 day 8, 2019 days 8 and 11, 2021 day 13 and 2022 day 10
+/// draw their answers with.
+const SMALL_FONT: Font = Font {
+    width: 4,
+    height: 6,
+    spacing: 5,
+    glyphs: &[
+        ('A', &[".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+        ('B', &["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+        ('C', &[".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+        ('E', &["####", "#...", "###.", "#...", "#...", "####"]),
+        ('F', &["####", "#...", "###.", "#...", "#...", "#..."]),
+        ('G', &[".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+        ('H', &["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+        ('I', &[".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+        ('J', &["..##", "...#", "...#", "...#", "#..#", ".##."]),
+        ('K', &["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+        ('L', &["#...", "#...", "#...", "#...", "#...", "####"]),
+        ('O', &[".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+        ('P', &["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+        ('R', &["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+        ('S', &[".###", "#...", "#...", ".##.", "...#", "###."]),
+        ('U', &["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+        ('Y', &["#...", "#...", ".#.#", "..#.", "..#.", "..#."]),
+        ('Z', &["####", "...#", "..#.", ".#..", "#...", "####"]),
+    ],
+};
+
+/// The font 2018 day 10 draws its answer with.
+const LARGE_FONT: Font = Font {
+    width: 6,
+    height: 10,
+    spacing: 8,
+    glyphs: &[
+        (
+            'A',
+            &[
+                "..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#",
+                "#....#", "#....#",
+            ],
+        ),
+        (
+            'B',
+            &[
+                "#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#",
+                "#....#", "#####.",
+            ],
+        ),
+        (
+            'C',
+            &[
+                ".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+                "#....#", ".####.",
+            ],
+        ),
+        (
+            'E',
+            &[
+                "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+                "#.....", "######",
+            ],
+        ),
+        (
+            'F',
+            &[
+                "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+                "#.....", "#.....",
+            ],
+        ),
+        (
+            'G',
+            &[
+                ".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#",
+                "#...##", ".###.#",
+            ],
+        ),
+        (
+            'H',
+            &[
+                "#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#",
+                "#....#", "#....#",
+            ],
+        ),
+        (
+            'J',
+            &[
+                "...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.",
+                "#...#.", ".###..",
+            ],
+        ),
+        (
+            'K',
+            &[
+                "#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..",
+                "#...#.", "#....#",
+            ],
+        ),
+        (
+            'L',
+            &[
+                "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+                "#.....", "######",
+            ],
+        ),
+        (
+            'N',
+            &[
+                "#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##",
+                "#...##", "#....#",
+            ],
+        ),
+        (
+            'P',
+            &[
+                "#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....",
+                "#.....", "#.....",
+            ],
+        ),
+        (
+            'R',
+            &[
+                "#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.",
+                "#....#", "#....#",
+            ],
+        ),
+        (
+            'X',
+            &[
+                "#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.",
+                "#....#", "#....#",
+            ],
+        ),
+        (
+            'Z',
+            &[
+                "######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....",
+                "#.....", "######",
+            ],
+        ),
+    ],
+};
+
+/// Reads the letters drawn in a picture where `#` is a lit pixel and
+/// anything else is dark, as some puzzles give their answers. The picture
+/// must be 6 or 10 rows high, for the two sizes of letters puzzles use.
+pub fn ocr(picture: &str) -> Result<String> {
+    let rows = picture.lines().map(str::as_bytes).collect::<Vec<_>>();
+    let font = match rows.len() {
+        6 => &SMALL_FONT,
+        10 => &LARGE_FONT,
+        height => bail!("Expected a picture 6 or 10 rows high, found {height} rows"),
+    };
+    let is_lit = |x: usize, y: usize| rows[y].get(x) == Some(&b'#');
+    let width = rows
+        .iter()
+        .map(|row| row.iter().rposition(|&b| b == b'#').map_or(0, |x| x + 1))
+        .max()
+        .unwrap_or(0);
+    (0..width)
+        .step_by(font.spacing)
+        .map(|left| {
+            let glyph = (0..font.height)
+                .map(|y| {
+                    (left..left + font.width)
+                        .map(|x| if is_lit(x, y) { '#' } else { '.' })
+                        .collect::<String>()
+                })
+                .collect::<Vec<_>>();
+            match font.glyphs.iter().find(|(_, rows)| *rows == glyph) {
+                Some(&(letter, _)) => Ok(letter),
+                None => bail!("Unknown letter at column {left}:\n{}", glyph.join("\n")),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_ocr() {
+        let picture = "\
+            .##..###...##.\n\
+            #..#.#..#.#..#\n\
+            #..#.###..#...\n\
+            ####.#..#.#...\n\
+            #..#.#..#.#..#\n\
+            #..#.###...##.";
+        assert_eq!(ocr(picture).unwrap(), "ABC");
+        // Dark pixels can be anything, and rows can stop early.
+        assert_eq!(
+            ocr(&picture.replace('.', " ").replace(" \n", "\n")).unwrap(),
+            "ABC"
+        );
+        assert_eq!(ocr(&"....\n".repeat(6)).unwrap(), "");
+        let error = ocr(&picture.replace("#.###", "#.#.#")).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Unknown letter at column 5:\n###.\n#..#\n#.#.\n#..#\n#..#\n#.#."
+        );
+        assert!(ocr("#").is_err());
+    }
+
+    #[test]
+    fn test_ocr_large() {
+        let picture = LARGE_FONT
+            .glyphs
+            .iter()
+            .fold(vec![String::new(); 10], |mut rows, (_, glyph)| {
+                for (row, glyph_row) in rows.iter_mut().zip(glyph.iter()) {
+                    row.push_str(glyph_row);
+                    row.push_str("..");
+                }
+                rows
+            })
+            .join("\n");
+        assert_eq!(ocr(&picture).unwrap(), "ABCEFGHJKLNPRXZ");
+    }
+
+    #[test]
+    fn test_fonts() {
+        for font in [&SMALL_FONT, &LARGE_FONT] {
+            for (letter, glyph) in font.glyphs {
+                assert_eq!(glyph.len(), font.height, "{letter}");
+                assert!(glyph.iter().all(|row| row.len() == font.width), "{letter}");
+            }
+        }
+    }
+}
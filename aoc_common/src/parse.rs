@@ -0,0 +1,233 @@
+use std::cmp;
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+pub type ParseResult<T> = Result<T, ParseError>;
+
+/// A problem with a puzzle input, along with where in the input it is, so
+/// that it can be shown like a compiler error:
+///
+/// ```text
+/// line 2, column 3: invalid digit found in string
+///   |
+/// 2 | 12x4
+///   |   ^^
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    message: String,
+    line: usize,
+    column: usize,
+    /// The line the error is on, or `None` if it couldn't be placed.
+    snippet: Option<String>,
+    width: usize,
+}
+
+impl ParseError {
+    /// An error about `span`, which should be a slice of `input`, such as one
+    /// of its lines or a word from one. If it isn't, the error only has the
+    /// message and is placed at line 1, column 1.
+    pub fn new(input: &str, span: &str, message: impl Display) -> Self {
+        match offset_of(input, span) {
+            Some(offset) => Self::with_width(input, offset, span.len(), message),
+            None => Self::without_span(message),
+        }
+    }
+
+    /// An error at byte `offset` of `input`.
+    pub fn at(input: &str, offset: usize, message: impl Display) -> Self {
+        Self::with_width(input, offset, 1, message)
+    }
+
+    /// An error at byte `index` of `span`, which should be a slice of `input`
+    /// as for [`ParseError::new`], for when the span is being read byte by
+    /// byte.
+    pub fn in_span(input: &str, span: &str, index: usize, message: impl Display) -> Self {
+        match offset_of(input, span) {
+            Some(offset) => Self::at(input, offset + index, message),
+            None => Self::without_span(message),
+        }
+    }
+
+    /// An error for input that stops before it should.
+    pub fn at_end(input: &str, message: impl Display) -> Self {
+        Self::at(input, input.trim_end().len(), message)
+    }
+
+    /// Converts an error from a `nom` parser run on `input`.
+    pub fn from_nom(input: &str, error: nom::error::Error<&str>) -> Self {
+        let message = match error.code {
+            nom::error::ErrorKind::Eof => "expected the end of the input".to_owned(),
+            code => format!("expected {}", code.description()),
+        };
+        Self::new(input, error.input, message)
+    }
+
+    fn with_width(input: &str, offset: usize, width: usize, message: impl Display) -> Self {
+        let offset = floor_char_boundary(input, offset);
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+        let snippet = input[line_start..line_end].trim_end_matches('\r');
+        let column = input[line_start..offset].chars().count() + 1;
+        // Only the part of the span on its first line is underlined.
+        let end = cmp::min(offset + width, line_start + snippet.len()).max(offset);
+        let end = floor_char_boundary(input, end);
+        let width = input[offset..end].chars().count().max(1);
+        Self {
+            message: message.to_string(),
+            line: before.matches('\n').count() + 1,
+            column,
+            snippet: Some(snippet.to_owned()),
+            width,
+        }
+    }
+
+    fn without_span(message: impl Display) -> Self {
+        Self {
+            message: message.to_string(),
+            line: 1,
+            column: 1,
+            snippet: None,
+            width: 0,
+        }
+    }
+
+    /// The line of the input the error is on, counting from 1.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// The column of the line the error starts at, counting characters
+    /// from 1.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+/// Where `span` starts in `input`, or `None` if it isn't a slice of it.
+fn offset_of(input: &str, span: &str) -> Option<usize> {
+    let offset = (span.as_ptr() as usize).wrapping_sub(input.as_ptr() as usize);
+    (offset <= input.len() && span.len() <= input.len() - offset).then_some(offset)
+}
+
+/// The start of the character that `offset` is in.
+fn floor_char_boundary(input: &str, mut offset: usize) -> usize {
+    offset = offset.min(input.len());
+    while !input.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    offset
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some(snippet) = &self.snippet else {
+            return write!(f, "{}", self.message);
+        };
+        let line_number = self.line.to_string();
+        let gutter = " ".repeat(line_number.len());
+        writeln!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{line_number} | {snippet}")?;
+        write!(
+            f,
+            "{gutter} | {}{}",
+            " ".repeat(self.column - 1),
+            "^".repeat(self.width)
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses `span`, a slice of `input`, with its [`FromStr`] implementation,
+/// pointing at it if that fails.
+pub fn parse_str<T>(input: &str, span: &str) -> ParseResult<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    span.parse()
+        .map_err(|err| ParseError::new(input, span, format!("{err}: {span:?}")))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let input = "1 2\n3 x5 6\n";
+        let span = &input[6..8];
+        let error = parse_str::<u32>(input, span).unwrap_err();
+        assert_eq!((error.line(), error.column()), (2, 3));
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 3: invalid digit found in string: \"x5\"\n  \
+             |\n\
+             2 | 3 x5 6\n  \
+             |   ^^"
+        );
+        let error = ParseError::at_end(input, "expected a third line");
+        assert_eq!((error.line(), error.column()), (2, 7));
+        let error = ParseError::at(input, 4, "oops");
+        assert_eq!((error.line(), error.column()), (2, 1));
+        assert!(error.to_string().ends_with("2 | 3 x5 6\n  | ^"));
+    }
+
+    #[test]
+    fn test_long_spans() {
+        // Spans running onto the next line are cut off at the end of the
+        // first, and empty spans still get a caret.
+        let input = "abc\r\ndef\n";
+        let error = ParseError::new(input, &input[1..6], "across lines");
+        assert!(error.to_string().ends_with("1 | abc\n  |  ^^"));
+        let error = ParseError::at(input, 4, "line ending");
+        assert!(error.to_string().ends_with("1 | abc\n  |     ^"));
+        let error = ParseError::new(input, &input[9..], "empty");
+        assert_eq!((error.line(), error.column()), (3, 1));
+        assert!(error.to_string().ends_with("3 | \n  | ^"));
+        let line = "é12";
+        let error = ParseError::new(line, &line[2..], "after a wide character");
+        assert_eq!(error.column(), 2);
+        let error = ParseError::at(line, 1, "inside a wide character");
+        assert_eq!(error.column(), 1);
+        let error = ParseError::in_span(line, &line[2..], 1, "in a span");
+        assert_eq!(error.column(), 3);
+    }
+
+    #[test]
+    fn test_span_outside_input() {
+        let input = "1 2\n";
+        let other = String::from("x");
+        let error = parse_str::<u32>(input, &other).unwrap_err();
+        assert_eq!((error.line(), error.column()), (1, 1));
+        assert_eq!(error.to_string(), "invalid digit found in string: \"x\"");
+        let error = ParseError::in_span(input, &other, 3, "outside");
+        assert_eq!(error.to_string(), "outside");
+    }
+
+    #[test]
+    fn test_from_nom() {
+        use nom::{character::complete::digit1, Finish};
+        let input = "12\nab";
+        let error = digit1::<_, nom::error::Error<&str>>(&input[3..])
+            .finish()
+            .unwrap_err();
+        let error = ParseError::from_nom(input, error);
+        assert_eq!((error.line(), error.column()), (2, 1));
+        assert_eq!(error.message(), "expected Digit");
+    }
+}
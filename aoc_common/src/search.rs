@@ -0,0 +1,535 @@
+use rustc_hash::FxHashMap;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, VecDeque};
+use std::hash::Hash;
+use std::marker::PhantomData;
+use std::ops::Add;
+
+/// An edge weight or path length.
+pub trait Cost: Copy + Ord + Add<Output = Self> {
+    const ZERO: Self;
+}
+
+macro_rules! impl_cost {
+    ($($t:ty),*) => {
+        $(
+            impl Cost for $t {
+                const ZERO: Self = 0;
+            }
+        )*
+    };
+}
+
+impl_cost!(u8, u16, u32, u64, usize, i32, i64);
+
+/// What a search knows about a node it reached.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Visit<N, C> {
+    /// The length of the shortest path found to the node.
+    pub cost: C,
+    /// The node before it on that path, or `None` for a start node.
+    pub parent: Option<N>,
+}
+
+/// Where a search stores the [`Visit`] for each node it reaches.
+pub trait NodeMap<N, C> {
+    fn contains(&self, node: N) -> bool;
+    fn get(&self, node: N) -> Option<Visit<N, C>>;
+    fn insert(&mut self, node: N, visit: Visit<N, C>);
+    fn iter(&self) -> impl Iterator<Item = (N, Visit<N, C>)> + '_;
+}
+
+impl<N: Copy + Eq + Hash, C: Copy> NodeMap<N, C> for FxHashMap<N, Visit<N, C>> {
+    #[inline]
+    fn contains(&self, node: N) -> bool {
+        self.contains_key(&node)
+    }
+
+    #[inline]
+    fn get(&self, node: N) -> Option<Visit<N, C>> {
+        FxHashMap::get(self, &node).copied()
+    }
+
+    #[inline]
+    fn insert(&mut self, node: N, visit: Visit<N, C>) {
+        FxHashMap::insert(self, node, visit);
+    }
+
+    fn iter(&self) -> impl Iterator<Item = (N, Visit<N, C>)> + '_ {
+        FxHashMap::iter(self).map(|(&node, &visit)| (node, visit))
+    }
+}
+
+/// Storage for graphs whose nodes are the indices `0..len`, such as the
+/// cells of a [`Grid`](crate::Grid), which is much faster than hashing.
+#[derive(Debug, Clone)]
+pub struct DenseMap<C> {
+    // Kept apart so that checking whether a node was reached, which is what
+    // searches do most, touches as little memory as possible.
+    reached: Vec<bool>,
+    costs: Vec<C>,
+    parents: Vec<usize>,
+}
+
+impl<C: Cost> DenseMap<C> {
+    pub fn new(len: usize) -> Self {
+        Self {
+            reached: vec![false; len],
+            costs: vec![C::ZERO; len],
+            parents: vec![usize::MAX; len],
+        }
+    }
+}
+
+impl<C: Cost> NodeMap<usize, C> for DenseMap<C> {
+    #[inline]
+    fn contains(&self, node: usize) -> bool {
+        self.reached[node]
+    }
+
+    #[inline]
+    fn get(&self, node: usize) -> Option<Visit<usize, C>> {
+        self.reached[node].then(|| Visit {
+            cost: self.costs[node],
+            parent: Some(self.parents[node]).filter(|&parent| parent != usize::MAX),
+        })
+    }
+
+    #[inline]
+    fn insert(&mut self, node: usize, visit: Visit<usize, C>) {
+        self.reached[node] = true;
+        self.costs[node] = visit.cost;
+        self.parents[node] = visit.parent.unwrap_or(usize::MAX);
+    }
+
+    fn iter(&self) -> impl Iterator<Item = (usize, Visit<usize, C>)> + '_ {
+        (0..self.reached.len()).filter_map(|node| Some((node, self.get(node)?)))
+    }
+}
+
+/// The outcome of a search from one or more start nodes: the shortest path
+/// to every node reached, and the goal if one was found. The search stops as
+/// soon as it reaches a goal, so to explore everything reachable pass a goal
+/// test that's always false.
+#[derive(Debug, Clone)]
+pub struct Search<N, C, M = FxHashMap<N, Visit<N, C>>> {
+    visits: M,
+    goal: Option<N>,
+    cost: PhantomData<C>,
+}
+
+impl<N: Copy, C: Cost, M: NodeMap<N, C>> Search<N, C, M> {
+    fn new(visits: M) -> Self {
+        Self {
+            visits,
+            goal: None,
+            cost: PhantomData,
+        }
+    }
+
+    /// The goal that was reached, if any.
+    pub fn goal(&self) -> Option<N> {
+        self.goal
+    }
+
+    /// The distance from the nearest start to the goal.
+    pub fn cost(&self) -> Option<C> {
+        self.goal.and_then(|goal| self.distance(goal))
+    }
+
+    /// The distance from the nearest start to `node`. For Dijkstra and A*,
+    /// this is only final for nodes that were expanded before the search
+    /// stopped.
+    pub fn distance(&self, node: N) -> Option<C> {
+        self.visits.get(node).map(|visit| visit.cost)
+    }
+
+    /// Every node reached, with its distance, in no particular order.
+    pub fn distances(&self) -> impl Iterator<Item = (N, C)> + '_ {
+        self.visits.iter().map(|(node, visit)| (node, visit.cost))
+    }
+
+    /// The nodes on the shortest path to the goal, starting with a start
+    /// node and ending with the goal.
+    pub fn path(&self) -> Option<Vec<N>> {
+        self.goal.and_then(|goal| self.path_to(goal))
+    }
+
+    /// The nodes on the shortest path to `node`, starting with a start node.
+    pub fn path_to(&self, node: N) -> Option<Vec<N>> {
+        let mut path = vec![node];
+        let mut visit = self.visits.get(node)?;
+        while let Some(parent) = visit.parent {
+            path.push(parent);
+            visit = self.visits.get(parent)?;
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Breadth-first search, where every edge has length 1.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(N) -> I,
+    is_goal: impl FnMut(N) -> bool,
+) -> Search<N, usize>
+where
+    N: Copy + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    bfs_in(FxHashMap::default(), starts, neighbours, is_goal)
+}
+
+/// Like [`bfs`], for graphs whose nodes are the indices `0..len`.
+pub fn bfs_dense<I>(
+    len: usize,
+    starts: impl IntoIterator<Item = usize>,
+    neighbours: impl FnMut(usize) -> I,
+    is_goal: impl FnMut(usize) -> bool,
+) -> Search<usize, usize, DenseMap<usize>>
+where
+    I: IntoIterator<Item = usize>,
+{
+    bfs_in(DenseMap::new(len), starts, neighbours, is_goal)
+}
+
+fn bfs_in<N, I, M>(
+    mut visits: M,
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(N) -> I,
+    mut is_goal: impl FnMut(N) -> bool,
+) -> Search<N, usize, M>
+where
+    N: Copy,
+    I: IntoIterator<Item = N>,
+    M: NodeMap<N, usize>,
+{
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if !visits.contains(start) {
+            let visit = Visit {
+                cost: 0,
+                parent: None,
+            };
+            visits.insert(start, visit);
+            queue.push_back((start, 0));
+        }
+    }
+    let mut goal = None;
+    while let Some((node, distance)) = queue.pop_front() {
+        if is_goal(node) {
+            goal = Some(node);
+            break;
+        }
+        // `for_each` rather than a `for` loop, since adapters like `filter`
+        // pass it on to the underlying iterator, which can then unroll its
+        // loop. This makes searches over grid neighbours much faster.
+        neighbours(node).into_iter().for_each(|neighbour| {
+            if !visits.contains(neighbour) {
+                let visit = Visit {
+                    cost: distance + 1,
+                    parent: Some(node),
+                };
+                visits.insert(neighbour, visit);
+                queue.push_back((neighbour, distance + 1));
+            }
+        });
+    }
+    let mut search = Search::new(visits);
+    search.goal = goal;
+    search
+}
+
+/// Dijkstra's algorithm, for edges with non-negative costs.
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(N) -> I,
+    is_goal: impl FnMut(N) -> bool,
+) -> Search<N, C>
+where
+    N: Copy + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar_in(
+        FxHashMap::default(),
+        starts,
+        neighbours,
+        |_| C::ZERO,
+        is_goal,
+    )
+}
+
+/// Like [`dijkstra`], for graphs whose nodes are the indices `0..len`.
+pub fn dijkstra_dense<C, I>(
+    len: usize,
+    starts: impl IntoIterator<Item = usize>,
+    neighbours: impl FnMut(usize) -> I,
+    is_goal: impl FnMut(usize) -> bool,
+) -> Search<usize, C, DenseMap<C>>
+where
+    C: Cost,
+    I: IntoIterator<Item = (usize, C)>,
+{
+    astar_in(DenseMap::new(len), starts, neighbours, |_| C::ZERO, is_goal)
+}
+
+/// A* search. The `heuristic` estimates the remaining cost to a goal and
+/// must never overestimate it, or the path found may not be the shortest.
+pub fn astar<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(N) -> I,
+    heuristic: impl FnMut(N) -> C,
+    is_goal: impl FnMut(N) -> bool,
+) -> Search<N, C>
+where
+    N: Copy + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar_in(FxHashMap::default(), starts, neighbours, heuristic, is_goal)
+}
+
+fn astar_in<N, C, I, M>(
+    visits: M,
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(N) -> I,
+    mut heuristic: impl FnMut(N) -> C,
+    mut is_goal: impl FnMut(N) -> bool,
+) -> Search<N, C, M>
+where
+    N: Copy,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+    M: NodeMap<N, C>,
+{
+    let mut search = Search::new(visits);
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        if !search.visits.contains(start) {
+            let visit = Visit {
+                cost: C::ZERO,
+                parent: None,
+            };
+            search.visits.insert(start, visit);
+            queue.push(Queued {
+                estimate: heuristic(start),
+                cost: C::ZERO,
+                node: start,
+            });
+        }
+    }
+    while let Some(Queued { cost, node, .. }) = queue.pop() {
+        // Nodes are queued again when a shorter path is found rather than
+        // updated in place, so skip the stale entries.
+        if search.distance(node).is_some_and(|best| best < cost) {
+            continue;
+        }
+        if is_goal(node) {
+            search.goal = Some(node);
+            break;
+        }
+        // See `bfs_in` for why this is `for_each`.
+        neighbours(node)
+            .into_iter()
+            .for_each(|(neighbour, edge_cost)| {
+                let neighbour_cost = cost + edge_cost;
+                if search
+                    .distance(neighbour)
+                    .is_some_and(|best| best <= neighbour_cost)
+                {
+                    return;
+                }
+                let visit = Visit {
+                    cost: neighbour_cost,
+                    parent: Some(node),
+                };
+                search.visits.insert(neighbour, visit);
+                queue.push(Queued {
+                    estimate: neighbour_cost + heuristic(neighbour),
+                    cost: neighbour_cost,
+                    node: neighbour,
+                });
+            });
+    }
+    search
+}
+
+/// An entry in the A* queue, ordered so the lowest estimate is popped first.
+/// The node itself is left out of the ordering so it needn't be `Ord`.
+struct Queued<N, C> {
+    estimate: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> PartialEq for Queued<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<N, C: Ord> Eq for Queued<N, C> {}
+
+impl<N, C: Ord> PartialOrd for Queued<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> Ord for Queued<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .estimate
+            .cmp(&self.estimate)
+            .then_with(|| self.cost.cmp(&other.cost))
+    }
+}
+
+/// The shortest distances between every pair of nodes `0..len`, found with
+/// the Floyd–Warshall algorithm.
+#[derive(Debug, Clone)]
+pub struct AllPairs<C> {
+    distances: Vec<Vec<Option<C>>>,
+    next: Vec<Vec<Option<usize>>>,
+}
+
+impl<C: Cost> AllPairs<C> {
+    /// Finds the distances in a graph of `len` nodes with the given directed
+    /// `(from, to, cost)` edges. Where there are several edges between the
+    /// same nodes, the cheapest is used.
+    pub fn new(len: usize, edges: impl IntoIterator<Item = (usize, usize, C)>) -> Self {
+        let mut distances = vec![vec![None; len]; len];
+        let mut next = vec![vec![None; len]; len];
+        for i in 0..len {
+            distances[i][i] = Some(C::ZERO);
+            next[i][i] = Some(i);
+        }
+        for (from, to, cost) in edges {
+            if distances[from][to].is_none_or(|distance| cost < distance) {
+                distances[from][to] = Some(cost);
+                next[from][to] = Some(to);
+            }
+        }
+        for k in 0..len {
+            for i in 0..len {
+                let Some(to_k) = distances[i][k] else {
+                    continue;
+                };
+                for j in 0..len {
+                    let Some(from_k) = distances[k][j] else {
+                        continue;
+                    };
+                    let through_k = to_k + from_k;
+                    if distances[i][j].is_none_or(|distance| through_k < distance) {
+                        distances[i][j] = Some(through_k);
+                        next[i][j] = next[i][k];
+                    }
+                }
+            }
+        }
+        Self { distances, next }
+    }
+
+    /// The distance from `from` to `to`, or `None` if there is no path.
+    #[inline]
+    pub fn distance(&self, from: usize, to: usize) -> Option<C> {
+        self.distances[from][to]
+    }
+
+    /// The distances from `from` to every node.
+    pub fn distances_from(&self, from: usize) -> &[Option<C>] {
+        &self.distances[from]
+    }
+
+    /// The nodes on the shortest path from `from` to `to`, including both.
+    pub fn path(&self, from: usize, to: usize) -> Option<Vec<usize>> {
+        let mut current = from;
+        let mut path = vec![current];
+        while current != to {
+            current = self.next[current][to]?;
+            path.push(current);
+        }
+        Some(path)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // A path 0, 1, 2, 4 of cheap edges, expensive edges from 4 and 0 to 3,
+    // and 5 on its own.
+    const EDGES: [(usize, usize, u32); 5] = [(0, 1, 1), (1, 2, 1), (2, 4, 1), (4, 3, 5), (0, 3, 5)];
+
+    fn weighted_neighbours(node: usize) -> Vec<(usize, u32)> {
+        EDGES
+            .iter()
+            .filter_map(|&(a, b, cost)| match node {
+                _ if node == a => Some((b, cost)),
+                _ if node == b => Some((a, cost)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn neighbours(node: usize) -> impl Iterator<Item = usize> {
+        weighted_neighbours(node).into_iter().map(|(n, _)| n)
+    }
+
+    #[test]
+    fn test_bfs() {
+        let search = bfs([0], neighbours, |node| node == 4);
+        assert_eq!(search.cost(), Some(2));
+        assert_eq!(search.path(), Some(vec![0, 3, 4]));
+        let search = bfs([0, 2], neighbours, |_| false);
+        assert_eq!(search.distance(4), Some(1));
+        assert_eq!(search.distance(3), Some(1));
+        assert_eq!(search.goal(), None);
+        assert_eq!(search.path_to(9), None);
+        let search = bfs_dense(6, [0], neighbours, |node| node == 4);
+        assert_eq!(search.path(), Some(vec![0, 3, 4]));
+        let mut distances = search.distances().collect::<Vec<_>>();
+        distances.sort_unstable();
+        assert_eq!(distances, [(0, 0), (1, 1), (2, 2), (3, 1), (4, 2)]);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let search = dijkstra([0], weighted_neighbours, |node| node == 3);
+        assert_eq!(search.cost(), Some(5));
+        assert_eq!(search.path(), Some(vec![0, 3]));
+        let search = dijkstra([3], weighted_neighbours, |node| node == 2);
+        assert_eq!(search.cost(), Some(6));
+        assert_eq!(search.path(), Some(vec![3, 4, 2]));
+        let search = dijkstra_dense(6, [3], weighted_neighbours, |node| node == 2);
+        assert_eq!(search.cost(), Some(6));
+        assert_eq!(search.distance(5), None);
+    }
+
+    #[test]
+    fn test_astar() {
+        let goal = (5i64, 3i64);
+        let search = astar(
+            [(0i64, 0i64)],
+            |(x, y)| [((x + 1, y), 1), ((x, y + 1), 1), ((x - 1, y), 1)],
+            |(x, y)| goal.0.abs_diff(x) + goal.1.abs_diff(y),
+            |node| node == goal,
+        );
+        assert_eq!(search.cost(), Some(8));
+        assert_eq!(search.path().map(|path| path.len()), Some(9));
+    }
+
+    #[test]
+    fn test_all_pairs() {
+        let all_pairs = AllPairs::new(6, EDGES);
+        assert_eq!(all_pairs.distance(0, 3), Some(5));
+        assert_eq!(all_pairs.distance(0, 4), Some(3));
+        assert_eq!(all_pairs.distance(3, 0), None);
+        assert_eq!(all_pairs.distance(5, 5), Some(0));
+        assert_eq!(all_pairs.path(0, 4), Some(vec![0, 1, 2, 4]));
+        assert_eq!(all_pairs.path(1, 0), None);
+        assert_eq!(all_pairs.distances_from(2)[3], Some(6));
+    }
+}
@@ -0,0 +1,25 @@
+use crate::Answer;
+use anyhow::Result;
+
+/// A solution to one day's puzzle.
+///
+/// Tooling runs a solution by calling `parse`, then `preprocess`, then
+/// either or both of the parts with the same input.
+pub trait Solution {
+    const YEAR: u32;
+    const DAY: u32;
+
+    /// The parsed puzzle input. It may borrow from the input text.
+    type Input<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>>;
+
+    /// Work shared by both parts that is worth timing separately from
+    /// parsing. Does nothing by default. The parts must still work when it
+    /// isn't called, such as by doing the work on first use.
+    fn preprocess(_input: &mut Self::Input<'_>) {}
+
+    fn part_one(input: &Self::Input<'_>) -> Answer;
+
+    fn part_two(input: &Self::Input<'_>) -> Answer;
+}
@@ -0,0 +1,483 @@
+use crate::Point2;
+use rustc_hash::FxHashMap;
+use std::cmp;
+use std::fmt::{self, Display, Formatter};
+use std::ops::RangeInclusive;
+
+/// An inclusive rectangle of points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bounds {
+    pub min: Point2,
+    pub max: Point2,
+}
+
+impl Bounds {
+    /// The bounds covering a single point.
+    pub fn point(point: Point2) -> Self {
+        Self {
+            min: point,
+            max: point,
+        }
+    }
+
+    /// Grows the bounds to cover `point`.
+    #[inline]
+    pub fn include(&mut self, point: Point2) {
+        self.min = Point2::new(cmp::min(self.min.x, point.x), cmp::min(self.min.y, point.y));
+        self.max = Point2::new(cmp::max(self.max.x, point.x), cmp::max(self.max.y, point.y));
+    }
+
+    #[inline]
+    pub fn contains(&self, point: Point2) -> bool {
+        self.xs().contains(&point.x) && self.ys().contains(&point.y)
+    }
+
+    /// The bounds covered by both `self` and `other`, if any.
+    pub fn intersection(&self, other: &Bounds) -> Option<Bounds> {
+        let min = Point2::new(
+            cmp::max(self.min.x, other.min.x),
+            cmp::max(self.min.y, other.min.y),
+        );
+        let max = Point2::new(
+            cmp::min(self.max.x, other.max.x),
+            cmp::min(self.max.y, other.max.y),
+        );
+        (min.x <= max.x && min.y <= max.y).then_some(Bounds { min, max })
+    }
+
+    pub fn xs(&self) -> RangeInclusive<i64> {
+        self.min.x..=self.max.x
+    }
+
+    pub fn ys(&self) -> RangeInclusive<i64> {
+        self.min.y..=self.max.y
+    }
+
+    pub fn width(&self) -> usize {
+        (self.max.x - self.min.x + 1) as usize
+    }
+
+    pub fn height(&self) -> usize {
+        (self.max.y - self.min.y + 1) as usize
+    }
+}
+
+/// A grid that only stores the cells that have been set, for simulations
+/// whose extent isn't known up front.
+///
+/// The bounding box covers every cell ever inserted; removing cells doesn't
+/// shrink it.
+#[derive(Debug, Clone)]
+pub struct SparseGrid<T> {
+    cells: FxHashMap<Point2, T>,
+    bounds: Option<Bounds>,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self {
+            cells: FxHashMap::default(),
+            bounds: None,
+        }
+    }
+
+    #[inline]
+    pub fn get(&self, point: Point2) -> Option<&T> {
+        self.cells.get(&point)
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self, point: Point2) -> Option<&mut T> {
+        self.cells.get_mut(&point)
+    }
+
+    #[inline]
+    pub fn contains(&self, point: Point2) -> bool {
+        self.cells.contains_key(&point)
+    }
+
+    /// Sets the cell at `point`, returning its previous value.
+    #[inline]
+    pub fn insert(&mut self, point: Point2, value: T) -> Option<T> {
+        include(&mut self.bounds, point);
+        self.cells.insert(point, value)
+    }
+
+    #[inline]
+    pub fn remove(&mut self, point: Point2) -> Option<T> {
+        self.cells.remove(&point)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// The bounding box of the cells, or `None` if nothing was inserted.
+    pub fn bounds(&self) -> Option<Bounds> {
+        self.bounds
+    }
+
+    /// The cells that are set, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point2, &T)> {
+        self.cells.iter().map(|(&point, value)| (point, value))
+    }
+
+    /// Draws the cells within `bounds` a row at a time, converting each with
+    /// `cell`.
+    pub fn render(&self, bounds: Bounds, mut cell: impl FnMut(Option<&T>) -> char) -> String {
+        render(bounds, |point| cell(self.get(point)))
+    }
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> FromIterator<(Point2, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point2, T)>>(iter: I) -> Self {
+        let mut grid = Self::new();
+        for (point, value) in iter {
+            grid.insert(point, value);
+        }
+        grid
+    }
+}
+
+/// Draws the bounding box, with a `.` for each empty cell. Meant for cells
+/// that display as a single character.
+impl<T: Display> Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let Some(bounds) = self.bounds else {
+            return Ok(());
+        };
+        for y in bounds.ys() {
+            for x in bounds.xs() {
+                match self.get(Point2::new(x, y)) {
+                    Some(value) => write!(f, "{value}")?,
+                    None => write!(f, ".")?,
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// A set of points packed into bits, which grows in any direction as points
+/// are inserted.
+///
+/// The storage at least doubles along an axis whenever it grows, so inserting
+/// points along a line, like a tower built upwards, takes amortized constant
+/// time. As with [`SparseGrid`], the bounding box never shrinks.
+#[derive(Debug, Clone, Default)]
+pub struct BitGrid {
+    /// The top left of the allocated area.
+    origin: Point2,
+    width: usize,
+    height: usize,
+    /// The number of words in each row.
+    stride: usize,
+    words: Vec<u64>,
+    bounds: Option<Bounds>,
+    len: usize,
+}
+
+impl BitGrid {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an empty grid with room for every point in `area`.
+    pub fn with_capacity(area: Bounds) -> Self {
+        let stride = area.width().div_ceil(64);
+        Self {
+            origin: area.min,
+            width: area.width(),
+            height: area.height(),
+            stride,
+            words: vec![0; stride * area.height()],
+            bounds: None,
+            len: 0,
+        }
+    }
+
+    /// The word and bit mask for `point`, if it's in the allocated area.
+    #[inline]
+    fn slot(&self, point: Point2) -> Option<(usize, u64)> {
+        // Points before the origin wrap around to huge offsets, so one
+        // comparison per axis checks both sides.
+        let column = (point.x - self.origin.x) as usize;
+        let row = (point.y - self.origin.y) as usize;
+        (column < self.width && row < self.height)
+            .then(|| (row * self.stride + column / 64, 1 << (column % 64)))
+    }
+
+    #[inline]
+    pub fn contains(&self, point: Point2) -> bool {
+        self.slot(point)
+            .is_some_and(|(word, mask)| self.words[word] & mask != 0)
+    }
+
+    /// Adds `point`, returning whether it wasn't already there.
+    #[inline]
+    pub fn insert(&mut self, point: Point2) -> bool {
+        let (word, mask) = match self.slot(point) {
+            Some(slot) => slot,
+            None => {
+                self.grow_to(point);
+                self.slot(point).unwrap()
+            }
+        };
+        include(&mut self.bounds, point);
+        let inserted = self.words[word] & mask == 0;
+        self.words[word] |= mask;
+        self.len += usize::from(inserted);
+        inserted
+    }
+
+    /// Removes `point`, returning whether it was there.
+    #[inline]
+    pub fn remove(&mut self, point: Point2) -> bool {
+        let Some((word, mask)) = self.slot(point) else {
+            return false;
+        };
+        let removed = self.words[word] & mask != 0;
+        self.words[word] &= !mask;
+        self.len -= usize::from(removed);
+        removed
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The bounding box of the points, or `None` if nothing was inserted.
+    pub fn bounds(&self) -> Option<Bounds> {
+        self.bounds
+    }
+
+    /// The points in the grid, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = Point2> + '_ {
+        self.words
+            .iter()
+            .enumerate()
+            .flat_map(move |(index, &word)| {
+                let (row, column) = (index / self.stride, index % self.stride * 64);
+                BitIter(word).map(move |bit| {
+                    Point2::new(
+                        self.origin.x + (column + bit) as i64,
+                        self.origin.y + row as i64,
+                    )
+                })
+            })
+    }
+
+    /// Draws the points within `bounds` as `#`, and everything else as `.`.
+    pub fn render(&self, bounds: Bounds) -> String {
+        render(bounds, |point| if self.contains(point) { '#' } else { '.' })
+    }
+
+    fn grow_to(&mut self, point: Point2) {
+        let area = if self.words.is_empty() {
+            Bounds::point(point)
+        } else {
+            let (width, height) = (self.width as i64, self.height as i64);
+            let Point2 { x: min_x, y: min_y } = self.origin;
+            let (max_x, max_y) = (min_x + width - 1, min_y + height - 1);
+            let grow = |value: i64, min: i64, max: i64, size: i64| {
+                if value < min {
+                    (cmp::min(value, min - size), max)
+                } else if value > max {
+                    (min, cmp::max(value, max + size))
+                } else {
+                    (min, max)
+                }
+            };
+            let (min_x, max_x) = grow(point.x, min_x, max_x, width);
+            let (min_y, max_y) = grow(point.y, min_y, max_y, height);
+            Bounds {
+                min: Point2::new(min_x, min_y),
+                max: Point2::new(max_x, max_y),
+            }
+        };
+        let mut grown = Self::with_capacity(area);
+        for point in self.iter() {
+            let (word, mask) = grown.slot(point).unwrap();
+            grown.words[word] |= mask;
+        }
+        grown.bounds = self.bounds;
+        grown.len = self.len;
+        *self = grown;
+    }
+}
+
+impl FromIterator<Point2> for BitGrid {
+    fn from_iter<I: IntoIterator<Item = Point2>>(iter: I) -> Self {
+        let mut grid = Self::new();
+        for point in iter {
+            grid.insert(point);
+        }
+        grid
+    }
+}
+
+/// Draws the bounding box with a `#` for each point.
+impl Display for BitGrid {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.bounds {
+            Some(bounds) => write!(f, "{}", self.render(bounds)),
+            None => Ok(()),
+        }
+    }
+}
+
+/// The indices of the set bits in a word, from the lowest.
+struct BitIter(u64);
+
+impl Iterator for BitIter {
+    type Item = usize;
+
+    #[inline]
+    fn next(&mut self) -> Option<usize> {
+        if self.0 == 0 {
+            return None;
+        }
+        let bit = self.0.trailing_zeros() as usize;
+        self.0 &= self.0 - 1;
+        Some(bit)
+    }
+}
+
+fn include(bounds: &mut Option<Bounds>, point: Point2) {
+    match bounds {
+        Some(bounds) => bounds.include(point),
+        None => *bounds = Some(Bounds::point(point)),
+    }
+}
+
+fn render(bounds: Bounds, mut cell: impl FnMut(Point2) -> char) -> String {
+    let mut output = String::with_capacity((bounds.width() + 1) * bounds.height());
+    for y in bounds.ys() {
+        output.extend(bounds.xs().map(|x| cell(Point2::new(x, y))));
+        output.push('\n');
+    }
+    output
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_bounds() {
+        let mut bounds = Bounds::point(Point2::new(2, -1));
+        bounds.include(Point2::new(-3, 4));
+        assert_eq!(
+            bounds,
+            Bounds {
+                min: Point2::new(-3, -1),
+                max: Point2::new(2, 4)
+            }
+        );
+        assert_eq!((bounds.width(), bounds.height()), (6, 6));
+        assert!(bounds.contains(Point2::new(0, 0)));
+        assert!(!bounds.contains(Point2::new(3, 0)));
+        let other = Bounds {
+            min: Point2::new(1, 3),
+            max: Point2::new(5, 5),
+        };
+        assert_eq!(
+            bounds.intersection(&other),
+            Some(Bounds {
+                min: Point2::new(1, 3),
+                max: Point2::new(2, 4)
+            })
+        );
+        assert_eq!(bounds.intersection(&Bounds::point(Point2::new(9, 9))), None);
+    }
+
+    #[test]
+    fn test_sparse_grid() {
+        let mut grid: SparseGrid<char> = [(Point2::new(0, 0), 'a'), (Point2::new(-2, 1), 'b')]
+            .into_iter()
+            .collect();
+        assert_eq!(grid.insert(Point2::new(0, 0), 'c'), Some('a'));
+        assert_eq!(grid.get(Point2::new(-2, 1)), Some(&'b'));
+        assert!(!grid.contains(Point2::new(-1, 0)));
+        assert_eq!(grid.len(), 2);
+        assert_eq!(grid.to_string(), "..c\nb..\n");
+        let crop = Bounds {
+            min: Point2::new(-1, 0),
+            max: Point2::new(0, 1),
+        };
+        assert_eq!(grid.render(crop, |cell| *cell.unwrap_or(&' ')), " c\n  \n");
+        assert_eq!(grid.remove(Point2::new(-2, 1)), Some('b'));
+        assert_eq!(
+            grid.bounds().map(|bounds| bounds.min),
+            Some(Point2::new(-2, 0))
+        );
+    }
+
+    #[test]
+    fn test_bit_grid() {
+        let mut grid = BitGrid::new();
+        assert!(grid.insert(Point2::new(0, 0)));
+        assert!(!grid.insert(Point2::new(0, 0)));
+        // Grows left, up, right and down, including past a word boundary.
+        for point in [
+            Point2::new(-1, 0),
+            Point2::new(0, -3),
+            Point2::new(70, 0),
+            Point2::new(5, 200),
+        ] {
+            assert!(grid.insert(point));
+        }
+        assert_eq!(grid.len(), 5);
+        for point in [
+            Point2::new(0, 0),
+            Point2::new(-1, 0),
+            Point2::new(0, -3),
+            Point2::new(70, 0),
+            Point2::new(5, 200),
+        ] {
+            assert!(grid.contains(point), "{point:?}");
+        }
+        assert!(!grid.contains(Point2::new(1, 0)));
+        assert!(!grid.contains(Point2::new(-100, -100)));
+        let mut points = grid.iter().collect::<Vec<_>>();
+        points.sort_unstable();
+        assert_eq!(
+            points,
+            [
+                Point2::new(-1, 0),
+                Point2::new(0, -3),
+                Point2::new(0, 0),
+                Point2::new(5, 200),
+                Point2::new(70, 0)
+            ]
+        );
+        assert!(grid.remove(Point2::new(70, 0)));
+        assert!(!grid.remove(Point2::new(70, 0)));
+        assert_eq!(grid.len(), 4);
+
+        let grid: BitGrid = [Point2::new(0, 0), Point2::new(1, 1), Point2::new(3, 1)]
+            .into_iter()
+            .collect();
+        assert_eq!(grid.to_string(), "#...\n.#.#\n");
+        let crop = Bounds {
+            min: Point2::new(1, 0),
+            max: Point2::new(2, 2),
+        };
+        assert_eq!(grid.render(crop), "..\n#.\n..\n");
+    }
+}
@@ -0,0 +1,54 @@
+use anyhow::{Context, Result};
+use std::fs;
+use std::path::PathBuf;
+
+/// Downloaded puzzle inputs, stored as `<year>/day_<day>.txt` so that
+/// regenerating a crate never downloads its input again.
+pub struct InputCache {
+    path: PathBuf,
+}
+
+impl InputCache {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    fn input_path(&self, year: u32, day: u32) -> PathBuf {
+        self.path
+            .join(year.to_string())
+            .join(format!("day_{day:02}.txt"))
+    }
+
+    pub fn get(&self, year: u32, day: u32) -> Result<Option<String>> {
+        let input_path = self.input_path(year, day);
+        if !input_path.exists() {
+            return Ok(None);
+        }
+        let input = fs::read_to_string(&input_path)
+            .with_context(|| format!("Failed to read {}", input_path.display()))?;
+        Ok(Some(input))
+    }
+
+    pub fn insert(&self, year: u32, day: u32, input: &str) -> Result<()> {
+        let input_path = self.input_path(year, day);
+        fs::create_dir_all(input_path.parent().unwrap())?;
+        fs::write(&input_path, input)
+            .with_context(|| format!("Failed to write {}", input_path.display()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_input_cache() {
+        let path = tempfile::tempdir().unwrap();
+        let cache = InputCache::new(path.path());
+        assert_eq!(cache.get(2023, 10).unwrap(), None);
+        cache.insert(2023, 10, "..F7.\n").unwrap();
+        assert_eq!(cache.get(2023, 10).unwrap().as_deref(), Some("..F7.\n"));
+        assert_eq!(cache.get(2023, 11).unwrap(), None);
+        assert!(path.path().join("2023/day_10.txt").exists());
+    }
+}
@@ -0,0 +1,217 @@
+use anyhow::{bail, ensure, Result};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// The first year of Advent of Code.
+pub const FIRST_YEAR: u32 = 2015;
+
+/// Puzzles unlock at midnight in UTC-5.
+const UTC_OFFSET_SECONDS: i64 = -5 * 60 * 60;
+
+/// Tells the current time, so that the calendar can be tested at fixed dates.
+pub trait Clock {
+    fn now(&self) -> SystemTime;
+}
+
+/// The clock of the operating system.
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+impl Clock for SystemTime {
+    fn now(&self) -> SystemTime {
+        *self
+    }
+}
+
+/// A date in the puzzles' time zone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Date {
+    pub year: u32,
+    pub month: u32,
+    pub day: u32,
+}
+
+impl Date {
+    pub fn today(clock: &impl Clock) -> Self {
+        let seconds = match clock.now().duration_since(UNIX_EPOCH) {
+            Ok(duration) => duration.as_secs() as i64,
+            Err(error) => -(error.duration().as_secs() as i64),
+        };
+        let days = (seconds + UTC_OFFSET_SECONDS).div_euclid(24 * 60 * 60);
+        Self::from_days_since_epoch(days)
+    }
+
+    /// Converts a number of days since 1970-01-01 to a date, using Howard
+    /// Hinnant's `civil_from_days` algorithm.
+    fn from_days_since_epoch(days: i64) -> Self {
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days.rem_euclid(146_097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+        let month = if shifted_month < 10 {
+            shifted_month + 3
+        } else {
+            shifted_month - 9
+        };
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+        Self {
+            year: year as u32,
+            month: month as u32,
+            day: day as u32,
+        }
+    }
+
+    /// Converts the date to a number of days since 1970-01-01, using Howard
+    /// Hinnant's `days_from_civil` algorithm.
+    fn days_since_epoch(self) -> i64 {
+        let (month, day) = (i64::from(self.month), i64::from(self.day));
+        let year = i64::from(self.year) - i64::from(month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year.rem_euclid(400);
+        let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    /// The day of the week, from 0 for Monday to 6 for Sunday.
+    pub fn weekday(self) -> u32 {
+        // 1970-01-01 was a Thursday.
+        (self.days_since_epoch() + 3).rem_euclid(7) as u32
+    }
+}
+
+/// The number of puzzles in a year's calendar, which shrank from 25 to 12 in
+/// 2025.
+pub fn days_in_year(year: u32) -> u32 {
+    if year >= 2025 {
+        12
+    } else {
+        25
+    }
+}
+
+/// The most recent year with at least one unlocked puzzle.
+pub fn latest_year(clock: &impl Clock) -> u32 {
+    let today = Date::today(clock);
+    if today.month == 12 {
+        today.year
+    } else {
+        today.year - 1
+    }
+}
+
+/// The number of puzzles of a year that are unlocked.
+pub fn unlocked_days(year: u32, clock: &impl Clock) -> u32 {
+    let today = Date::today(clock);
+    if year < FIRST_YEAR || year > today.year || (year == today.year && today.month < 12) {
+        0
+    } else if year == today.year {
+        today.day.min(days_in_year(year))
+    } else {
+        days_in_year(year)
+    }
+}
+
+/// Checks that a day's puzzle exists and is unlocked.
+pub fn ensure_unlocked(year: u32, day: u32, clock: &impl Clock) -> Result<()> {
+    let latest_year = latest_year(clock);
+    ensure!(
+        (FIRST_YEAR..=latest_year).contains(&year),
+        "Year must be in the range {FIRST_YEAR}..={latest_year}"
+    );
+    let days = days_in_year(year);
+    ensure!(
+        (1..=days).contains(&day),
+        "Day must be between 1 and {days}"
+    );
+    if day > unlocked_days(year, clock) {
+        bail!("{year} day {day} unlocks at midnight UTC-5 on December {day}");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::time::Duration;
+
+    fn at(seconds: u64) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(seconds)
+    }
+
+    #[test]
+    fn test_today() {
+        let date = |year, month, day| Date { year, month, day };
+        // 2024-12-01T05:00:00Z and one second earlier.
+        assert_eq!(Date::today(&at(1_733_029_200)), date(2024, 12, 1));
+        assert_eq!(Date::today(&at(1_733_029_199)), date(2024, 11, 30));
+        // 2024-02-29T12:00:00Z.
+        assert_eq!(Date::today(&at(1_709_208_000)), date(2024, 2, 29));
+        assert_eq!(Date::today(&UNIX_EPOCH), date(1969, 12, 31));
+    }
+
+    #[test]
+    fn test_weekday() {
+        let weekday = |year, month, day| Date { year, month, day }.weekday();
+        assert_eq!(weekday(1970, 1, 1), 3);
+        assert_eq!(weekday(2022, 12, 1), 3);
+        assert_eq!(weekday(2023, 12, 1), 4);
+        assert_eq!(weekday(2024, 12, 1), 6);
+        assert_eq!(weekday(2024, 2, 29), 3);
+        assert_eq!(weekday(2015, 12, 1), 1);
+    }
+
+    #[test]
+    fn test_latest_year() {
+        // 2024-11-30T12:00:00Z.
+        assert_eq!(latest_year(&at(1_732_968_000)), 2023);
+        // 2024-12-01T05:00:00Z.
+        assert_eq!(latest_year(&at(1_733_029_200)), 2024);
+        // 2025-01-10T00:00:00Z.
+        assert_eq!(latest_year(&at(1_736_467_200)), 2024);
+    }
+
+    #[test]
+    fn test_unlocked_days() {
+        // 2023-12-25T04:59:59Z and one second later.
+        assert_eq!(unlocked_days(2023, &at(1_703_480_399)), 24);
+        assert_eq!(unlocked_days(2023, &at(1_703_480_400)), 25);
+        assert_eq!(unlocked_days(2022, &at(1_703_480_400)), 25);
+        assert_eq!(unlocked_days(2024, &at(1_703_480_400)), 0);
+        assert_eq!(unlocked_days(2014, &at(1_703_480_400)), 0);
+        // 2025-12-12T05:00:00Z.
+        assert_eq!(unlocked_days(2025, &at(1_765_515_600)), 12);
+        // 2024-11-30T12:00:00Z.
+        assert_eq!(unlocked_days(2024, &at(1_732_968_000)), 0);
+    }
+
+    #[test]
+    fn test_ensure_unlocked() {
+        // 2024-12-01T05:00:00Z.
+        let clock = at(1_733_029_200);
+        assert!(ensure_unlocked(2015, 1, &clock).is_ok());
+        assert!(ensure_unlocked(2024, 1, &clock).is_ok());
+        assert_eq!(
+            ensure_unlocked(2024, 2, &clock).unwrap_err().to_string(),
+            "2024 day 2 unlocks at midnight UTC-5 on December 2"
+        );
+        assert!(ensure_unlocked(2014, 1, &clock).is_err());
+        assert!(ensure_unlocked(2025, 1, &clock).is_err());
+        assert!(ensure_unlocked(2023, 26, &clock).is_err());
+        // 2025-12-12T05:00:00Z.
+        let clock = at(1_765_515_600);
+        assert!(ensure_unlocked(2025, 12, &clock).is_ok());
+        assert_eq!(
+            ensure_unlocked(2025, 13, &clock).unwrap_err().to_string(),
+            "Day must be between 1 and 12"
+        );
+    }
+}
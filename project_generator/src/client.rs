@@ -0,0 +1,80 @@
+use anyhow::{bail, Context, Result};
+use reqwest::blocking::RequestBuilder;
+use reqwest::StatusCode;
+
+pub const ADVENT_OF_CODE_URL: &str = "https://adventofcode.com";
+
+/// Talks to the Advent of Code website, or to any server at `base_url` that
+/// mimics it.
+pub struct Client {
+    base_url: String,
+    cookie: String,
+    client: reqwest::blocking::Client,
+}
+
+impl Client {
+    /// Creates a client sending `cookie`, which is either a whole
+    /// `session=<token>` cookie or just the token.
+    pub fn new(base_url: &str, cookie: &str) -> Self {
+        let cookie = cookie.trim();
+        let cookie = if cookie.contains('=') {
+            cookie.to_owned()
+        } else {
+            format!("session={cookie}")
+        };
+        Self {
+            base_url: base_url.trim_end_matches('/').to_owned(),
+            cookie,
+            client: reqwest::blocking::Client::new(),
+        }
+    }
+
+    /// Downloads the personal puzzle input of a day.
+    pub fn fetch_input(&self, year: u32, day: u32) -> Result<String> {
+        self.get(&format!("{}/{}/day/{}/input", self.base_url, year, day))
+    }
+
+    /// Downloads the puzzle page of a day, which includes part two once part
+    /// one is solved.
+    pub fn fetch_puzzle(&self, year: u32, day: u32) -> Result<String> {
+        self.get(&format!("{}/{}/day/{}", self.base_url, year, day))
+    }
+
+    /// Submits an answer to one part of a day and returns the response page.
+    pub fn submit_answer(&self, year: u32, day: u32, part: u32, answer: &str) -> Result<String> {
+        let url = format!("{}/{}/day/{}/answer", self.base_url, year, day);
+        let request = self
+            .client
+            .post(&url)
+            .form(&[("level", &*part.to_string()), ("answer", answer)]);
+        self.send(&url, request)
+    }
+
+    fn get(&self, url: &str) -> Result<String> {
+        self.send(url, self.client.get(url))
+    }
+
+    fn send(&self, url: &str, request: RequestBuilder) -> Result<String> {
+        let response = request
+            .header("cookie", &self.cookie)
+            .send()
+            .with_context(|| format!("Failed to connect to {url}"))?;
+        let status = response.status();
+        let body = response.text()?;
+        match status {
+            status if status.is_success() => Ok(body),
+            StatusCode::BAD_REQUEST | StatusCode::UNAUTHORIZED if body.contains("log in") => bail!(
+                "The session cookie was rejected, it has probably expired. Log in to the \
+                 website again and save the new session cookie"
+            ),
+            StatusCode::NOT_FOUND => {
+                bail!("Requesting {url} failed with {status}, the puzzle may not be unlocked yet")
+            }
+            status if status.is_server_error() => bail!(
+                "Requesting {url} failed with {status}, the website may be down: {}",
+                body.trim()
+            ),
+            status => bail!("Requesting {url} failed with {status}: {}", body.trim()),
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use anyhow::{bail, Context, Result};
+use serde::Deserialize;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+/// The environment variable that overrides where the session cookie is read
+/// from.
+pub const COOKIE_PATH_VAR: &str = "AOC_COOKIE_PATH";
+
+/// Where the session cookie is read from when neither the environment nor
+/// the config file say otherwise.
+pub const DEFAULT_COOKIE_PATH: &str = "project_generator/cookie";
+
+/// Local settings, read from a TOML file that isn't checked in.
+#[derive(Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// The file holding the session cookie, relative to the workspace.
+    pub cookie_path: Option<PathBuf>,
+    /// The server to talk to instead of adventofcode.com.
+    pub base_url: Option<String>,
+}
+
+impl Config {
+    /// Loads the config at `path`, or the default one if there is no file.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        toml::from_str(&contents).with_context(|| format!("Failed to parse {}", path.display()))
+    }
+
+    /// The file holding the session cookie: `override_path` if given, then
+    /// the config's `cookie_path`, then [`DEFAULT_COOKIE_PATH`].
+    pub fn cookie_path(&self, override_path: Option<&Path>) -> PathBuf {
+        override_path
+            .or(self.cookie_path.as_deref())
+            .unwrap_or(Path::new(DEFAULT_COOKIE_PATH))
+            .to_owned()
+    }
+}
+
+/// Reads the session cookie, explaining where to put it if it's missing.
+pub fn read_cookie(path: &Path) -> Result<String> {
+    let cookie = match fs::read_to_string(path) {
+        Ok(cookie) => cookie,
+        Err(error) if error.kind() == ErrorKind::NotFound => bail!(
+            "No session cookie found at {}. Log in to adventofcode.com and save the value of \
+             its session cookie there, or set {COOKIE_PATH_VAR} or cookie_path in the config \
+             to the file holding it",
+            path.display()
+        ),
+        Err(error) => {
+            return Err(error).with_context(|| {
+                format!("Failed to read the session cookie from {}", path.display())
+            })
+        }
+    };
+    let cookie = cookie.trim();
+    if cookie.is_empty() {
+        bail!("The session cookie file {} is empty", path.display());
+    }
+    Ok(cookie.to_owned())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_load() {
+        let path = tempfile::tempdir().unwrap();
+        let config_path = path.path().join("config.toml");
+        assert_eq!(Config::load(&config_path).unwrap(), Config::default());
+        fs::write(&config_path, "cookie_path = \"/home/me/.aoc_cookie\"\n").unwrap();
+        let config = Config::load(&config_path).unwrap();
+        assert_eq!(config.cookie_path(None), Path::new("/home/me/.aoc_cookie"));
+        assert_eq!(
+            config.cookie_path(Some(Path::new("cookie"))),
+            Path::new("cookie")
+        );
+        assert_eq!(
+            Config::default().cookie_path(None),
+            Path::new(DEFAULT_COOKIE_PATH)
+        );
+        fs::write(&config_path, "cookie = \"abc\"\n").unwrap();
+        assert!(Config::load(&config_path).is_err());
+    }
+
+    #[test]
+    fn test_read_cookie() {
+        let path = tempfile::tempdir().unwrap();
+        let cookie_path = path.path().join("cookie");
+        let error = read_cookie(&cookie_path).unwrap_err().to_string();
+        assert!(error.starts_with("No session cookie found at"), "{error}");
+        assert!(error.contains(COOKIE_PATH_VAR), "{error}");
+        fs::write(&cookie_path, "\n").unwrap();
+        assert!(read_cookie(&cookie_path).is_err());
+        fs::write(&cookie_path, "session=abc\n").unwrap();
+        assert_eq!(read_cookie(&cookie_path).unwrap(), "session=abc");
+    }
+}
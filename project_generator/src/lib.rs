@@ -0,0 +1,20 @@
+mod cache;
+pub mod calendar;
+mod client;
+mod config;
+mod puzzle;
+mod scaffold;
+mod status;
+mod submission;
+mod template;
+
+pub use cache::InputCache;
+pub use client::{Client, ADVENT_OF_CODE_URL};
+pub use config::{read_cookie, Config, COOKIE_PATH_VAR, DEFAULT_COOKIE_PATH};
+pub use puzzle::{parse_puzzle, Puzzle};
+pub use scaffold::{create_crate, write_puzzle, CrateInfo};
+pub use status::{
+    day_status, render_calendar, render_table, scan_workspace, DayStatus, Progress, YearStatus,
+};
+pub use submission::{parse_response, Response, Submission, Submissions, Verdict};
+pub use template::TemplateSet;
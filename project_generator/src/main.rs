@@ -1,61 +1,418 @@
-use anyhow::{anyhow, ensure, Context, Result};
-use reqwest::blocking::Client;
+use anyhow::{bail, ensure, Context, Result};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use project_generator::calendar::{self, SystemClock};
+use project_generator::{
+    create_crate, parse_puzzle, parse_response, read_cookie, render_calendar, render_table,
+    scan_workspace, write_puzzle, Client, Config, CrateInfo, InputCache, Submission, Submissions,
+    TemplateSet, Verdict, ADVENT_OF_CODE_URL, COOKIE_PATH_VAR,
+};
 use std::env;
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::process::{self, Command, Stdio};
 
-const LATEST_YEAR: u32 = 2024;
-const EARLIEST_YEAR: u32 = 2015;
+const CONFIG_PATH: &str = "project_generator/config.toml";
 
-const ADVENT_OF_CODE_URL: &str = "https://adventofcode.com";
-
-const COOKIE_PATH: &str = "project_generator/cookie";
+const CACHE_PATH: &str = "project_generator/cache";
 
 const TEMPLATES_PATH: &str = "project_generator/templates";
-const CARGO_TEMPLATE: &str = "Cargo.toml.template";
-const MAIN_TEMPLATE: &str = "main.rs.template";
+const DEFAULT_TEMPLATE: &str = "lib";
+
+const WORKSPACE_MANIFEST_PATH: &str = "Cargo.toml";
+
+const SUBMISSIONS_PATH: &str = "project_generator/submissions.toml";
+
+/// Generates the crates of Advent of Code puzzles and talks to the website.
+///
+/// Run it from the root of the workspace. The session cookie is read from the
+/// file given by --cookie, AOC_COOKIE_PATH or cookie_path in
+/// project_generator/config.toml, and project_generator/cookie otherwise.
+#[derive(Parser)]
+#[command(version)]
+struct Cli {
+    /// Read the session cookie from this file
+    #[arg(long, global = true, env = COOKIE_PATH_VAR, value_name = "PATH")]
+    cookie: Option<PathBuf>,
+    /// Talk to a server other than adventofcode.com
+    #[arg(long, global = true, value_name = "URL")]
+    base_url: Option<String>,
+    #[command(subcommand)]
+    command: CliCommand,
+}
+
+#[derive(Subcommand)]
+enum CliCommand {
+    /// Generate the crate of a day, with its input, description and examples
+    New {
+        #[command(flatten)]
+        day: DayArgs,
+        /// Generate the crate from this set of templates in
+        /// project_generator/templates
+        #[arg(long, default_value = DEFAULT_TEMPLATE, value_name = "NAME")]
+        template: String,
+        /// Add workspace dependencies to the crate
+        #[arg(long, value_delimiter = ',', value_name = "CRATE,...")]
+        with: Vec<String>,
+        /// Don't download anything, only use cached inputs and skip the
+        /// puzzle description
+        #[arg(long)]
+        offline: bool,
+        /// Overwrite existing crate files, examples and input
+        #[arg(long)]
+        force: bool,
+    },
+    /// Write the input of an existing crate, downloading it unless cached
+    FetchInput {
+        #[command(flatten)]
+        day: DayArgs,
+    },
+    /// Write the description and examples of an existing crate, for example
+    /// to get part two once part one is solved
+    FetchPuzzle {
+        #[command(flatten)]
+        day: DayArgs,
+        /// Overwrite existing examples
+        #[arg(long)]
+        force: bool,
+    },
+    /// Submit the answer to one part of a day
+    Submit {
+        #[command(flatten)]
+        day: DayArgs,
+        #[arg(value_enum)]
+        part: Part,
+        /// Submit this answer instead of running the solution
+        #[arg(long)]
+        answer: Option<String>,
+    },
+    /// List the template sets and the crates generated for each year
+    List,
+    /// Report for each day whether parsing and both parts are implemented,
+    /// tested and benchmarked
+    Status {
+        /// Only report on this year
+        #[arg(value_parser = parse_year)]
+        year: Option<u32>,
+        /// Render a markdown calendar instead of a table
+        #[arg(long)]
+        markdown: bool,
+    },
+}
+
+#[derive(Args)]
+struct DayArgs {
+    /// The year of the puzzle, where 23 means 2023
+    #[arg(value_parser = parse_year)]
+    year: u32,
+    /// The day of the puzzle
+    day: u32,
+}
+
+impl DayArgs {
+    fn project_path(&self) -> PathBuf {
+        PathBuf::from(format!("year_{}/day_{:02}", self.year, self.day))
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Part {
+    #[value(alias = "1")]
+    One,
+    #[value(alias = "2")]
+    Two,
+}
+
+impl Part {
+    fn number(self) -> u32 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+fn parse_year(year: &str) -> Result<u32> {
+    let year = year.parse::<u32>().context("Year must be a number")?;
+    Ok(if year < 100 { year + 2000 } else { year })
+}
+
+/// Reads the session cookie and talks to the website lazily, so that
+/// commands which find everything in the cache need neither.
+struct Session {
+    base_url: String,
+    cookie_path: PathBuf,
+    client: Option<Client>,
+}
+
+impl Session {
+    fn new(cli: &Cli) -> Result<Self> {
+        let config = Config::load(Path::new(CONFIG_PATH))?;
+        let base_url = cli
+            .base_url
+            .clone()
+            .or(config.base_url.clone())
+            .unwrap_or_else(|| String::from(ADVENT_OF_CODE_URL));
+        Ok(Self {
+            base_url,
+            cookie_path: config.cookie_path(cli.cookie.as_deref()),
+            client: None,
+        })
+    }
+
+    fn client(&mut self) -> Result<&Client> {
+        if self.client.is_none() {
+            let cookie = read_cookie(&self.cookie_path)?;
+            self.client = Some(Client::new(&self.base_url, &cookie));
+        }
+        Ok(self.client.as_ref().unwrap())
+    }
+}
 
 fn main() -> Result<()> {
-    let (year, day_number) = read_args()?;
-    let crate_name = format!("year_{year}_day_{day_number:02}");
-    let project_path = PathBuf::from(format!("year_{year}/day_{day_number:02}"));
-    let src_path = project_path.join("src");
-    fs::create_dir_all(&src_path)?;
-    let pairs = [
-        (CARGO_TEMPLATE, project_path.join("Cargo.toml")),
-        (MAIN_TEMPLATE, src_path.join("main.rs")),
-    ];
-    let templates_path = Path::new(TEMPLATES_PATH);
-    for (template_name, output_path) in pairs {
-        let template_path = templates_path.join(template_name);
-        let template = fs::read_to_string(template_path)?;
-        let rendered = template.replace("{{ crate_name }}", &crate_name);
-        fs::write(output_path, rendered)?;
-    }
-    let url = format!("{}/{}/day/{}/input", ADVENT_OF_CODE_URL, year, day_number);
-    let cookie = fs::read_to_string(COOKIE_PATH)?;
-    let client = Client::new();
-    let response = client.get(url).header("cookie", cookie.trim()).send()?;
-    let input_path = project_path.join("input.txt");
-    fs::write(input_path, response.text()?)?;
+    let cli = Cli::parse();
+    let mut session = Session::new(&cli)?;
+    match &cli.command {
+        CliCommand::New {
+            day,
+            template,
+            with,
+            offline,
+            force,
+        } => {
+            calendar::ensure_unlocked(day.year, day.day, &SystemClock)?;
+            let workspace_dependencies = workspace_dependencies()?;
+            for dependency in with {
+                ensure!(
+                    workspace_dependencies.contains(dependency),
+                    "{dependency} is not a workspace dependency, add it to {WORKSPACE_MANIFEST_PATH} first"
+                );
+            }
+            let template_set = TemplateSet::load(Path::new(TEMPLATES_PATH), template)?;
+            let puzzle = if *offline {
+                None
+            } else {
+                let html = session.client()?.fetch_puzzle(day.year, day.day)?;
+                Some(parse_puzzle(&html)?)
+            };
+            let project_path = day.project_path();
+            let info = CrateInfo {
+                year: day.year,
+                day: day.day,
+                title: puzzle
+                    .as_ref()
+                    .map(|puzzle| puzzle.title.clone())
+                    .filter(|title| !title.is_empty()),
+                dependencies: with.clone(),
+            };
+            let kept_paths = create_crate(&template_set, &project_path, &info, *force)?;
+            for path in kept_paths {
+                println!("Kept existing {}", path.display());
+            }
+            let input_path = project_path.join("input.txt");
+            // An empty input is the placeholder left by an offline run.
+            let has_input = fs::metadata(&input_path).is_ok_and(|metadata| metadata.len() > 0);
+            if has_input && !force {
+                println!("Kept existing {}", input_path.display());
+            } else {
+                write_input(&mut session, day, *offline)?;
+            }
+            if let Some(puzzle) = &puzzle {
+                for path in write_puzzle(&project_path, puzzle, *force)? {
+                    println!("Kept existing {}", path.display());
+                }
+            }
+        }
+        CliCommand::FetchInput { day } => {
+            calendar::ensure_unlocked(day.year, day.day, &SystemClock)?;
+            ensure_crate_exists(day)?;
+            write_input(&mut session, day, false)?;
+        }
+        CliCommand::FetchPuzzle { day, force } => {
+            calendar::ensure_unlocked(day.year, day.day, &SystemClock)?;
+            ensure_crate_exists(day)?;
+            let html = session.client()?.fetch_puzzle(day.year, day.day)?;
+            let puzzle = parse_puzzle(&html)?;
+            for path in write_puzzle(&day.project_path(), &puzzle, *force)? {
+                println!("Kept existing {}", path.display());
+            }
+        }
+        CliCommand::Submit { day, part, answer } => {
+            calendar::ensure_unlocked(day.year, day.day, &SystemClock)?;
+            submit_answer(&mut session, day, part.number(), answer.as_deref())?;
+        }
+        CliCommand::List => list()?,
+        CliCommand::Status { year, markdown } => {
+            let mut years = scan_workspace(Path::new("."), &SystemClock)?;
+            if let Some(year) = year {
+                years.retain(|status| status.year == *year);
+                ensure!(!years.is_empty(), "There are no crates for {year}");
+            }
+            if *markdown {
+                print!("{}", render_calendar(&years));
+            } else {
+                print!("{}", render_table(&years));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn ensure_crate_exists(day: &DayArgs) -> Result<()> {
+    let project_path = day.project_path();
+    ensure!(
+        project_path.exists(),
+        "{} does not exist, generate it with `project_generator new {} {}` first",
+        project_path.display(),
+        day.year,
+        day.day
+    );
+    Ok(())
+}
+
+/// Writes the input of the day, from the cache if possible. Offline, a
+/// missing input is replaced with an empty placeholder.
+fn write_input(session: &mut Session, day: &DayArgs, offline: bool) -> Result<()> {
+    let input_path = day.project_path().join("input.txt");
+    let cache = InputCache::new(CACHE_PATH);
+    let input = match cache.get(day.year, day.day)? {
+        Some(input) => input,
+        None if offline => {
+            // The crate includes its input at compile time, so it needs a
+            // placeholder until the input is downloaded.
+            if !input_path.exists() {
+                fs::write(input_path, "")?;
+            }
+            return Ok(());
+        }
+        None => {
+            let input = session.client()?.fetch_input(day.year, day.day)?;
+            cache.insert(day.year, day.day, &input)?;
+            input
+        }
+    };
+    fs::write(input_path, input)?;
     Ok(())
 }
 
-fn read_args() -> Result<(u32, u32)> {
-    let mut args = env::args().skip(1);
-    let year_str = args.next().ok_or_else(|| anyhow!("No year provided"))?;
-    let mut year = year_str.parse().context("Year must be a number")?;
-    if year < 100 {
-        year += 2000;
+/// Submits the answer to one part, which is found by running the day's
+/// solution unless given, and exits with an error unless it was correct.
+fn submit_answer(
+    session: &mut Session,
+    day: &DayArgs,
+    part: u32,
+    answer: Option<&str>,
+) -> Result<()> {
+    let answer = match answer {
+        Some(answer) => answer.to_owned(),
+        None => run_part(day.year, day.day, part)?,
+    };
+    let mut submissions = Submissions::load(SUBMISSIONS_PATH)?;
+    if let Some(submission) = submissions.ruled_out_by(day.year, day.day, part, &answer) {
+        bail!(
+            "Not submitting {answer}, since {} was already submitted for this part and was {}",
+            submission.answer,
+            submission.verdict
+        );
+    }
+    println!(
+        "Submitting {answer} for {} day {} part {part}",
+        day.year, day.day
+    );
+    let html = session
+        .client()?
+        .submit_answer(day.year, day.day, part, &answer)?;
+    let response = parse_response(&html)?;
+    println!("{}", response.message);
+    submissions.record(Submission {
+        year: day.year,
+        day: day.day,
+        part,
+        answer,
+        verdict: response.verdict,
+    });
+    submissions.save()?;
+    if response.verdict != Verdict::Correct {
+        process::exit(1);
     }
+    Ok(())
+}
+
+/// Runs one part of a day's solution and returns the answer it prints.
+fn run_part(year: u32, day: u32, part: u32) -> Result<String> {
+    let crate_name = format!("year_{year}_day_{day:02}");
+    let part_arg = if part == 1 { "one" } else { "two" };
+    let output = Command::new(env::var("CARGO").unwrap_or_else(|_| String::from("cargo")))
+        .args([
+            "run",
+            "--release",
+            "--quiet",
+            "-p",
+            &crate_name,
+            "--",
+            part_arg,
+        ])
+        .stderr(Stdio::inherit())
+        .output()
+        .context("Failed to run cargo")?;
     ensure!(
-        (EARLIEST_YEAR..=LATEST_YEAR).contains(&year),
-        "Year must be in the range {}..={}",
-        EARLIEST_YEAR,
-        LATEST_YEAR
+        output.status.success(),
+        "Running {crate_name} failed with {}",
+        output.status
     );
-    let day_str = args.next().ok_or_else(|| anyhow!("No day provided"))?;
-    let day = day_str.parse().context("Day must be a number")?;
-    ensure!((1..=25).contains(&day), "Day must be between 1 and 25");
-    Ok((year, day))
+    let answer = String::from_utf8(output.stdout)?.trim().to_owned();
+    ensure!(
+        !answer.is_empty() && !answer.contains('\n'),
+        "{crate_name} printed {answer:?}, which isn't a single answer; pass one with --answer"
+    );
+    Ok(answer)
+}
+
+/// Prints the template sets and, for each year, the days that have a crate
+/// out of those unlocked.
+fn list() -> Result<()> {
+    println!("Template sets:");
+    let template_sets = TemplateSet::list(Path::new(TEMPLATES_PATH))?;
+    let width = template_sets
+        .iter()
+        .map(|template_set| template_set.name.len())
+        .max()
+        .unwrap_or(0);
+    for template_set in &template_sets {
+        println!(
+            "  {:width$}  {}",
+            template_set.name, template_set.description
+        );
+    }
+    println!("Crates:");
+    for year in calendar::FIRST_YEAR..=calendar::latest_year(&SystemClock) {
+        let unlocked_days = calendar::unlocked_days(year, &SystemClock);
+        let days = (1..=unlocked_days)
+            .filter(|day| Path::new(&format!("year_{year}/day_{day:02}")).exists())
+            .map(|day| day.to_string())
+            .collect::<Vec<_>>();
+        if !days.is_empty() {
+            println!(
+                "  {year}  {} of {unlocked_days} days: {}",
+                days.len(),
+                days.join(" ")
+            );
+        }
+    }
+    Ok(())
+}
+
+/// The names of the dependencies declared by the workspace, which generated
+/// crates can use with `<name>.workspace = true`.
+fn workspace_dependencies() -> Result<Vec<String>> {
+    let contents = fs::read_to_string(WORKSPACE_MANIFEST_PATH)
+        .with_context(|| format!("Failed to read {WORKSPACE_MANIFEST_PATH}"))?;
+    let manifest: toml::Table = toml::from_str(&contents)
+        .with_context(|| format!("Failed to parse {WORKSPACE_MANIFEST_PATH}"))?;
+    let dependencies = manifest
+        .get("workspace")
+        .and_then(|workspace| workspace.get("dependencies"))
+        .and_then(toml::Value::as_table)
+        .map(|dependencies| dependencies.keys().cloned().collect())
+        .unwrap_or_default();
+    Ok(dependencies)
 }
@@ -0,0 +1,302 @@
+use crate::ADVENT_OF_CODE_URL;
+use anyhow::{ensure, Result};
+
+/// The parts of a puzzle page worth keeping next to the solution.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Puzzle {
+    /// The title of the day, such as `Calorie Counting`.
+    pub title: String,
+    /// The puzzle description converted to Markdown.
+    pub readme: String,
+    /// The contents of every distinct `<pre><code>` block, in page order.
+    pub examples: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Token<'a> {
+    Open { name: &'a str, attributes: &'a str },
+    Close(&'a str),
+    Text(&'a str),
+}
+
+/// Extracts the puzzle description from a puzzle page. Part two is only
+/// included once part one has been solved.
+pub fn parse_puzzle(html: &str) -> Result<Puzzle> {
+    let mut converter = Converter::default();
+    let mut rest = html;
+    while let Some(start) = rest.find("<article") {
+        let article = &rest[start..];
+        let end = article.find("</article>").unwrap_or(article.len());
+        converter.convert(&tokenize(&article[..end]));
+        rest = &article[end..];
+    }
+    ensure!(
+        !converter.markdown.is_empty(),
+        "No puzzle description found on the page"
+    );
+    Ok(Puzzle {
+        title: converter.title.unwrap_or_default(),
+        readme: converter.markdown.trim_end().to_owned() + "\n",
+        examples: converter.examples,
+    })
+}
+
+/// The text of the first `<article>` on a page, with the markup removed and
+/// whitespace collapsed.
+pub(crate) fn article_text(html: &str) -> Option<String> {
+    let start = html.find("<article")?;
+    let article = &html[start..];
+    let end = article.find("</article>").unwrap_or(article.len());
+    let text = tokenize(&article[..end])
+        .into_iter()
+        .filter_map(|token| match token {
+            Token::Text(text) => Some(decode_entities(text)),
+            _ => None,
+        })
+        .collect::<String>();
+    Some(text.split_whitespace().collect::<Vec<_>>().join(" "))
+}
+
+/// Splits HTML into tags and text, skipping comments and declarations.
+fn tokenize(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    let mut rest = html;
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            tokens.push(Token::Text(rest));
+            break;
+        };
+        if start > 0 {
+            tokens.push(Token::Text(&rest[..start]));
+        }
+        rest = &rest[start..];
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+            continue;
+        }
+        let Some(end) = rest.find('>') else {
+            tokens.push(Token::Text(rest));
+            break;
+        };
+        let tag = rest[1..end].trim_end_matches('/').trim();
+        rest = &rest[end + 1..];
+        if let Some(name) = tag.strip_prefix('/') {
+            tokens.push(Token::Close(name.trim()));
+        } else if !tag.starts_with('!') {
+            let (name, attributes) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+            tokens.push(Token::Open { name, attributes });
+        }
+    }
+    tokens
+}
+
+#[derive(Default)]
+struct Converter {
+    markdown: String,
+    title: Option<String>,
+    examples: Vec<String>,
+    /// Where the heading currently being converted starts in `markdown`.
+    heading_start: Option<usize>,
+    /// The contents of the `<pre>` block currently being converted.
+    pre: Option<String>,
+    hrefs: Vec<String>,
+}
+
+impl Converter {
+    fn convert(&mut self, tokens: &[Token]) {
+        let mut i = 0;
+        while i < tokens.len() {
+            // AoC highlights answers with `<code><em>..</em></code>`, which
+            // Markdown can only express the other way around.
+            if let [Token::Open { name: "code", .. }, Token::Open { name: "em", .. }, Token::Text(text), Token::Close("em"), Token::Close("code"), ..] =
+                tokens[i..]
+            {
+                if self.pre.is_none() {
+                    self.markdown.push_str("**`");
+                    self.markdown.push_str(&decode_entities(text));
+                    self.markdown.push_str("`**");
+                    i += 5;
+                    continue;
+                }
+            }
+            self.convert_token(&tokens[i]);
+            i += 1;
+        }
+    }
+
+    fn convert_token(&mut self, token: &Token) {
+        if let Some(pre) = &mut self.pre {
+            match *token {
+                Token::Text(text) => pre.push_str(&decode_entities(text)),
+                Token::Close("pre") => self.end_pre(),
+                _ => {}
+            }
+            return;
+        }
+        match *token {
+            Token::Open { name: "h2", .. } => self.heading_start = Some(self.markdown.len()),
+            Token::Close("h2") => self.end_heading(),
+            Token::Open { name: "li", .. } => self.markdown.push_str("- "),
+            Token::Close("li") => self.end_line(),
+            Token::Close("p" | "ul") => self.end_block(),
+            Token::Open { name: "pre", .. } => self.pre = Some(String::new()),
+            Token::Open { name: "em", .. } | Token::Close("em") => self.markdown.push_str("**"),
+            Token::Open { name: "code", .. } | Token::Close("code") => self.markdown.push('`'),
+            Token::Open {
+                name: "a",
+                attributes,
+            } => {
+                let mut href = attribute(attributes, "href").unwrap_or_default();
+                if href.starts_with('/') {
+                    href.insert_str(0, ADVENT_OF_CODE_URL);
+                }
+                self.hrefs.push(href);
+                self.markdown.push('[');
+            }
+            Token::Close("a") => {
+                let href = self.hrefs.pop().unwrap_or_default();
+                self.markdown.push_str(&format!("]({href})"));
+            }
+            Token::Text(text) => {
+                let text = decode_entities(text);
+                let mut words = text.split_whitespace().peekable();
+                if text.starts_with(char::is_whitespace)
+                    && !self.markdown.is_empty()
+                    && !self.markdown.ends_with([' ', '\n'])
+                {
+                    self.markdown.push(' ');
+                }
+                while let Some(word) = words.next() {
+                    self.markdown.push_str(word);
+                    if words.peek().is_some() || text.ends_with(char::is_whitespace) {
+                        self.markdown.push(' ');
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Turns `--- Day 1: Calorie Counting ---` into the page title and any
+    /// later heading, such as `--- Part Two ---`, into a section.
+    fn end_heading(&mut self) {
+        let Some(start) = self.heading_start.take() else {
+            return;
+        };
+        let heading = self.markdown.split_off(start);
+        let heading = heading.trim().trim_matches('-').trim().to_owned();
+        if self.title.is_none() {
+            let title = heading
+                .split_once(": ")
+                .map_or(&*heading, |(_, title)| title);
+            self.title = Some(title.to_owned());
+            self.markdown.push_str(&format!("# {heading}"));
+        } else {
+            self.markdown.push_str(&format!("## {heading}"));
+        }
+        self.end_block();
+    }
+
+    fn end_pre(&mut self) {
+        let Some(pre) = self.pre.take() else {
+            return;
+        };
+        let example = pre.trim_end_matches('\n').to_owned() + "\n";
+        self.markdown.push_str(&format!("```\n{example}```"));
+        self.end_block();
+        if !self.examples.contains(&example) {
+            self.examples.push(example);
+        }
+    }
+
+    fn end_line(&mut self) {
+        self.markdown.truncate(self.markdown.trim_end().len());
+        self.markdown.push('\n');
+    }
+
+    fn end_block(&mut self) {
+        self.markdown.truncate(self.markdown.trim_end().len());
+        self.markdown.push_str("\n\n");
+    }
+}
+
+fn attribute(attributes: &str, name: &str) -> Option<String> {
+    let start = attributes.find(&format!("{name}=\""))? + name.len() + 2;
+    let length = attributes[start..].find('"')?;
+    Some(decode_entities(&attributes[start..start + length]))
+}
+
+fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let entity = rest[1..].find(';').map(|end| &rest[1..end + 1]);
+        let character = entity.and_then(|entity| match entity {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some(' '),
+            _ => {
+                let code = match entity.strip_prefix("#x") {
+                    Some(hex) => u32::from_str_radix(hex, 16).ok(),
+                    None => entity.strip_prefix('#')?.parse().ok(),
+                };
+                code.and_then(char::from_u32)
+            }
+        });
+        match (entity, character) {
+            (Some(entity), Some(character)) => {
+                decoded.push(character);
+                rest = &rest[entity.len() + 2..];
+            }
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_tokenize() {
+        assert_eq!(
+            tokenize("<p>A <a href=\"/2022\">link</a><!-- x --><br/></p>"),
+            [
+                Token::Open {
+                    name: "p",
+                    attributes: ""
+                },
+                Token::Text("A "),
+                Token::Open {
+                    name: "a",
+                    attributes: "href=\"/2022\""
+                },
+                Token::Text("link"),
+                Token::Close("a"),
+                Token::Open {
+                    name: "br",
+                    attributes: ""
+                },
+                Token::Close("p"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_decode_entities() {
+        assert_eq!(
+            decode_entities("a &lt;b&gt; &amp;&#39;c&#x27; &foo"),
+            "a <b> &'c' &foo"
+        );
+    }
+}
@@ -0,0 +1,121 @@
+use crate::template::{render, TemplateSet};
+use crate::Puzzle;
+use anyhow::{Context, Result};
+use aoc_common::MANIFEST_NAME;
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const MANIFEST_HEADER: &str = "\
+# The answers to the examples in this directory, checked by the day's
+# `test_examples`. Fill in the parts each example applies to; examples
+# without any answers are skipped.
+";
+
+/// The day a crate is generated for.
+pub struct CrateInfo {
+    pub year: u32,
+    pub day: u32,
+    /// The puzzle title, such as `Camel Cards`, if it is known.
+    pub title: Option<String>,
+    /// Workspace dependencies to add on top of those of the template set.
+    pub dependencies: Vec<String>,
+}
+
+impl CrateInfo {
+    fn variables(&self, template_set: &TemplateSet) -> Vec<(&'static str, String)> {
+        let CrateInfo { year, day, .. } = *self;
+        let description = match &self.title {
+            Some(title) => format!("Advent of Code {year} Day {day}: {title}"),
+            None => format!("Advent of Code {year} Day {day}"),
+        };
+        let dependencies = template_set
+            .dependencies
+            .iter()
+            .chain(&self.dependencies)
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .map(|dependency| format!("{dependency}.workspace = true"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        vec![
+            ("crate_name", format!("year_{year}_day_{day:02}")),
+            ("year", year.to_string()),
+            ("day", day.to_string()),
+            ("day_padded", format!("{day:02}")),
+            // Rendered as a quoted and escaped TOML string, since titles can
+            // contain quotes and backslashes.
+            ("description", toml::Value::String(description).to_string()),
+            ("dependencies", dependencies),
+        ]
+    }
+}
+
+/// Renders a template set into a crate for the day at `project_path`. Files
+/// that already exist are left alone unless `overwrite` is set, so that
+/// regenerating a crate only fills in what is missing. Returns the files that
+/// were kept.
+pub fn create_crate(
+    template_set: &TemplateSet,
+    project_path: &Path,
+    info: &CrateInfo,
+    overwrite: bool,
+) -> Result<Vec<PathBuf>> {
+    let variables = info.variables(template_set);
+    let mut kept_paths = vec![];
+    for (relative_path, template_path) in template_set.files() {
+        let output_path = project_path.join(relative_path);
+        if output_path.exists() && !overwrite {
+            kept_paths.push(output_path);
+            continue;
+        }
+        let template = fs::read_to_string(template_path)
+            .with_context(|| format!("Failed to read {}", template_path.display()))?;
+        fs::create_dir_all(output_path.parent().unwrap())?;
+        fs::write(output_path, render(&template, &variables))?;
+    }
+    Ok(kept_paths)
+}
+
+/// Writes the puzzle description to `README.md` and its examples to
+/// `examples/<n>.txt`, listing each example in `examples/expected.toml` so
+/// that its answers can be filled in. The description is always replaced,
+/// since it gains part two once part one is solved, but existing examples are
+/// left alone unless `overwrite` is set. Returns the files that were kept.
+pub fn write_puzzle(project_path: &Path, puzzle: &Puzzle, overwrite: bool) -> Result<Vec<PathBuf>> {
+    fs::write(project_path.join("README.md"), &puzzle.readme)?;
+    let examples_path = project_path.join("examples");
+    fs::create_dir_all(&examples_path)?;
+    let mut kept_paths = vec![];
+    for (i, example) in puzzle.examples.iter().enumerate() {
+        let example_path = examples_path.join(format!("{}.txt", i + 1));
+        if example_path.exists() && !overwrite {
+            kept_paths.push(example_path);
+            continue;
+        }
+        fs::write(example_path, example)?;
+    }
+    write_manifest(&examples_path, puzzle.examples.len())?;
+    Ok(kept_paths)
+}
+
+/// Adds an entry to the example manifest for each example it doesn't list
+/// yet, keeping any answers already filled in.
+fn write_manifest(examples_path: &Path, example_count: usize) -> Result<()> {
+    let manifest_path = examples_path.join(MANIFEST_NAME);
+    let mut manifest = if manifest_path.exists() {
+        fs::read_to_string(&manifest_path)?
+    } else {
+        String::from(MANIFEST_HEADER)
+    };
+    for i in 1..=example_count {
+        let file = format!("{i}.txt");
+        if !manifest.contains(&format!("file = \"{file}\"")) {
+            manifest.push_str(&format!(
+                "\n[[example]]\nfile = \"{file}\"\n# part_one =\n# part_two =\n"
+            ));
+        }
+    }
+    fs::write(&manifest_path, manifest)
+        .with_context(|| format!("Failed to write {}", manifest_path.display()))
+}
@@ -0,0 +1,514 @@
+use crate::calendar::{self, Clock, Date};
+use anyhow::{Context, Result};
+use aoc_common::load_examples;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+use std::fs;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+/// Whether one step of a day's solution is written, checked by a test and
+/// measured by a benchmark.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Progress {
+    pub implemented: bool,
+    pub tested: bool,
+    pub benchmarked: bool,
+}
+
+impl Progress {
+    /// One letter for each of implemented, tested and benchmarked, or `-`
+    /// for each that isn't.
+    fn flags(self) -> String {
+        [
+            (self.implemented, 'I'),
+            (self.tested, 'T'),
+            (self.benchmarked, 'B'),
+        ]
+        .iter()
+        .map(|&(done, flag)| if done { flag } else { '-' })
+        .collect()
+    }
+}
+
+/// The progress of a day's crate, found by reading its source rather than
+/// building it.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DayStatus {
+    pub parse: Progress,
+    pub part_one: Progress,
+    pub part_two: Progress,
+}
+
+impl DayStatus {
+    fn parts(&self) -> [Progress; 2] {
+        [self.part_one, self.part_two]
+    }
+}
+
+/// The crates of a year, keyed by day.
+#[derive(Debug, PartialEq, Eq)]
+pub struct YearStatus {
+    pub year: u32,
+    pub unlocked_days: u32,
+    pub days: BTreeMap<u32, DayStatus>,
+}
+
+impl YearStatus {
+    /// Every day to report on: the unlocked ones and any others with a crate.
+    fn day_numbers(&self) -> BTreeSet<u32> {
+        (1..=self.unlocked_days)
+            .chain(self.days.keys().copied())
+            .collect()
+    }
+
+    fn summary(&self) -> String {
+        let parts = || self.days.values().flat_map(DayStatus::parts);
+        let count = |done: fn(&Progress) -> bool| parts().filter(done).count();
+        format!(
+            "{} of {} days started, {} of {} parts implemented, {} tested, {} benchmarked",
+            self.days.len(),
+            self.unlocked_days,
+            count(|part| part.implemented),
+            2 * self.unlocked_days,
+            count(|part| part.tested),
+            count(|part| part.benchmarked),
+        )
+    }
+}
+
+/// Reads every `year_YYYY/day_DD` crate in the workspace, sorted by year.
+pub fn scan_workspace(workspace_path: &Path, clock: &impl Clock) -> Result<Vec<YearStatus>> {
+    let mut years = vec![];
+    for (year, year_path) in numbered_entries(workspace_path, "year_")? {
+        let mut days = BTreeMap::new();
+        for (day, project_path) in numbered_entries(&year_path, "day_")? {
+            days.insert(day, day_status(&project_path)?);
+        }
+        years.push(YearStatus {
+            year,
+            unlocked_days: calendar::unlocked_days(year, clock),
+            days,
+        });
+    }
+    Ok(years)
+}
+
+/// The directories in `path` named `<prefix><number>`, sorted by number.
+fn numbered_entries(path: &Path, prefix: &str) -> Result<BTreeMap<u32, PathBuf>> {
+    let mut entries = BTreeMap::new();
+    let dir = fs::read_dir(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    for entry in dir {
+        let entry = entry?;
+        let name = entry.file_name();
+        let number = name
+            .to_str()
+            .and_then(|name| name.strip_prefix(prefix))
+            .and_then(|number| number.parse().ok());
+        if let Some(number) = number {
+            if entry.path().is_dir() {
+                entries.insert(number, entry.path());
+            }
+        }
+    }
+    Ok(entries)
+}
+
+/// Reads a day's crate. Each step is implemented when its `Solution` method
+/// and the functions it calls hold no `todo!()`, tested when a test calls
+/// them or checks an example with an expected answer for it, and
+/// benchmarked when `src/bench.rs` calls them.
+pub fn day_status(project_path: &Path) -> Result<DayStatus> {
+    let mut source = String::new();
+    let mut bench = String::new();
+    read_sources(&project_path.join("src"), &mut source, &mut bench)?;
+    let source = blank_literals(&source);
+    let bench = blank_literals(&bench);
+    let bench_calls = calls(&bench);
+
+    let functions = functions(&source);
+    let solution = solution_impl(&source);
+    let in_solution = |function: &Function| {
+        solution
+            .as_ref()
+            .is_some_and(|range| range.contains(&function.start))
+    };
+    let mut free_functions = BTreeMap::<&str, Vec<&str>>::new();
+    for function in &functions {
+        if !function.is_test && !in_solution(function) {
+            free_functions
+                .entry(function.name)
+                .or_default()
+                .push(function.body);
+        }
+    }
+    let tests = functions
+        .iter()
+        .filter(|function| function.is_test && !has_placeholder(function.body))
+        .collect::<Vec<_>>();
+    let test_calls = tests
+        .iter()
+        .flat_map(|test| calls(test.body))
+        .collect::<BTreeSet<_>>();
+    let examples = if tests
+        .iter()
+        .any(|test| test.body.contains("check_examples"))
+    {
+        load_examples(&project_path.join("examples"))?
+    } else {
+        vec![]
+    };
+
+    let step = |method: &str, has_example: &dyn Fn(&aoc_common::Example) -> bool| {
+        let Some(function) = functions
+            .iter()
+            .find(|function| function.name == method && in_solution(function))
+        else {
+            return Progress::default();
+        };
+        let mut names = calls(function.body)
+            .into_iter()
+            .filter(|name| free_functions.contains_key(name))
+            .collect::<BTreeSet<_>>();
+        names.insert(method);
+        // Follow the calls through the day's own functions to find anything
+        // left unwritten.
+        let mut reachable = names.iter().copied().collect::<Vec<_>>();
+        let mut seen = names.clone();
+        let mut implemented = !has_placeholder(function.body);
+        while let Some(name) = reachable.pop() {
+            for body in free_functions.get(name).into_iter().flatten() {
+                implemented &= !has_placeholder(body);
+                for callee in calls(body) {
+                    if free_functions.contains_key(callee) && seen.insert(callee) {
+                        reachable.push(callee);
+                    }
+                }
+            }
+        }
+        Progress {
+            implemented,
+            tested: names.iter().any(|name| test_calls.contains(name))
+                || examples.iter().any(has_example),
+            benchmarked: names.iter().any(|name| bench_calls.contains(name)),
+        }
+    };
+    let mut parse = step("parse", &|_| false);
+    let part_one = step("part_one", &|example| example.part_one.is_some());
+    let part_two = step("part_two", &|example| example.part_two.is_some());
+    // A test of either part parses its input too.
+    parse.tested |= part_one.tested || part_two.tested;
+    Ok(DayStatus {
+        parse,
+        part_one,
+        part_two,
+    })
+}
+
+/// Appends every Rust file in `path` to `source`, except the benchmark,
+/// which goes to `bench`.
+fn read_sources(path: &Path, source: &mut String, bench: &mut String) -> Result<()> {
+    let mut paths = fs::read_dir(path)
+        .with_context(|| format!("Failed to read {}", path.display()))?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<_>, _>>()?;
+    paths.sort();
+    for path in paths {
+        if path.is_dir() {
+            read_sources(&path, source, bench)?;
+        } else if path.extension().is_some_and(|ext| ext == "rs") {
+            let contents = fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read {}", path.display()))?;
+            let target = if path.file_name().is_some_and(|name| name == "bench.rs") {
+                &mut *bench
+            } else {
+                &mut *source
+            };
+            target.push_str(&contents);
+            target.push('\n');
+        }
+    }
+    Ok(())
+}
+
+fn has_placeholder(body: &str) -> bool {
+    body.contains("todo!") || body.contains("unimplemented!")
+}
+
+/// Replaces the contents of comments, strings and characters with spaces,
+/// so that braces and names in them don't count as code.
+fn blank_literals(source: &str) -> String {
+    let bytes = source.as_bytes();
+    let mut blanked = bytes.to_vec();
+    let mut i = 0;
+    let mut blank = |from: usize, to: usize| blanked[from..to].fill(b' ');
+    while i < bytes.len() {
+        let rest = &source[i..];
+        let end = if rest.starts_with("//") {
+            rest.find('\n').map_or(bytes.len(), |end| i + end)
+        } else if rest.starts_with("/*") {
+            rest.find("*/").map_or(bytes.len(), |end| i + end + 2)
+        } else if bytes[i] == b'"' {
+            let mut end = i + 1;
+            while end < bytes.len() && bytes[end] != b'"' {
+                end += if bytes[end] == b'\\' { 2 } else { 1 };
+            }
+            (end + 1).min(bytes.len())
+        } else if bytes[i] == b'r'
+            && (i == 0 || !is_ident_byte(bytes[i - 1]))
+            && rest[1..].trim_start_matches('#').starts_with('"')
+        {
+            let hashes = rest[1..].len() - rest[1..].trim_start_matches('#').len();
+            let closing = format!("\"{}", "#".repeat(hashes));
+            rest[hashes + 2..]
+                .find(&closing)
+                .map_or(bytes.len(), |end| i + hashes + 2 + end + closing.len())
+        } else if bytes[i] == b'\'' {
+            // Either a character or a lifetime, which has no closing quote.
+            let mut chars = rest[1..].chars();
+            match (chars.next(), chars.next()) {
+                (Some('\\'), _) => rest[3..].find('\'').map_or(bytes.len(), |end| i + end + 4),
+                (Some(c), Some('\'')) => i + c.len_utf8() + 2,
+                _ => {
+                    i += 1;
+                    continue;
+                }
+            }
+        } else {
+            i += rest.chars().next().unwrap().len_utf8();
+            continue;
+        };
+        blank(i, end);
+        i = end;
+    }
+    String::from_utf8(blanked).expect("only whole characters are blanked")
+}
+
+fn is_ident_byte(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || byte == b'_'
+}
+
+/// A function with a body, in source with blanked literals.
+struct Function<'a> {
+    name: &'a str,
+    start: usize,
+    body: &'a str,
+    is_test: bool,
+}
+
+fn functions(source: &str) -> Vec<Function<'_>> {
+    let bytes = source.as_bytes();
+    let mut functions = vec![];
+    for (start, _) in source.match_indices("fn ") {
+        if start > 0 && is_ident_byte(bytes[start - 1]) {
+            continue;
+        }
+        let name_start = start + 3;
+        let name_end = ident_end(source, name_start);
+        if name_end == name_start {
+            continue;
+        }
+        let Some(open) = source[name_end..]
+            .find(['{', ';'])
+            .map(|open| name_end + open)
+        else {
+            continue;
+        };
+        if bytes[open] == b';' {
+            continue;
+        }
+        functions.push(Function {
+            name: &source[name_start..name_end],
+            start,
+            body: &source[open..matching_brace(source, open)],
+            is_test: has_test_attribute(&source[..start]),
+        });
+    }
+    functions
+}
+
+fn ident_end(source: &str, start: usize) -> usize {
+    source[start..]
+        .bytes()
+        .position(|byte| !is_ident_byte(byte))
+        .map_or(source.len(), |end| start + end)
+}
+
+/// The end of the block opened at `open`, just past its closing brace.
+fn matching_brace(source: &str, open: usize) -> usize {
+    let mut depth = 0;
+    for (i, byte) in source.bytes().enumerate().skip(open) {
+        match byte {
+            b'{' => depth += 1,
+            b'}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return i + 1;
+                }
+            }
+            _ => {}
+        }
+    }
+    source.len()
+}
+
+/// Whether the attributes just before a function include `#[test]`.
+fn has_test_attribute(before: &str) -> bool {
+    let mut before = before.trim_end();
+    while before.ends_with(']') {
+        let Some(start) = before.rfind("#[") else {
+            break;
+        };
+        if before[start..] == *"#[test]" {
+            return true;
+        }
+        before = before[..start].trim_end();
+    }
+    false
+}
+
+/// The byte range of the `impl Solution for ...` block.
+fn solution_impl(source: &str) -> Option<Range<usize>> {
+    let start = source.find("Solution for ")?;
+    let open = start + source[start..].find('{')?;
+    Some(start..matching_brace(source, open))
+}
+
+/// The names of the functions and methods called in `code`.
+fn calls(code: &str) -> BTreeSet<&str> {
+    let bytes = code.as_bytes();
+    let mut calls = BTreeSet::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        if !is_ident_byte(bytes[i]) || (i > 0 && is_ident_byte(bytes[i - 1])) {
+            i += 1;
+            continue;
+        }
+        let end = ident_end(code, i);
+        if code[end..].trim_start().starts_with('(') && !code[..i].ends_with("fn ") {
+            calls.insert(&code[i..end]);
+        }
+        i = end;
+    }
+    calls
+}
+
+/// Renders a table with a row for each day, and a summary for each year.
+pub fn render_table(years: &[YearStatus]) -> String {
+    let mut table = String::from(
+        "I: implemented, T: tested, B: benchmarked\n\nYear  Day  Parse  Part one  Part two\n",
+    );
+    for year in years {
+        for day in year.day_numbers() {
+            match year.days.get(&day) {
+                Some(status) => writeln!(
+                    table,
+                    "{}  {day:>3}  {:<5}  {:<8}  {}",
+                    year.year,
+                    status.parse.flags(),
+                    status.part_one.flags(),
+                    status.part_two.flags()
+                ),
+                None => writeln!(table, "{}  {day:>3}  not started", year.year),
+            }
+            .unwrap();
+        }
+        writeln!(table, "{}: {}", year.year, year.summary()).unwrap();
+    }
+    table
+}
+
+/// Renders a calendar of each year, laid out by week, with a star for each
+/// implemented part and T or B when every implemented part is tested or
+/// benchmarked.
+pub fn render_calendar(years: &[YearStatus]) -> String {
+    let mut calendar = String::from("# Advent of Code\n");
+    for year in years {
+        writeln!(calendar, "\n## {}\n", year.year).unwrap();
+        calendar.push_str("| Mon | Tue | Wed | Thu | Fri | Sat | Sun |\n");
+        calendar.push_str("| --- | --- | --- | --- | --- | --- | --- |\n");
+        let first_weekday = Date {
+            year: year.year,
+            month: 12,
+            day: 1,
+        }
+        .weekday();
+        let mut cells = vec![String::new(); first_weekday as usize];
+        for day in 1..=calendar::days_in_year(year.year) {
+            cells.push(calendar_cell(year, day));
+        }
+        cells.resize(cells.len().next_multiple_of(7), String::new());
+        for week in cells.chunks(7) {
+            writeln!(calendar, "| {} |", week.join(" | ")).unwrap();
+        }
+        writeln!(calendar, "\n{}.", year.summary()).unwrap();
+    }
+    calendar
+}
+
+fn calendar_cell(year: &YearStatus, day: u32) -> String {
+    let Some(status) = year.days.get(&day) else {
+        return if day > year.unlocked_days {
+            format!("{day} 🔒")
+        } else {
+            day.to_string()
+        };
+    };
+    let implemented = status
+        .parts()
+        .into_iter()
+        .filter(|part| part.implemented)
+        .collect::<Vec<_>>();
+    let mut cell = format!("[{day}](year_{}/day_{day:02})", year.year);
+    if !implemented.is_empty() {
+        cell.push(' ');
+        cell.push_str(&"⭐".repeat(implemented.len()));
+        if implemented.iter().all(|part| part.tested) {
+            cell.push_str(" T");
+        }
+        if implemented.iter().all(|part| part.benchmarked) {
+            cell.push_str(" B");
+        }
+    }
+    cell
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_blank_literals() {
+        let source =
+            "let s = \"{ \\\" }\"; // }\nlet c = '{'; fn f<'a>(x: &'a str) {}\nr#\"}\"#; '\\''";
+        assert_eq!(
+            blank_literals(source),
+            "let s =         ;     \nlet c =    ; fn f<'a>(x: &'a str) {}\n      ;     "
+        );
+    }
+
+    #[test]
+    fn test_functions() {
+        let source = blank_literals(
+            "fn parse_input(input: &str) -> Vec<u32> { input.lines().map(|l| { l.parse().unwrap() }).collect() }
+trait T { fn declared(&self); }
+#[cfg(test)]
+mod test {
+    #[test]
+    #[should_panic]
+    fn test_parse() { parse_input(\"}\"); }
+}",
+        );
+        let functions = functions(&source);
+        let names = functions
+            .iter()
+            .map(|function| (function.name, function.is_test))
+            .collect::<Vec<_>>();
+        assert_eq!(names, [("parse_input", false), ("test_parse", true)]);
+        assert!(functions[0].body.ends_with("collect() }"));
+        assert_eq!(
+            calls(functions[1].body).into_iter().collect::<Vec<_>>(),
+            ["parse_input"]
+        );
+    }
+}
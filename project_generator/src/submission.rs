@@ -0,0 +1,200 @@
+use crate::puzzle::article_text;
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display, Formatter};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// How the website judged a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    Incorrect,
+    TooHigh,
+    TooLow,
+    RateLimited,
+    AlreadySolved,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let description = match self {
+            Verdict::Correct => "correct",
+            Verdict::Incorrect => "incorrect",
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+            Verdict::RateLimited => "rate limited",
+            Verdict::AlreadySolved => "already solved",
+        };
+        f.write_str(description)
+    }
+}
+
+/// The website's response to a submitted answer.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Response {
+    pub verdict: Verdict,
+    /// The text of the response, which says how long to wait when rate
+    /// limited.
+    pub message: String,
+}
+
+/// Reads the verdict from the page returned after submitting an answer.
+pub fn parse_response(html: &str) -> Result<Response> {
+    let message = article_text(html).ok_or_else(|| anyhow!("No response found on the page"))?;
+    let verdict = if message.starts_with("That's the right answer") {
+        Verdict::Correct
+    } else if message.contains("your answer is too high") {
+        Verdict::TooHigh
+    } else if message.contains("your answer is too low") {
+        Verdict::TooLow
+    } else if message.starts_with("That's not the right answer") {
+        Verdict::Incorrect
+    } else if message.starts_with("You gave an answer too recently") {
+        Verdict::RateLimited
+    } else if message.contains("Did you already complete it?") {
+        Verdict::AlreadySolved
+    } else {
+        return Err(anyhow!("Unrecognized response: {message}"));
+    };
+    Ok(Response { verdict, message })
+}
+
+/// An answer that was submitted for one part of a day.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+struct SubmissionsFile {
+    #[serde(default)]
+    submission: Vec<Submission>,
+}
+
+/// Every answer submitted so far, stored as a TOML file with one
+/// `[[submission]]` table each.
+pub struct Submissions {
+    path: PathBuf,
+    file: SubmissionsFile,
+}
+
+impl Submissions {
+    pub fn load(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let file = if path.exists() {
+            let contents = fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read {}", path.display()))?;
+            toml::from_str(&contents)
+                .with_context(|| format!("Failed to parse {}", path.display()))?
+        } else {
+            SubmissionsFile::default()
+        };
+        Ok(Self { path, file })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let contents = toml::to_string(&self.file)?;
+        fs::write(&self.path, contents)
+            .with_context(|| format!("Failed to write {}", self.path.display()))
+    }
+
+    /// Finds an earlier submission which shows that `answer` is wrong or that
+    /// the part is already solved, so submitting it again would be pointless.
+    pub fn ruled_out_by(
+        &self,
+        year: u32,
+        day: u32,
+        part: u32,
+        answer: &str,
+    ) -> Option<&Submission> {
+        let number = answer.parse::<i64>().ok();
+        self.file.submission.iter().find(|submission| {
+            if (submission.year, submission.day, submission.part) != (year, day, part) {
+                return false;
+            }
+            let numbers = number.zip(submission.answer.parse::<i64>().ok());
+            match submission.verdict {
+                Verdict::Correct => true,
+                Verdict::Incorrect => submission.answer == answer,
+                Verdict::TooHigh => {
+                    submission.answer == answer
+                        || numbers.is_some_and(|(n, submitted)| n >= submitted)
+                }
+                Verdict::TooLow => {
+                    submission.answer == answer
+                        || numbers.is_some_and(|(n, submitted)| n <= submitted)
+                }
+                Verdict::RateLimited | Verdict::AlreadySolved => false,
+            }
+        })
+    }
+
+    /// Records a submission, unless its verdict says nothing about the answer.
+    pub fn record(&mut self, submission: Submission) {
+        if !matches!(
+            submission.verdict,
+            Verdict::RateLimited | Verdict::AlreadySolved
+        ) {
+            self.file.submission.push(submission);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn submission(part: u32, answer: &str, verdict: Verdict) -> Submission {
+        Submission {
+            year: 2023,
+            day: 10,
+            part,
+            answer: answer.to_owned(),
+            verdict,
+        }
+    }
+
+    #[test]
+    fn test_ruled_out_by() {
+        let path = tempfile::tempdir().unwrap();
+        let mut submissions = Submissions::load(path.path().join("submissions.toml")).unwrap();
+        submissions.record(submission(1, "7000", Verdict::TooHigh));
+        submissions.record(submission(1, "12", Verdict::TooLow));
+        submissions.record(submission(1, "6000", Verdict::Incorrect));
+        submissions.record(submission(1, "6500", Verdict::RateLimited));
+        submissions.save().unwrap();
+
+        let submissions = Submissions::load(path.path().join("submissions.toml")).unwrap();
+        let ruled_out = |answer| {
+            submissions
+                .ruled_out_by(2023, 10, 1, answer)
+                .map(|submission| submission.answer.as_str())
+        };
+        assert_eq!(ruled_out("7000"), Some("7000"));
+        assert_eq!(ruled_out("8000"), Some("7000"));
+        assert_eq!(ruled_out("3"), Some("12"));
+        assert_eq!(ruled_out("6000"), Some("6000"));
+        assert_eq!(ruled_out("6500"), None);
+        assert_eq!(ruled_out("abc"), None);
+        assert_eq!(submissions.ruled_out_by(2023, 10, 2, "8000"), None);
+    }
+
+    #[test]
+    fn test_ruled_out_by_correct() {
+        let path = tempfile::tempdir().unwrap();
+        let mut submissions = Submissions::load(path.path().join("submissions.toml")).unwrap();
+        submissions.record(submission(2, "ABC", Verdict::Correct));
+        assert!(submissions.ruled_out_by(2023, 10, 2, "ABC").is_some());
+        assert!(submissions.ruled_out_by(2023, 10, 2, "ABD").is_some());
+    }
+}
@@ -0,0 +1,160 @@
+use anyhow::{ensure, Context, Result};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const CONFIG_NAME: &str = "template.toml";
+const TEMPLATE_EXTENSION: &str = "template";
+
+#[derive(Deserialize)]
+struct Config {
+    description: String,
+    extends: Option<String>,
+    #[serde(default)]
+    dependencies: Vec<String>,
+}
+
+/// A directory of templates for one crate layout. Each `<path>.template` file
+/// in it is rendered to `<path>` in the new crate, and `template.toml`
+/// describes the set, the workspace dependencies it needs and optionally a
+/// set to take any templates it doesn't override from.
+pub struct TemplateSet {
+    pub name: String,
+    pub description: String,
+    pub dependencies: Vec<String>,
+    /// The template for each output path, relative to the crate.
+    files: BTreeMap<PathBuf, PathBuf>,
+}
+
+impl TemplateSet {
+    pub fn load(templates_path: &Path, name: &str) -> Result<Self> {
+        let set_path = templates_path.join(name);
+        let config_path = set_path.join(CONFIG_NAME);
+        ensure!(
+            config_path.exists(),
+            "There is no template set called {name} in {}",
+            templates_path.display()
+        );
+        let contents = fs::read_to_string(&config_path)
+            .with_context(|| format!("Failed to read {}", config_path.display()))?;
+        let config: Config = toml::from_str(&contents)
+            .with_context(|| format!("Failed to parse {}", config_path.display()))?;
+        let (mut dependencies, mut files) = match &config.extends {
+            Some(base) => {
+                ensure!(base != name, "Template set {name} extends itself");
+                let base = Self::load(templates_path, base)?;
+                (base.dependencies, base.files)
+            }
+            None => (vec![], BTreeMap::new()),
+        };
+        dependencies.extend(config.dependencies);
+        find_templates(&set_path, Path::new(""), &mut files)?;
+        Ok(Self {
+            name: name.to_owned(),
+            description: config.description,
+            dependencies,
+            files,
+        })
+    }
+
+    /// Loads every template set in `templates_path`, sorted by name.
+    pub fn list(templates_path: &Path) -> Result<Vec<Self>> {
+        let mut names = vec![];
+        let entries = fs::read_dir(templates_path)
+            .with_context(|| format!("Failed to read {}", templates_path.display()))?;
+        for entry in entries {
+            let entry = entry?;
+            if entry.path().join(CONFIG_NAME).exists() {
+                names.push(entry.file_name().to_string_lossy().into_owned());
+            }
+        }
+        names.sort();
+        names
+            .iter()
+            .map(|name| Self::load(templates_path, name))
+            .collect()
+    }
+
+    /// The output paths and the template for each, relative to the crate.
+    pub fn files(&self) -> impl Iterator<Item = (&Path, &Path)> {
+        self.files
+            .iter()
+            .map(|(output_path, template_path)| (output_path.as_path(), template_path.as_path()))
+    }
+}
+
+fn find_templates(
+    directory: &Path,
+    relative_path: &Path,
+    files: &mut BTreeMap<PathBuf, PathBuf>,
+) -> Result<()> {
+    for entry in fs::read_dir(directory)? {
+        let path = entry?.path();
+        let name = path.file_name().unwrap();
+        if path.is_dir() {
+            find_templates(&path, &relative_path.join(name), files)?;
+        } else if path
+            .extension()
+            .is_some_and(|ext| ext == TEMPLATE_EXTENSION)
+        {
+            let output_name = path.file_stem().unwrap();
+            files.insert(relative_path.join(output_name), path);
+        }
+    }
+    Ok(())
+}
+
+/// Replaces each `{{ name }}` in a template with its value. A line holding
+/// nothing but a variable whose value is empty is dropped, so that optional
+/// lines such as `{{ dependencies }}` don't leave gaps.
+pub fn render(template: &str, variables: &[(&str, String)]) -> String {
+    let mut rendered = String::with_capacity(template.len());
+    for line in template.split_inclusive('\n') {
+        let is_empty_variable = variables
+            .iter()
+            .any(|(name, value)| value.is_empty() && line.trim() == format!("{{{{ {name} }}}}"));
+        if is_empty_variable {
+            continue;
+        }
+        let mut line = line.to_owned();
+        for (name, value) in variables {
+            line = line.replace(&format!("{{{{ {name} }}}}"), value);
+        }
+        rendered.push_str(&line);
+    }
+    rendered
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let template = "\
+[dependencies]
+aoc_common.workspace = true
+{{ dependencies }}
+
+name = \"{{ crate_name }}\" # {{ crate_name }}
+";
+        let mut variables = vec![
+            ("crate_name", String::from("year_2023_day_07")),
+            ("dependencies", String::new()),
+        ];
+        assert_eq!(
+            render(template, &variables),
+            "\
+[dependencies]
+aoc_common.workspace = true
+
+name = \"year_2023_day_07\" # year_2023_day_07
+"
+        );
+        variables[1].1 = String::from("nom.workspace = true\nrayon.workspace = true");
+        assert!(render(template, &variables).contains(
+            "aoc_common.workspace = true\nnom.workspace = true\nrayon.workspace = true\n\n"
+        ));
+    }
+}
@@ -0,0 +1,60 @@
+use std::sync::{Arc, Mutex};
+use std::thread;
+use tiny_http::{Header, Response, Server};
+
+/// A request received by a [`StubServer`].
+#[derive(Debug, Clone)]
+pub struct Request {
+    pub method: String,
+    pub url: String,
+    pub cookie: Option<String>,
+    pub body: String,
+}
+
+/// A local HTTP server that stands in for the Advent of Code website. Every
+/// request is recorded and answered by the handler.
+pub struct StubServer {
+    url: String,
+    requests: Arc<Mutex<Vec<Request>>>,
+}
+
+impl StubServer {
+    pub fn start(handler: impl Fn(&Request) -> (u16, String) + Send + 'static) -> Self {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let requests = Arc::new(Mutex::new(vec![]));
+        let recorded_requests = Arc::clone(&requests);
+        thread::spawn(move || {
+            for mut incoming in server.incoming_requests() {
+                let mut body = String::new();
+                incoming.as_reader().read_to_string(&mut body).unwrap();
+                let request = Request {
+                    method: incoming.method().to_string(),
+                    url: incoming.url().to_owned(),
+                    cookie: incoming
+                        .headers()
+                        .iter()
+                        .find(|header| header.field.equiv("cookie"))
+                        .map(|header| header.value.to_string()),
+                    body,
+                };
+                let (status, response_body) = handler(&request);
+                recorded_requests.lock().unwrap().push(request);
+                let content_type = Header::from_bytes("Content-Type", "text/plain").unwrap();
+                let response = Response::from_string(response_body)
+                    .with_status_code(status)
+                    .with_header(content_type);
+                incoming.respond(response).unwrap();
+            }
+        });
+        Self { url, requests }
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+}
@@ -0,0 +1,67 @@
+mod common;
+
+use common::StubServer;
+use project_generator::Client;
+
+#[test]
+fn test_fetch_input() {
+    let server = StubServer::start(|_| (200, String::from("1\n2\n3\n")));
+    let client = Client::new(server.url(), "session=abc\n");
+    assert_eq!(client.fetch_input(2022, 1).unwrap(), "1\n2\n3\n");
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(requests[0].url, "/2022/day/1/input");
+    assert_eq!(requests[0].cookie.as_deref(), Some("session=abc"));
+    assert_eq!(requests[0].body, "");
+}
+
+#[test]
+fn test_fetch_input_expired_session() {
+    let server = StubServer::start(|_| {
+        (
+            400,
+            String::from(
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n",
+            ),
+        )
+    });
+    let client = Client::new(&format!("{}/", server.url()), "session=expired");
+    let error = client.fetch_input(2023, 25).unwrap_err().to_string();
+    assert!(error.contains("session cookie was rejected"), "{error}");
+    assert_eq!(server.requests()[0].url, "/2023/day/25/input");
+}
+
+#[test]
+fn test_fetch_input_failure() {
+    let server = StubServer::start(|request| match request.url.as_str() {
+        "/2024/day/25/input" => (
+            404,
+            String::from("Please don't repeatedly request this endpoint before it unlocks!"),
+        ),
+        _ => (500, String::from("Internal Server Error")),
+    });
+    let client = Client::new(server.url(), "abc");
+    let error = client.fetch_input(2024, 25).unwrap_err().to_string();
+    assert!(error.contains("404 Not Found"), "{error}");
+    assert!(error.contains("not be unlocked yet"), "{error}");
+    let error = client.fetch_input(2024, 1).unwrap_err().to_string();
+    assert!(error.contains("500 Internal Server Error"), "{error}");
+    assert!(error.contains("website may be down"), "{error}");
+    assert_eq!(server.requests()[0].cookie.as_deref(), Some("session=abc"));
+}
+
+#[test]
+fn test_fetch_puzzle() {
+    let server = StubServer::start(|request| {
+        let body = match request.url.as_str() {
+            "/2023/day/10" => include_str!("fixtures/2023_day_10.html"),
+            _ => "",
+        };
+        (200, body.to_owned())
+    });
+    let client = Client::new(server.url(), "session=abc");
+    let html = client.fetch_puzzle(2023, 10).unwrap();
+    assert!(html.contains("--- Day 10: Pipe Maze ---"));
+    assert_eq!(server.requests()[0].cookie.as_deref(), Some("session=abc"));
+}
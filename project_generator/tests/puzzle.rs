@@ -0,0 +1,88 @@
+use project_generator::parse_puzzle;
+
+#[test]
+fn test_parse_puzzle() {
+    let puzzle = parse_puzzle(include_str!("fixtures/2023_day_10.html")).unwrap();
+    assert_eq!(puzzle.title, "Pipe Maze");
+    assert_eq!(
+        puzzle.readme,
+        "\
+# Day 10: Pipe Maze
+
+The pipes are arranged in a two-dimensional grid of **tiles**:
+
+- `|` is a **vertical pipe** connecting north and south.
+- `-` is a **horizontal pipe** connecting east and west.
+- `S` is the **starting position** of the animal; there is a pipe on this tile, but your sketch doesn't show what shape the pipe has.
+
+For example, here is a square loop of pipe:
+
+```
+.....
+.F-7.
+.|.|.
+.L-J.
+.....
+```
+
+If the animal had entered this loop in the northwest corner, the sketch would instead look like this:
+
+```
+.....
+.S-7.
+.|.|.
+.L-J.
+.....
+```
+
+Here's the more complex loop again:
+
+```
+.....
+.S-7.
+.|.|.
+.L-J.
+.....
+```
+
+Find the single giant loop starting at `S`. **How many steps along the loop does it take to get from the starting position to the point farthest from the starting position?**
+"
+    );
+    assert_eq!(
+        puzzle.examples,
+        [
+            ".....\n.F-7.\n.|.|.\n.L-J.\n.....\n",
+            ".....\n.S-7.\n.|.|.\n.L-J.\n.....\n",
+        ]
+    );
+}
+
+#[test]
+fn test_parse_puzzle_with_part_two() {
+    let puzzle = parse_puzzle(include_str!("fixtures/2022_day_01.html")).unwrap();
+    assert_eq!(puzzle.title, "Calorie Counting");
+    assert!(puzzle.readme.starts_with(
+        "# Day 1: Calorie Counting\n\n\
+         Santa's reindeer typically eat regular reindeer food, but they need a lot of \
+         [magical energy](https://adventofcode.com/2018/day/25) to deliver presents on Christmas.\n\n"
+    ));
+    assert!(puzzle.readme.contains(
+        "- The first Elf is carrying food with `1000`, `2000`, and `3000` Calories, \
+         a total of **`6000`** Calories.\n\
+         - The second Elf is carrying one food item with **`4000`** Calories.\n\n"
+    ));
+    assert!(puzzle.readme.contains("\n\n## Part Two\n\n"));
+    assert!(puzzle
+        .readme
+        .ends_with("**How many Calories are those Elves carrying in total?**\n"));
+    assert!(!puzzle.readme.contains("Your puzzle answer"));
+    assert_eq!(
+        puzzle.examples,
+        ["1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n"]
+    );
+}
+
+#[test]
+fn test_parse_puzzle_without_article() {
+    assert!(parse_puzzle("<html><body>404 Not Found</body></html>").is_err());
+}
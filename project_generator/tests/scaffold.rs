@@ -0,0 +1,175 @@
+use project_generator::{create_crate, write_puzzle, CrateInfo, Puzzle, TemplateSet};
+use std::fs;
+use std::path::Path;
+
+fn template_set(name: &str) -> TemplateSet {
+    let templates_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("templates");
+    TemplateSet::load(&templates_path, name).unwrap()
+}
+
+fn info(day: u32) -> CrateInfo {
+    CrateInfo {
+        year: 2023,
+        day,
+        title: None,
+        dependencies: vec![],
+    }
+}
+
+#[test]
+fn test_create_crate() {
+    let workspace = tempfile::tempdir().unwrap();
+    let project_path = workspace.path().join("day_07");
+    let info = CrateInfo {
+        title: Some(String::from("Camel Cards")),
+        ..info(7)
+    };
+    let kept_paths = create_crate(&template_set("lib"), &project_path, &info, false).unwrap();
+    assert!(kept_paths.is_empty());
+    let cargo_toml = fs::read_to_string(project_path.join("Cargo.toml")).unwrap();
+    assert!(cargo_toml.contains("name = \"year_2023_day_07\""));
+    assert!(cargo_toml.contains("description = \"Advent of Code 2023 Day 7: Camel Cards\""));
+    assert!(cargo_toml.contains("[dependencies]\naoc_common.workspace = true\n\n"));
+    let lib = fs::read_to_string(project_path.join("src/lib.rs")).unwrap();
+    assert!(lib.contains("pub struct Day07;"));
+    assert!(lib.contains("const YEAR: u32 = 2023;"));
+    assert!(lib.contains("const DAY: u32 = 7;"));
+    assert!(project_path.join("src/main.rs").exists());
+    assert!(project_path.join("src/bench.rs").exists());
+}
+
+#[test]
+fn test_create_crate_escapes_the_title() {
+    let workspace = tempfile::tempdir().unwrap();
+    let project_path = workspace.path().join("day_07");
+    let info = CrateInfo {
+        title: Some(String::from(r#"The "Camel" \ Cards"#)),
+        ..info(7)
+    };
+    create_crate(&template_set("bin"), &project_path, &info, false).unwrap();
+    let cargo_toml = fs::read_to_string(project_path.join("Cargo.toml")).unwrap();
+    let manifest = cargo_toml.parse::<toml::Table>().unwrap();
+    assert_eq!(
+        manifest["package"]["description"].as_str(),
+        Some(r#"Advent of Code 2023 Day 7: The "Camel" \ Cards"#)
+    );
+}
+
+#[test]
+fn test_create_crate_with_dependencies() {
+    let workspace = tempfile::tempdir().unwrap();
+    let project_path = workspace.path().join("day_05");
+    let info = CrateInfo {
+        dependencies: vec![String::from("rayon"), String::from("nom")],
+        ..info(5)
+    };
+    create_crate(&template_set("nom"), &project_path, &info, false).unwrap();
+    let cargo_toml = fs::read_to_string(project_path.join("Cargo.toml")).unwrap();
+    assert!(cargo_toml.contains("description = \"Advent of Code 2023 Day 5\""));
+    assert!(cargo_toml.contains(
+        "aoc_common.workspace = true\n\
+         nom.workspace = true\n\
+         rayon.workspace = true\n\n"
+    ));
+    let lib = fs::read_to_string(project_path.join("src/lib.rs")).unwrap();
+    assert!(lib.contains("pub mod parser {"));
+    assert!(lib.contains("ParseError::from_nom(input, err)"));
+    let bench = fs::read_to_string(project_path.join("src/bench.rs")).unwrap();
+    assert!(bench.contains("year_2023_day_05::parser::parse(INPUT)"));
+    assert!(project_path.join("src/main.rs").exists());
+}
+
+#[test]
+fn test_create_binary_crate() {
+    let workspace = tempfile::tempdir().unwrap();
+    let project_path = workspace.path().join("day_03");
+    create_crate(&template_set("bin"), &project_path, &info(3), false).unwrap();
+    let main = fs::read_to_string(project_path.join("src/main.rs")).unwrap();
+    assert!(main.contains("aoc_common::run::<Day03>(INPUT);"));
+    assert!(!project_path.join("src/lib.rs").exists());
+    assert!(!project_path.join("src/bench.rs").exists());
+}
+
+#[test]
+fn test_list_template_sets() {
+    let templates_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("templates");
+    let names = TemplateSet::list(&templates_path)
+        .unwrap()
+        .into_iter()
+        .map(|template_set| template_set.name)
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["bin", "lib", "nom"]);
+}
+
+#[test]
+fn test_create_crate_keeps_existing_files() {
+    let workspace = tempfile::tempdir().unwrap();
+    let project_path = workspace.path().join("day_10");
+    let lib_path = project_path.join("src/lib.rs");
+    fs::create_dir_all(lib_path.parent().unwrap()).unwrap();
+    fs::write(&lib_path, "// solved\n").unwrap();
+    let kept_paths = create_crate(&template_set("lib"), &project_path, &info(10), false).unwrap();
+    assert_eq!(kept_paths, [project_path.join("src/lib.rs")]);
+    assert_eq!(fs::read_to_string(&lib_path).unwrap(), "// solved\n");
+    assert!(project_path.join("Cargo.toml").exists());
+    assert!(project_path.join("src/main.rs").exists());
+
+    let kept_paths = create_crate(&template_set("lib"), &project_path, &info(10), true).unwrap();
+    assert!(kept_paths.is_empty());
+    assert!(fs::read_to_string(&lib_path)
+        .unwrap()
+        .contains("pub struct Day10;"));
+}
+
+#[test]
+fn test_write_puzzle() {
+    let workspace = tempfile::tempdir().unwrap();
+    let project_path = workspace.path();
+    let mut puzzle = Puzzle {
+        title: String::from("Pipe Maze"),
+        readme: String::from("# Day 10: Pipe Maze\n"),
+        examples: vec![String::from("-L|F7\n"), String::from("..F7.\n")],
+    };
+    assert!(write_puzzle(project_path, &puzzle, false)
+        .unwrap()
+        .is_empty());
+    assert_eq!(
+        fs::read_to_string(project_path.join("examples/2.txt")).unwrap(),
+        "..F7.\n"
+    );
+
+    let manifest_path = project_path.join("examples/expected.toml");
+    let manifest = fs::read_to_string(&manifest_path).unwrap();
+    assert!(manifest.ends_with(
+        "\n[[example]]\nfile = \"1.txt\"\n# part_one =\n# part_two =\n\
+         \n[[example]]\nfile = \"2.txt\"\n# part_one =\n# part_two =\n"
+    ));
+    fs::write(
+        &manifest_path,
+        manifest.replace("# part_one =", "part_one = 4"),
+    )
+    .unwrap();
+
+    puzzle.readme.push_str("\n## Part Two\n");
+    puzzle.examples[1] = String::from("changed\n");
+    puzzle.examples.push(String::from("7-F7-\n"));
+    let kept_paths = write_puzzle(project_path, &puzzle, false).unwrap();
+    assert_eq!(
+        kept_paths,
+        [
+            project_path.join("examples/1.txt"),
+            project_path.join("examples/2.txt")
+        ]
+    );
+    let manifest = fs::read_to_string(&manifest_path).unwrap();
+    assert_eq!(manifest.matches("part_one = 4").count(), 2);
+    assert!(manifest.ends_with("\n[[example]]\nfile = \"3.txt\"\n# part_one =\n# part_two =\n"));
+    assert_eq!(
+        fs::read_to_string(project_path.join("README.md")).unwrap(),
+        puzzle.readme
+    );
+    assert_eq!(
+        fs::read_to_string(project_path.join("examples/2.txt")).unwrap(),
+        "..F7.\n"
+    );
+}
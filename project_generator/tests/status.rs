@@ -0,0 +1,87 @@
+use project_generator::{
+    create_crate, day_status, render_calendar, render_table, scan_workspace, CrateInfo, DayStatus,
+    Progress, TemplateSet,
+};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, UNIX_EPOCH};
+
+fn progress(implemented: bool, tested: bool, benchmarked: bool) -> Progress {
+    Progress {
+        implemented,
+        tested,
+        benchmarked,
+    }
+}
+
+fn create_day(workspace: &Path, day: u32) -> PathBuf {
+    let templates_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("templates");
+    let template_set = TemplateSet::load(&templates_path, "lib").unwrap();
+    let project_path = workspace.join(format!("year_2023/day_{day:02}"));
+    let info = CrateInfo {
+        year: 2023,
+        day,
+        title: None,
+        dependencies: vec![],
+    };
+    create_crate(&template_set, &project_path, &info, false).unwrap();
+    project_path
+}
+
+#[test]
+fn test_day_status() {
+    let workspace = tempfile::tempdir().unwrap();
+    let project_path = create_day(workspace.path(), 7);
+    let generated = progress(false, false, true);
+    assert_eq!(
+        day_status(&project_path).unwrap(),
+        DayStatus {
+            parse: generated,
+            part_one: generated,
+            part_two: generated,
+        }
+    );
+
+    let lib_path = project_path.join("src/lib.rs");
+    let lib = fs::read_to_string(&lib_path).unwrap();
+    let lib =
+        lib.replacen("    todo!()", "    Ok(Input {})", 1)
+            .replacen("    todo!()", "    1", 1);
+    fs::write(&lib_path, lib).unwrap();
+    fs::create_dir_all(project_path.join("examples")).unwrap();
+    fs::write(project_path.join("examples/1.txt"), "1\n").unwrap();
+    fs::write(
+        project_path.join("examples/expected.toml"),
+        "[[example]]\nfile = \"1.txt\"\npart_one = 1\n",
+    )
+    .unwrap();
+    assert_eq!(
+        day_status(&project_path).unwrap(),
+        DayStatus {
+            parse: progress(true, true, true),
+            part_one: progress(true, true, true),
+            part_two: generated,
+        }
+    );
+}
+
+#[test]
+fn test_render() {
+    let workspace = tempfile::tempdir().unwrap();
+    create_day(workspace.path(), 2);
+    // 2023-12-03T12:00:00Z.
+    let clock = UNIX_EPOCH + Duration::from_secs(1_701_604_800);
+    let years = scan_workspace(workspace.path(), &clock).unwrap();
+    assert_eq!(years.len(), 1);
+    assert_eq!(years[0].unlocked_days, 3);
+
+    let table = render_table(&years);
+    assert!(table.contains("\n2023    1  not started\n2023    2  --B    --B       --B\n"));
+    assert!(table.ends_with(
+        "2023: 1 of 3 days started, 0 of 6 parts implemented, 0 tested, 2 benchmarked\n"
+    ));
+
+    let calendar = render_calendar(&years);
+    // 2023-12-01 was a Friday.
+    assert!(calendar.contains("\n|  |  |  |  | 1 | [2](year_2023/day_02) | 3 |\n| 4 🔒 |"));
+}
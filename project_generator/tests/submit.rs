@@ -0,0 +1,56 @@
+mod common;
+
+use common::StubServer;
+use project_generator::{parse_response, Client, Verdict};
+
+#[test]
+fn test_submit_answer() {
+    let server = StubServer::start(|_| {
+        let html = include_str!("fixtures/answer_too_high.html");
+        (200, html.to_owned())
+    });
+    let client = Client::new(server.url(), "session=abc");
+    let html = client.submit_answer(2023, 10, 1, "7000").unwrap();
+    assert_eq!(parse_response(&html).unwrap().verdict, Verdict::TooHigh);
+    let requests = server.requests();
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].url, "/2023/day/10/answer");
+    assert_eq!(requests[0].cookie.as_deref(), Some("session=abc"));
+    assert_eq!(requests[0].body, "level=1&answer=7000");
+}
+
+#[test]
+fn test_parse_response() {
+    let cases = [
+        (
+            include_str!("fixtures/answer_correct.html"),
+            Verdict::Correct,
+        ),
+        (
+            include_str!("fixtures/answer_incorrect.html"),
+            Verdict::Incorrect,
+        ),
+        (
+            include_str!("fixtures/answer_too_high.html"),
+            Verdict::TooHigh,
+        ),
+        (
+            include_str!("fixtures/answer_rate_limited.html"),
+            Verdict::RateLimited,
+        ),
+        (
+            include_str!("fixtures/answer_already_solved.html"),
+            Verdict::AlreadySolved,
+        ),
+    ];
+    for (html, expected) in cases {
+        assert_eq!(parse_response(html).unwrap().verdict, expected);
+    }
+    let response = parse_response(include_str!("fixtures/answer_rate_limited.html")).unwrap();
+    assert_eq!(
+        response.message,
+        "You gave an answer too recently; you have to wait after submitting an answer \
+         before trying again. You have 41s left to wait. [Return to Day 10]"
+    );
+    assert!(parse_response("<article><p>Hmm.</p></article>").is_err());
+}
@@ -8,12 +8,12 @@ fn criterion_benchmark(c: &mut Criterion) {
         b.iter(|| year_2022_day_01::parse_input(black_box(INPUT)));
     });
 
-    c.bench_function("year_2022_day_01::solve", |b| {
-        b.iter_batched(
-            || group_sums.clone(),
-            |group_sums| year_2022_day_01::solve(black_box(group_sums)),
-            criterion::BatchSize::SmallInput,
-        );
+    c.bench_function("year_2022_day_01::part_one", |b| {
+        b.iter(|| year_2022_day_01::part_one(black_box(&group_sums)));
+    });
+
+    c.bench_function("year_2022_day_01::part_two", |b| {
+        b.iter(|| year_2022_day_01::part_two(black_box(&group_sums)));
     });
 }
 
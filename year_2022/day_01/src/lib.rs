@@ -1,8 +1,8 @@
-use std::num::ParseIntError;
+use aoc_common::{parse_str, Answer, ParseResult, Result, Solution};
 
 pub const INPUT: &str = include_str!("../input.txt");
 
-pub fn parse_input(input: &str) -> Result<Vec<u32>, ParseIntError> {
+pub fn parse_input(input: &str) -> ParseResult<Vec<u32>> {
     let mut sums = vec![];
     let mut sum = 0;
     for line in input.lines() {
@@ -10,30 +10,65 @@ pub fn parse_input(input: &str) -> Result<Vec<u32>, ParseIntError> {
             sums.push(sum);
             sum = 0;
         } else {
-            sum += line.parse::<u32>()?;
+            sum += parse_str::<u32>(input, line)?;
         }
     }
     sums.push(sum);
     Ok(sums)
 }
 
-pub fn solve(mut group_sums: Vec<u32>) -> (u32, u32) {
-    group_sums.sort_unstable();
-    (
-        *group_sums.last().unwrap(),
-        group_sums.iter().rev().take(3).sum::<u32>(),
-    )
+pub fn part_one(group_sums: &[u32]) -> u32 {
+    group_sums.iter().copied().max().unwrap_or_default()
+}
+
+pub fn part_two(group_sums: &[u32]) -> u32 {
+    // The three largest sums, smallest first.
+    let mut top_three = [0; 3];
+    for &sum in group_sums {
+        if sum > top_three[0] {
+            top_three[0] = sum;
+            top_three.sort_unstable();
+        }
+    }
+    top_three.iter().sum()
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 1;
+
+    type Input<'a> = Vec<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
 
+    const CASES: [(&str, u32, u32); 2] = [
+        (INPUT, 70_509, 208_567),
+        (include_str!("../examples/1.txt"), 24_000, 45_000),
+    ];
+
     #[test]
-    fn test_solve() {
-        let group_sums = parse_input(INPUT).unwrap();
-        let (part_one, part_two) = solve(group_sums);
-        assert_eq!(part_one, 70_509);
-        assert_eq!(part_two, 208_567);
+    fn test_parts() {
+        for (input, expected_one, expected_two) in CASES {
+            let group_sums = parse_input(input).unwrap();
+            assert_eq!(part_one(&group_sums), expected_one);
+            assert_eq!(part_two(&group_sums), expected_two);
+        }
     }
 }
@@ -2,8 +2,18 @@ use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use year_2022_day_02::INPUT;
 
 fn criterion_benchmark(c: &mut Criterion) {
-    c.bench_function("year_2022_day_02::solve", |b| {
-        b.iter(|| year_2022_day_02::solve(black_box(INPUT)));
+    let rounds = year_2022_day_02::parse_input(INPUT).unwrap();
+
+    c.bench_function("year_2022_day_02::parse_input", |b| {
+        b.iter(|| year_2022_day_02::parse_input(black_box(INPUT)));
+    });
+
+    c.bench_function("year_2022_day_02::part_one", |b| {
+        b.iter(|| year_2022_day_02::part_one(black_box(&rounds)));
+    });
+
+    c.bench_function("year_2022_day_02::part_two", |b| {
+        b.iter(|| year_2022_day_02::part_two(black_box(&rounds)));
     });
 }
 
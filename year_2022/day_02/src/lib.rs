@@ -1,52 +1,110 @@
+use aoc_common::{Answer, ParseError, ParseResult, Result, Solution};
+
 pub const INPUT: &str = include_str!("../input.txt");
 
-pub fn solve(input: &str) -> (u32, u32) {
-    let mut part_one_sum = 0;
-    let mut part_two_sum = 0;
-    let iter = input.as_bytes().chunks_exact(4); // 4 bytes per line "A X\n"
-    for chunk in iter {
-        let (part_one_score, part_two_score) = match (chunk[0], chunk[2]) {
-            // Opponent: Paper, part one: Rock(1) -> Lose(0), part two: Lose(0) -> Rock(1)
-            (b'B', b'X') => (1, 1),
+/// The scores of a round in parts one and two, indexed by the opponent's
+/// shape (A, B or C) and then by the second column (X, Y or Z).
+const SCORES: [[(u32, u32); 3]; 3] = [
+    [
+        // Opponent: Rock, part one: Rock(1) -> Draw(3), part two: Lose(0) -> Scissors(3)
+        (4, 3),
+        // Opponent: Rock, part one: Paper(2) -> Win(6), part two: Draw(3) -> Rock(1)
+        (8, 4),
+        // Opponent: Rock, part one: Scissors(3) -> Lose(0), part two: Win(6) -> Paper(2)
+        (3, 8),
+    ],
+    [
+        // Opponent: Paper, part one: Rock(1) -> Lose(0), part two: Lose(0) -> Rock(1)
+        (1, 1),
+        // Opponent: Paper, part one: Paper(2) -> Draw(3), part two: Draw(3) -> Paper(2)
+        (5, 5),
+        // Opponent: Paper, part one: Scissors(3) -> Win(6), part two: Win(6) -> Scissors(3)
+        (9, 9),
+    ],
+    [
+        // Opponent: Scissors, part one: Rock(1) -> Win(6), part two: Lose(0) -> Paper(2)
+        (7, 2),
+        // Opponent: Scissors, part one: Paper(2) -> Lose(0), part two: Draw(3) -> Scissors(3)
+        (2, 6),
+        // Opponent: Scissors, part one: Scissors(3) -> Draw(3), part two: Win(6) -> Rock(1)
+        (6, 7),
+    ],
+];
 
-            // Opponent: Scissors, part one: Paper(2) -> Lose(0), part two: Draw(3) -> Scissors(3)
-            (b'C', b'Y') => (2, 6),
+/// Parses each round into the indices of its two columns into [`SCORES`].
+pub fn parse_input(input: &str) -> ParseResult<Vec<(u8, u8)>> {
+    input
+        .lines()
+        .map(|line| match *line.as_bytes() {
+            [opponent @ b'A'..=b'C', b' ', response @ b'X'..=b'Z'] => {
+                Ok((opponent - b'A', response - b'X'))
+            }
+            _ => Err(ParseError::new(
+                input,
+                line,
+                "expected a round like \"A Y\"",
+            )),
+        })
+        .collect()
+}
 
-            // Opponent: Rock, part one: Scissors(3) -> Lose(0), part two: Win(6) -> Paper(2)
-            (b'A', b'Z') => (3, 8),
+pub fn part_one(rounds: &[(u8, u8)]) -> u32 {
+    rounds
+        .iter()
+        .map(|&(opponent, response)| SCORES[opponent as usize][response as usize].0)
+        .sum()
+}
 
-            // Opponent: Rock, part one: Rock(1) -> Draw(3), part two: Lose(0) -> Scissors(3)
-            (b'A', b'X') => (4, 3),
+pub fn part_two(rounds: &[(u8, u8)]) -> u32 {
+    rounds
+        .iter()
+        .map(|&(opponent, response)| SCORES[opponent as usize][response as usize].1)
+        .sum()
+}
 
-            // Opponent: Paper, part one: Paper(2) -> Draw(3), part two: Draw(3) -> Paper(2)
-            (b'B', b'Y') => (5, 5),
+pub struct Day02;
 
-            // Opponent: Scissors, part one: Scissors(3) -> Draw(3), part two: Win(6) -> Rock(1)
-            (b'C', b'Z') => (6, 7),
+impl Solution for Day02 {
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 2;
 
-            // Opponent: Scissors, part one: Rock(1) -> Win(6), part two: Lose(0) -> Paper(2)
-            (b'C', b'X') => (7, 2),
+    type Input<'a> = Vec<(u8, u8)>;
 
-            // Opponent: Rock, part one: Paper(2) -> Win(6), part two: Draw(3) -> Rock(1)
-            (b'A', b'Y') => (8, 4),
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse_input(input)?)
+    }
 
-            // Opponent: Paper, part one: Scissors(3) -> Win(6), part two: Win(6) -> Scissors(3)
-            (b'B', b'Z') => (9, 9),
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
 
-            _ => panic!("Invalid round"),
-        };
-        part_one_sum += part_one_score;
-        part_two_sum += part_two_score;
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
     }
-    (part_one_sum, part_two_sum)
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
 
+    const CASES: [(&str, (u32, u32)); 2] = [
+        (INPUT, (14_531, 11_258)),
+        (include_str!("../examples/1.txt"), (15, 12)),
+    ];
+
+    #[test]
+    fn test_parts() {
+        for (input, expected) in CASES {
+            let rounds = parse_input(input).unwrap();
+            assert_eq!((part_one(&rounds), part_two(&rounds)), expected);
+        }
+    }
+
     #[test]
-    fn test_solve() {
-        assert_eq!(solve(INPUT), (14_531, 11_258));
+    fn test_parse_error() {
+        let error = parse_input("A Y\nB X\nC").unwrap_err();
+        assert_eq!((error.line(), error.column()), (3, 1));
+        let error = parse_input("A Y\nB W\n").unwrap_err();
+        assert_eq!((error.line(), error.column()), (2, 1));
     }
 }
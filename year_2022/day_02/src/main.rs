@@ -1,6 +1,5 @@
-use year_2022_day_02::INPUT;
+use year_2022_day_02::{Day02, INPUT};
 
 fn main() {
-    let (part_one, part_two) = year_2022_day_02::solve(INPUT);
-    println!("{part_one}\n{part_two}");
+    aoc_common::run::<Day02>(INPUT);
 }
@@ -1,8 +1,8 @@
-use year_2022_day_03::INPUT;
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use year_2022_day_03::INPUT;
 
 fn criterion_benchmark(c: &mut Criterion) {
-    let (part_one_sets, part_two_sets) = year_2022_day_03::parse_input(INPUT);
+    let (part_one_sets, part_two_sets) = year_2022_day_03::parse_input(INPUT).unwrap();
 
     c.bench_function("year_2022_day_03::parse_input", |b| {
         b.iter(|| year_2022_day_03::parse_input(black_box(INPUT)));
@@ -1,28 +1,38 @@
+use aoc_common::{Answer, ParseError, ParseResult, Result, Solution};
+
 pub const INPUT: &str = include_str!("../input.txt");
 
 pub type Set = [bool; 52];
 
-pub fn parse_input(input: &str) -> (Vec<(Set, Set)>, Vec<Set>) {
+/// The sets of items in each compartment of each rucksack, and in each
+/// rucksack as a whole.
+pub type Sets = (Vec<(Set, Set)>, Vec<Set>);
+
+pub fn parse_input(input: &str) -> ParseResult<Sets> {
     input
         .lines()
         .map(|line| {
-            let (left, right) = line.split_at(line.len() / 2);
+            if line.len() % 2 != 0 {
+                let message = "a rucksack needs the same number of items in each compartment";
+                return Err(ParseError::new(input, line, message));
+            }
+            let half = line.len() / 2;
             let mut left_set = [false; 52];
             let mut right_set = [false; 52];
             let mut line_set = [false; 52];
-            for byte in left.bytes() {
-                let set_index = to_set_index(byte);
-                left_set[set_index] = true;
+            for (i, byte) in line.bytes().enumerate() {
+                let set_index = to_set_index(byte)
+                    .ok_or_else(|| ParseError::in_span(input, line, i, "expected a letter"))?;
+                if i < half {
+                    left_set[set_index] = true;
+                } else {
+                    right_set[set_index] = true;
+                }
                 line_set[set_index] = true;
             }
-            for byte in right.bytes() {
-                let set_index = to_set_index(byte);
-                right_set[set_index] = true;
-                line_set[set_index] = true;
-            }
-            ((left_set, right_set), line_set)
+            Ok(((left_set, right_set), line_set))
         })
-        .unzip()
+        .collect()
 }
 
 pub fn part_one(sets: &[(Set, Set)]) -> u32 {
@@ -65,12 +75,12 @@ fn priority(byte: u8) -> u32 {
     })
 }
 
-fn to_set_index(byte: u8) -> usize {
-    usize::from(match byte {
-        b'a'..=b'z' => byte - b'a',
-        b'A'..=b'Z' => byte - b'A' + 26,
-        _ => panic!("Invalid byte"),
-    })
+fn to_set_index(byte: u8) -> Option<usize> {
+    match byte {
+        b'a'..=b'z' => Some(usize::from(byte - b'a')),
+        b'A'..=b'Z' => Some(usize::from(byte - b'A' + 26)),
+        _ => None,
+    }
 }
 
 fn to_byte(set_index: u8) -> u8 {
@@ -81,19 +91,50 @@ fn to_byte(set_index: u8) -> u8 {
     }
 }
 
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 3;
+
+    type Input<'a> = Sets;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(&input.0).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(&input.1).into()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
 
+    const PART_ONE_CASES: [(&str, u32); 2] =
+        [(INPUT, 8515), (include_str!("../examples/1.txt"), 157)];
+
+    const PART_TWO_CASES: [(&str, u32); 2] =
+        [(INPUT, 2434), (include_str!("../examples/1.txt"), 70)];
+
     #[test]
     fn test_part_one() {
-        let (part_one_sets, _) = parse_input(INPUT);
-        assert_eq!(part_one(&part_one_sets), 8515);
+        for (input, expected) in PART_ONE_CASES {
+            let (part_one_sets, _) = parse_input(input).unwrap();
+            assert_eq!(part_one(&part_one_sets), expected);
+        }
     }
 
     #[test]
     fn test_part_two() {
-        let (_, part_two_sets) = parse_input(INPUT);
-        assert_eq!(part_two(&part_two_sets), 2434);
+        for (input, expected) in PART_TWO_CASES {
+            let (_, part_two_sets) = parse_input(input).unwrap();
+            assert_eq!(part_two(&part_two_sets), expected);
+        }
     }
 }
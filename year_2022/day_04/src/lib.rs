@@ -1,53 +1,113 @@
-use std::{num::ParseIntError, ops::RangeInclusive};
+use aoc_common::{parse_str, Answer, IntervalSet, ParseError, ParseResult, Result, Solution};
 
 pub const INPUT: &str = include_str!("../input.txt");
 
-type ParseResult = Result<Vec<(RangeInclusive<u8>, RangeInclusive<u8>)>, ParseIntError>;
-pub fn parse_input(input: &str) -> ParseResult {
-    let parts = input.trim().split(['\n', ',', '-']);
-    let numbers = parts.map(str::parse::<u8>).collect::<Result<Vec<_>, _>>()?;
-    let ranges = numbers
-        .chunks_exact(4)
-        .map(|chunk| (chunk[0]..=chunk[1], chunk[2]..=chunk[3]))
-        .collect();
-    Ok(ranges)
+/// The sections an elf is assigned. They are numbered with `u8`, but stored
+/// as `u16` so that the half-open range of section 255 can end at 256.
+type Sections = IntervalSet<u16>;
+
+pub fn parse_input(input: &str) -> ParseResult<Vec<(Sections, Sections)>> {
+    input
+        .lines()
+        .map(|line| {
+            let (first, second) = line
+                .split_once(',')
+                .ok_or_else(|| ParseError::new(input, line, "expected a pair like 2-4,6-8"))?;
+            Ok((
+                parse_sections(input, first)?,
+                parse_sections(input, second)?,
+            ))
+        })
+        .collect()
 }
 
-pub fn part_one(pairs: &[(RangeInclusive<u8>, RangeInclusive<u8>)]) -> usize {
+fn parse_sections(input: &str, range: &str) -> ParseResult<Sections> {
+    let (start, end) = range
+        .split_once('-')
+        .ok_or_else(|| ParseError::new(input, range, "expected a range like 2-4"))?;
+    let start = parse_str::<u8>(input, start)?;
+    let end = parse_str::<u8>(input, end)?;
+    if start > end {
+        return Err(ParseError::new(
+            input,
+            range,
+            "the range must not end before it starts",
+        ));
+    }
+    Ok(Sections::from(start as u16..end as u16 + 1))
+}
+
+pub fn part_one(pairs: &[(Sections, Sections)]) -> usize {
     pairs
         .iter()
-        .filter(|(a, b)| {
-            b.contains(a.start()) && b.contains(a.end())
-                || a.contains(b.start()) && a.contains(b.end())
-        })
+        .filter(|(a, b)| a.difference(b).is_empty() || b.difference(a).is_empty())
         .count()
 }
 
-pub fn part_two(pairs: &[(RangeInclusive<u8>, RangeInclusive<u8>)]) -> usize {
+pub fn part_two(pairs: &[(Sections, Sections)]) -> usize {
     pairs
         .iter()
-        .filter(|(a, b)| {
-            b.contains(a.start())
-                || b.contains(a.end())
-                || a.contains(b.start())
-                || a.contains(b.end())
-        })
+        .filter(|(a, b)| !a.intersection(b).is_empty())
         .count()
 }
 
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 4;
+
+    type Input<'a> = Vec<(Sections, Sections)>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
 
+    const PART_ONE_CASES: [(&str, usize); 2] =
+        [(INPUT, 441), (include_str!("../examples/1.txt"), 2)];
+
+    const PART_TWO_CASES: [(&str, usize); 2] =
+        [(INPUT, 861), (include_str!("../examples/1.txt"), 4)];
+
     #[test]
     fn test_part_one() {
-        let pairs = parse_input(INPUT).unwrap();
-        assert_eq!(part_one(&pairs), 441);
+        for (input, expected) in PART_ONE_CASES {
+            let pairs = parse_input(input).unwrap();
+            assert_eq!(part_one(&pairs), expected);
+        }
     }
 
     #[test]
     fn test_part_two() {
-        let pairs = parse_input(INPUT).unwrap();
-        assert_eq!(part_two(&pairs), 861);
+        for (input, expected) in PART_TWO_CASES {
+            let pairs = parse_input(input).unwrap();
+            assert_eq!(part_two(&pairs), expected);
+        }
+    }
+
+    #[test]
+    fn test_last_section() {
+        let pairs = parse_input("1-255,255-255\n3-3,4-255\n").unwrap();
+        assert_eq!((part_one(&pairs), part_two(&pairs)), (1, 1));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = parse_input("2-4,6-8\n2-3,5-3\n").unwrap_err();
+        assert_eq!((error.line(), error.column()), (2, 5));
+        assert_eq!(error.message(), "the range must not end before it starts");
     }
 }
@@ -1,4 +1,5 @@
-use std::{cell::RefCell, num::ParseIntError};
+use aoc_common::{parse_str, Answer, ParseError, ParseResult, Result, Solution};
+use std::cell::RefCell;
 
 pub const INPUT: &str = include_str!("../input.txt");
 
@@ -9,24 +10,30 @@ pub struct Move {
     pub destination_index: usize,
 }
 
-pub fn parse_input(input: &str) -> Result<(Vec<Vec<u8>>, Vec<Move>), ParseIntError> {
-    let (stacks_str, moves_str) = input.split_once("\n\n").unwrap();
+pub fn parse_input(input: &str) -> ParseResult<(Vec<Vec<u8>>, Vec<Move>)> {
+    let (stacks_str, moves_str) = input.split_once("\n\n").ok_or_else(|| {
+        ParseError::at_end(
+            input,
+            "expected a blank line between the stacks and the moves",
+        )
+    })?;
     let stacks = parse_stacks(stacks_str);
-    let moves = parse_moves(moves_str)?;
+    let moves = parse_moves(input, moves_str, stacks.len())?;
     Ok((stacks, moves))
 }
 
 fn parse_stacks(stacks_str: &str) -> Vec<Vec<u8>> {
     let mut lines_iter = stacks_str.lines().peekable();
-    let line_len = lines_iter.peek().unwrap().len();
+    let line_len = lines_iter.peek().map_or(0, |line| line.len());
     // 4 bytes per "[X] ", +1 because no space at end of line
     let stack_count = (line_len + 1) / 4;
     let mut stacks = vec![vec![]; stack_count];
     for line in lines_iter {
         for (i, stack) in stacks.iter_mut().enumerate() {
-            let byte = line.as_bytes()[i * 4 + 1];
-            if byte.is_ascii_uppercase() {
-                stack.push(byte);
+            // Lines may be missing their trailing spaces.
+            match line.as_bytes().get(i * 4 + 1) {
+                Some(&byte) if byte.is_ascii_uppercase() => stack.push(byte),
+                _ => {}
             }
         }
     }
@@ -34,20 +41,31 @@ fn parse_stacks(stacks_str: &str) -> Vec<Vec<u8>> {
     stacks
 }
 
-pub fn parse_moves(moves_str: &str) -> Result<Vec<Move>, ParseIntError> {
-    let parts = moves_str.trim().split(['\n', ' ']).skip(1).step_by(2);
-    let numbers = parts
-        .map(str::parse::<usize>)
-        .collect::<Result<Vec<_>, _>>()?;
-    let moves = numbers
-        .chunks_exact(3)
-        .map(|chunk| Move {
-            count: chunk[0],
-            source_index: chunk[1] - 1,
-            destination_index: chunk[2] - 1,
+fn parse_moves(input: &str, moves_str: &str, stack_count: usize) -> ParseResult<Vec<Move>> {
+    moves_str
+        .lines()
+        .map(|line| {
+            let invalid =
+                || ParseError::new(input, line, "expected a move like \"move 1 from 2 to 3\"");
+            let rest = line.strip_prefix("move ").ok_or_else(invalid)?;
+            let (count, rest) = rest.split_once(" from ").ok_or_else(invalid)?;
+            let (source, destination) = rest.split_once(" to ").ok_or_else(invalid)?;
+            Ok(Move {
+                count: parse_str(input, count)?,
+                source_index: parse_stack_index(input, source, stack_count)?,
+                destination_index: parse_stack_index(input, destination, stack_count)?,
+            })
         })
-        .collect();
-    Ok(moves)
+        .collect()
+}
+
+fn parse_stack_index(input: &str, span: &str, stack_count: usize) -> ParseResult<usize> {
+    let number = parse_str::<usize>(input, span)?;
+    if !(1..=stack_count).contains(&number) {
+        let message = format!("stacks are numbered from 1 to {stack_count}");
+        return Err(ParseError::new(input, span, message));
+    }
+    Ok(number - 1)
 }
 
 pub fn part_one(mut stacks: Vec<Vec<u8>>, moves: &[Move]) -> String {
@@ -93,19 +111,54 @@ fn get_top_of_stacks(stacks: &[Vec<u8>]) -> String {
     unsafe { String::from_utf8_unchecked(tops) }
 }
 
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 5;
+
+    type Input<'a> = (Vec<Vec<u8>>, Vec<Move>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input.0.clone(), &input.1).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input.0.clone(), &input.1).into()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
 
+    const PART_ONE_CASES: [(&str, &str); 2] = [
+        (INPUT, "PSNRGBTFT"),
+        (include_str!("../examples/1.txt"), "CMZ"),
+    ];
+
+    const PART_TWO_CASES: [(&str, &str); 2] = [
+        (INPUT, "BNTZFPMMW"),
+        (include_str!("../examples/1.txt"), "MCD"),
+    ];
+
     #[test]
     fn test_part_one() {
-        let (stacks, moves) = parse_input(INPUT).unwrap();
-        assert_eq!(part_one(stacks, &moves), "PSNRGBTFT");
+        for (input, expected) in PART_ONE_CASES {
+            let (stacks, moves) = parse_input(input).unwrap();
+            assert_eq!(part_one(stacks, &moves), expected);
+        }
     }
 
     #[test]
     fn test_part_two() {
-        let (stacks, moves) = parse_input(INPUT).unwrap();
-        assert_eq!(part_two(stacks, &moves), "BNTZFPMMW");
+        for (input, expected) in PART_TWO_CASES {
+            let (stacks, moves) = parse_input(input).unwrap();
+            assert_eq!(part_two(stacks, &moves), expected);
+        }
     }
 }
@@ -1,13 +1,19 @@
-use year_2022_day_06::INPUT;
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use year_2022_day_06::INPUT;
 
 fn criterion_benchmark(c: &mut Criterion) {
+    let signal = year_2022_day_06::parse_input(INPUT).unwrap();
+
+    c.bench_function("year_2022_day_06::parse_input", |b| {
+        b.iter(|| year_2022_day_06::parse_input(black_box(INPUT)));
+    });
+
     c.bench_function("year_2022_day_06::part_one", |b| {
-        b.iter(|| year_2022_day_06::part_one(black_box(INPUT)));
+        b.iter(|| year_2022_day_06::part_one(black_box(signal)));
     });
 
     c.bench_function("year_2022_day_06::part_two", |b| {
-        b.iter(|| year_2022_day_06::part_two(black_box(INPUT)));
+        b.iter(|| year_2022_day_06::part_two(black_box(signal)));
     });
 }
 
@@ -1,24 +1,44 @@
+use aoc_common::{Answer, Error, ParseError, ParseResult, Result, Solution};
+
 pub const INPUT: &str = include_str!("../input.txt");
 
-pub fn part_one(input: &str) -> usize {
-    find_unique_window_index(input.trim().as_bytes(), 4).unwrap()
+pub fn parse_input(input: &str) -> ParseResult<&[u8]> {
+    let signal = input.trim();
+    if let Some(i) = signal.bytes().position(|b| !b.is_ascii_lowercase()) {
+        return Err(ParseError::in_span(
+            input,
+            signal,
+            i,
+            "expected a lowercase letter",
+        ));
+    }
+    Ok(signal.as_bytes())
+}
+
+pub fn part_one(signal: &[u8]) -> Result<usize> {
+    find_unique_window_index(signal, 4)
+        .ok_or_else(|| Error::msg("there is no start-of-packet marker"))
 }
 
-pub fn part_two(input: &str) -> usize {
-    find_unique_window_index(input.trim().as_bytes(), 14).unwrap()
+pub fn part_two(signal: &[u8]) -> Result<usize> {
+    find_unique_window_index(signal, 14)
+        .ok_or_else(|| Error::msg("there is no start-of-message marker"))
 }
 
 fn find_unique_window_index(bytes: &[u8], window_size: usize) -> Option<usize> {
     let mut byte_counts = [0; 26];
     let mut duplicate_count = 0;
 
-    for &byte in &bytes[..window_size] {
+    for &byte in bytes.get(..window_size)? {
         let byte_count = &mut byte_counts[to_index(byte)];
         *byte_count += 1;
         if *byte_count == 2 {
             duplicate_count += 1;
         }
     }
+    if duplicate_count == 0 {
+        return Some(window_size);
+    }
 
     for index in window_size..bytes.len() {
         let removed_byte = bytes[index - window_size];
@@ -44,17 +64,72 @@ const fn to_index(byte: u8) -> usize {
     (byte - b'a') as usize
 }
 
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 6;
+
+    type Input<'a> = &'a [u8];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
 
+    const PART_ONE_CASES: [(&str, usize); 6] = [
+        (INPUT, 1647),
+        (include_str!("../examples/1.txt"), 7),
+        (include_str!("../examples/2.txt"), 5),
+        (include_str!("../examples/3.txt"), 6),
+        (include_str!("../examples/4.txt"), 10),
+        (include_str!("../examples/5.txt"), 11),
+    ];
+
+    const PART_TWO_CASES: [(&str, usize); 6] = [
+        (INPUT, 2447),
+        (include_str!("../examples/1.txt"), 19),
+        (include_str!("../examples/2.txt"), 23),
+        (include_str!("../examples/3.txt"), 23),
+        (include_str!("../examples/4.txt"), 29),
+        (include_str!("../examples/5.txt"), 26),
+    ];
+
     #[test]
     fn test_part_one() {
-        assert_eq!(part_one(INPUT), 1647);
+        for (input, expected) in PART_ONE_CASES {
+            let signal = parse_input(input).unwrap();
+            assert_eq!(part_one(signal).unwrap(), expected);
+        }
     }
 
     #[test]
     fn test_part_two() {
-        assert_eq!(part_two(INPUT), 2447);
+        for (input, expected) in PART_TWO_CASES {
+            let signal = parse_input(input).unwrap();
+            assert_eq!(part_two(signal).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_no_marker() {
+        let signal = parse_input("abcabcabcabcabcabc\n").unwrap();
+        assert!(part_one(signal).is_err());
+        assert_eq!(part_one(b"abcd").unwrap(), 4);
+        assert!(part_two(b"abcd").is_err());
+        let error = parse_input("abcD\n").unwrap_err();
+        assert_eq!((error.line(), error.column()), (1, 4));
     }
 }
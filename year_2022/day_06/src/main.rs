@@ -1,22 +1,5 @@
-use year_2022_day_06::INPUT;
-use std::env;
+use year_2022_day_06::{Day06, INPUT};
 
 fn main() {
-    match env::args().nth(1).as_deref() {
-        Some("all") => {
-            let part_one = year_2022_day_06::part_one(INPUT);
-            println!("{part_one}");
-            let part_two = year_2022_day_06::part_two(INPUT);
-            println!("{part_two}");
-        }
-        Some("one") => {
-            let part_one = year_2022_day_06::part_one(INPUT);
-            println!("{part_one}");
-        }
-        Some("two") => {
-            let part_two = year_2022_day_06::part_two(INPUT);
-            println!("{part_two}");
-        }
-        _ => println!("Invalid argument: must be one of all, one, or two"),
-    }
+    aoc_common::run::<Day06>(INPUT);
 }
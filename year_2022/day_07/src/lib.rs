@@ -1,34 +1,38 @@
+use aoc_common::{parse_str, Answer, ParseError, ParseResult, Result, Solution};
+
 pub const INPUT: &str = include_str!("../input.txt");
 
-pub fn parse_input(input: &str) -> (Vec<usize>, usize) {
+pub fn parse_input(input: &str) -> ParseResult<(Vec<usize>, usize)> {
     let mut sizes = vec![];
     let mut root_size = 0;
     let mut stack: Vec<usize> = vec![];
     for line in input.lines() {
         match line {
             "$ cd .." => {
-                let size = stack.pop().unwrap();
+                let size = stack.pop().ok_or_else(|| {
+                    ParseError::new(input, line, "there is no directory to leave")
+                })?;
                 sizes.push(size);
             }
-            s if s.starts_with("$ cd") => {
+            "$ ls" => {}
+            s if s.starts_with("$ cd ") => {
                 stack.push(0);
             }
+            s if s.starts_with("dir ") => {}
             s => {
-                let file_size = s
-                    .split_whitespace()
-                    .next()
-                    .and_then(|s| s.parse::<usize>().ok());
-                if let Some(size) = file_size {
-                    for directory_size in &mut stack {
-                        *directory_size += size;
-                    }
-                    root_size += size;
+                let (size, _) = s.split_once(' ').ok_or_else(|| {
+                    ParseError::new(input, line, "expected a command, a directory or a file")
+                })?;
+                let size = parse_str::<usize>(input, size)?;
+                for directory_size in &mut stack {
+                    *directory_size += size;
                 }
+                root_size += size;
             }
         }
     }
     sizes.append(&mut stack);
-    (sizes, root_size)
+    Ok((sizes, root_size))
 }
 
 pub fn part_one(directory_sizes: &[usize]) -> usize {
@@ -51,19 +55,54 @@ pub fn part_two(directory_sizes: &[usize], root_size: usize) -> usize {
         .unwrap()
 }
 
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 7;
+
+    type Input<'a> = (Vec<usize>, usize);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(&input.0).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(&input.0, input.1).into()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
 
+    const PART_ONE_CASES: [(&str, usize); 2] = [
+        (INPUT, 1_743_217),
+        (include_str!("../examples/1.txt"), 95_437),
+    ];
+
+    const PART_TWO_CASES: [(&str, usize); 2] = [
+        (INPUT, 8_319_096),
+        (include_str!("../examples/1.txt"), 24_933_642),
+    ];
+
     #[test]
     fn test_part_one() {
-        let (directory_sizes, _) = parse_input(INPUT);
-        assert_eq!(part_one(&directory_sizes), 1_743_217);
+        for (input, expected) in PART_ONE_CASES {
+            let (directory_sizes, _) = parse_input(input).unwrap();
+            assert_eq!(part_one(&directory_sizes), expected);
+        }
     }
 
     #[test]
     fn test_part_two() {
-        let (directory_sizes, root_size) = parse_input(INPUT);
-        assert_eq!(part_two(&directory_sizes, root_size), 8_319_096);
+        for (input, expected) in PART_TWO_CASES {
+            let (directory_sizes, root_size) = parse_input(input).unwrap();
+            assert_eq!(part_two(&directory_sizes, root_size), expected);
+        }
     }
 }
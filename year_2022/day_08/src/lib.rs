@@ -1,69 +1,41 @@
+use aoc_common::{Answer, Grid, ParseResult, Result, Solution};
 use rayon::prelude::*;
 use std::cmp;
 
 pub const INPUT: &str = include_str!("../input.txt");
 
-pub struct Grid {
-    size: usize,
-    data: Vec<u8>,
+pub fn parse_input(input: &str) -> ParseResult<Grid<u8>> {
+    Grid::try_parse(input, |byte| match byte {
+        b'0'..=b'9' => Ok(byte - b'0'),
+        _ => Err("expected a digit"),
+    })
 }
 
-impl Grid {
-    pub fn rows(&self) -> impl Iterator<Item = &[u8]> + ExactSizeIterator + DoubleEndedIterator {
-        self.data.chunks_exact(self.size)
-    }
-
-    pub fn par_rows(&self) -> impl ParallelIterator<Item = &[u8]> + IndexedParallelIterator {
-        self.data.par_chunks_exact(self.size)
-    }
-
-    pub fn get(&self, x: usize, y: usize) -> u8 {
-        self.data[y * self.size + x]
-    }
-}
-
-pub fn parse_input(input: &str) -> Grid {
-    let size = input.find('\n').unwrap();
-    let data: Vec<_> = input
-        .bytes()
-        .filter_map(|byte| {
-            if byte.is_ascii_digit() {
-                Some(byte - b'0')
-            } else {
-                None
-            }
-        })
-        .collect();
-    Grid { size, data }
-}
-
-pub fn part_one(grid: &Grid) -> usize {
-    let mut visible = vec![false; grid.size * grid.size];
+pub fn part_one(grid: &Grid<u8>) -> usize {
+    let mut visible = Grid::new(grid.width(), grid.height(), false);
     let mut visible_count = 0;
 
-    let mut max_up: Vec<Option<u8>> = vec![None; grid.size];
+    let mut max_up: Vec<Option<u8>> = vec![None; grid.width()];
     for (y, row) in grid.rows().enumerate() {
         let mut max_left = None;
         for (x, &digit) in row.iter().enumerate() {
-            let index = y * grid.size + x;
             if Some(digit) > max_left || Some(digit) > max_up[x] {
                 visible_count += 1;
-                visible[index] = true;
+                visible[(x, y)] = true;
                 max_left = cmp::max(max_left, Some(digit));
                 max_up[x] = cmp::max(max_up[x], Some(digit));
             }
         }
     }
 
-    let mut max_down: Vec<Option<u8>> = vec![None; grid.size];
+    let mut max_down: Vec<Option<u8>> = vec![None; grid.width()];
     for (y, row) in grid.rows().enumerate().rev() {
         let mut max_right = None;
         for (x, &digit) in row.iter().enumerate().rev() {
-            let index = y * grid.size + x;
             if Some(digit) > max_right || Some(digit) > max_down[x] {
-                if !visible[index] {
+                if !visible[(x, y)] {
                     visible_count += 1;
-                    visible[index] = true;
+                    visible[(x, y)] = true;
                 }
                 max_right = cmp::max(max_right, Some(digit));
                 max_down[x] = cmp::max(max_down[x], Some(digit));
@@ -74,53 +46,16 @@ pub fn part_one(grid: &Grid) -> usize {
     visible_count
 }
 
-pub fn part_two(grid: &Grid) -> usize {
+pub fn part_two(grid: &Grid<u8>) -> usize {
     grid.par_rows()
         .enumerate()
         .flat_map_iter(|(y, row)| {
             row.iter().enumerate().map(move |(x, &digit)| {
-                let left_count = (0..x)
-                    .rev()
-                    .enumerate()
-                    .find_map(|(i, left_x)| {
-                        if grid.get(left_x, y) >= digit {
-                            Some(i + 1)
-                        } else {
-                            None
-                        }
-                    })
-                    .unwrap_or(x);
-                let right_count = (x + 1..grid.size)
-                    .enumerate()
-                    .find_map(|(i, right_x)| {
-                        if grid.get(right_x, y) >= digit {
-                            Some(i + 1)
-                        } else {
-                            None
-                        }
-                    })
-                    .unwrap_or(grid.size - x - 1);
-                let up_count = (0..y)
-                    .rev()
-                    .enumerate()
-                    .find_map(|(i, up_y)| {
-                        if grid.get(x, up_y) >= digit {
-                            Some(i + 1)
-                        } else {
-                            None
-                        }
-                    })
-                    .unwrap_or(y);
-                let down_count = (y + 1..grid.size)
-                    .enumerate()
-                    .find_map(|(i, down_y)| {
-                        if grid.get(x, down_y) >= digit {
-                            Some(i + 1)
-                        } else {
-                            None
-                        }
-                    })
-                    .unwrap_or(grid.size - y - 1);
+                let left_count = viewing_distance(digit, row[..x].iter().rev());
+                let right_count = viewing_distance(digit, &row[x + 1..]);
+                let up_count = viewing_distance(digit, (0..y).rev().map(|y| &grid[(x, y)]));
+                let down_count =
+                    viewing_distance(digit, (y + 1..grid.height()).map(|y| &grid[(x, y)]));
 
                 left_count * right_count * up_count * down_count
             })
@@ -129,19 +64,63 @@ pub fn part_two(grid: &Grid) -> usize {
         .unwrap()
 }
 
+/// The number of trees seen looking past `trees` from a tree of `height`,
+/// up to and including the first one that is at least as tall.
+fn viewing_distance<'a>(height: u8, trees: impl IntoIterator<Item = &'a u8>) -> usize {
+    let mut count = 0;
+    for &tree in trees {
+        count += 1;
+        if tree >= height {
+            break;
+        }
+    }
+    count
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 8;
+
+    type Input<'a> = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
 
+    const PART_ONE_CASES: [(&str, usize); 2] =
+        [(INPUT, 1681), (include_str!("../examples/1.txt"), 21)];
+
+    const PART_TWO_CASES: [(&str, usize); 2] =
+        [(INPUT, 201_684), (include_str!("../examples/1.txt"), 8)];
+
     #[test]
     fn test_part_one() {
-        let grid = parse_input(INPUT);
-        assert_eq!(part_one(&grid), 1681);
+        for (input, expected) in PART_ONE_CASES {
+            let grid = parse_input(input).unwrap();
+            assert_eq!(part_one(&grid), expected);
+        }
     }
 
     #[test]
     fn test_part_two() {
-        let grid = parse_input(INPUT);
-        assert_eq!(part_two(&grid), 201_684);
+        for (input, expected) in PART_TWO_CASES {
+            let grid = parse_input(input).unwrap();
+            assert_eq!(part_two(&grid), expected);
+        }
     }
 }
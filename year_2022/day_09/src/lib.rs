@@ -1,43 +1,26 @@
+use aoc_common::{parse_str, Answer, Direction, ParseError, ParseResult, Point2, Result, Solution};
 use rustc_hash::FxHashSet;
 
 pub const INPUT: &str = include_str!("../input.txt");
 
-#[derive(Debug, Clone, Copy)]
-pub enum Direction {
-    Left,
-    Right,
-    Up,
-    Down,
-}
-
 type Motion = (Direction, usize);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
-struct Position {
-    x: i32,
-    y: i32,
-}
-
-impl Position {
-    const fn is_adjacent_to(self, other: Self) -> bool {
-        self.x.abs_diff(other.x) <= 1 && self.y.abs_diff(other.y) <= 1
-    }
-}
-
-pub fn parse_input(input: &str) -> Vec<Motion> {
+pub fn parse_input(input: &str) -> ParseResult<Vec<Motion>> {
     input
         .lines()
         .map(|line| {
-            let (d, s) = line.split_once(' ').unwrap();
+            let (d, s) = line
+                .split_once(' ')
+                .ok_or_else(|| ParseError::new(input, line, "expected a motion like \"R 4\""))?;
             let direction = match d {
                 "L" => Direction::Left,
                 "R" => Direction::Right,
                 "U" => Direction::Up,
                 "D" => Direction::Down,
-                _ => panic!("invalid direction {d}"),
+                _ => return Err(ParseError::new(input, d, "expected L, R, U or D")),
             };
-            let steps = s.parse().unwrap();
-            (direction, steps)
+            let steps = parse_str(input, s)?;
+            Ok((direction, steps))
         })
         .collect()
 }
@@ -51,26 +34,18 @@ pub fn part_two(motions: &[Motion]) -> usize {
 }
 
 fn count_tail_positions(motions: &[Motion], knot_count: usize) -> usize {
-    let mut current_positions = vec![Position::default(); knot_count];
+    let mut current_positions = vec![Point2::ORIGIN; knot_count];
     let mut tail_positions = FxHashSet::default();
     for &(direction, step_count) in motions {
-        let (dx, dy) = match direction {
-            Direction::Left => (-1, 0),
-            Direction::Right => (1, 0),
-            Direction::Up => (0, 1),
-            Direction::Down => (0, -1),
-        };
+        let offset = direction.offset();
         for _ in 0..step_count {
-            let head_position = &mut current_positions[0];
-            head_position.x += dx;
-            head_position.y += dy;
-            let mut leader = *head_position;
+            current_positions[0] += offset;
+            let mut leader = current_positions[0];
             for follower in &mut current_positions[1..] {
-                if leader.is_adjacent_to(*follower) {
+                if leader.chebyshev_distance(*follower) <= 1 {
                     break;
                 }
-                follower.x += (leader.x - follower.x).signum();
-                follower.y += (leader.y - follower.y).signum();
+                *follower += (leader - *follower).signum();
                 leader = *follower;
             }
             tail_positions.insert(current_positions[knot_count - 1]);
@@ -79,19 +54,53 @@ fn count_tail_positions(motions: &[Motion], knot_count: usize) -> usize {
     tail_positions.len()
 }
 
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 9;
+
+    type Input<'a> = Vec<Motion>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
 
+    const PART_ONE_CASES: [(&str, usize); 2] =
+        [(INPUT, 6087), (include_str!("../examples/1.txt"), 13)];
+
+    const PART_TWO_CASES: [(&str, usize); 3] = [
+        (INPUT, 2493),
+        (include_str!("../examples/1.txt"), 1),
+        (include_str!("../examples/2.txt"), 36),
+    ];
+
     #[test]
     fn test_part_one() {
-        let motions = parse_input(INPUT);
-        assert_eq!(part_one(&motions), 6087);
+        for (input, expected) in PART_ONE_CASES {
+            let motions = parse_input(input).unwrap();
+            assert_eq!(part_one(&motions), expected);
+        }
     }
 
     #[test]
     fn test_part_two() {
-        let motions = parse_input(INPUT);
-        assert_eq!(part_two(&motions), 2493);
+        for (input, expected) in PART_TWO_CASES {
+            let motions = parse_input(input).unwrap();
+            assert_eq!(part_two(&motions), expected);
+        }
     }
 }
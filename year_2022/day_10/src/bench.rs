@@ -1,15 +1,19 @@
-use year_2022_day_10::INPUT;
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use year_2022_day_10::INPUT;
 
 fn criterion_benchmark(c: &mut Criterion) {
-    let instructions = year_2022_day_10::parse_input(INPUT);
+    let instructions = year_2022_day_10::parse_input(INPUT).unwrap();
 
     c.bench_function("year_2022_day_10::parse_input", |b| {
         b.iter(|| year_2022_day_10::parse_input(black_box(INPUT)));
     });
 
-    c.bench_function("year_2022_day_10::solve", |b| {
-        b.iter(|| year_2022_day_10::solve(black_box(&instructions)));
+    c.bench_function("year_2022_day_10::part_one", |b| {
+        b.iter(|| year_2022_day_10::part_one(black_box(&instructions)));
+    });
+
+    c.bench_function("year_2022_day_10::part_two", |b| {
+        b.iter(|| year_2022_day_10::part_two(black_box(&instructions)));
     });
 }
 
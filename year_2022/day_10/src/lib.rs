@@ -1,3 +1,6 @@
+use aoc_common::{ocr, parse_str, Answer, ParseError, ParseResult, Result, Solution};
+use std::iter;
+
 pub const INPUT: &str = include_str!("../input.txt");
 
 pub enum Instruction {
@@ -5,59 +8,86 @@ pub enum Instruction {
     Add(i32),
 }
 
-pub fn parse_input(input: &str) -> Vec<Instruction> {
+pub fn parse_input(input: &str) -> ParseResult<Vec<Instruction>> {
     input
         .lines()
-        .map(|line| {
-            if line.starts_with('a') {
-                Instruction::Add(line[5..].parse().unwrap())
-            } else {
-                Instruction::Noop
-            }
+        .map(|line| match line.strip_prefix("addx ") {
+            Some(value) => Ok(Instruction::Add(parse_str(input, value)?)),
+            None if line == "noop" => Ok(Instruction::Noop),
+            None => Err(ParseError::new(input, line, "expected addx or noop")),
         })
         .collect()
 }
 
-pub fn solve(instructions: &[Instruction]) -> (i32, String) {
-    const CYCLES: [i32; 6] = [20, 60, 100, 140, 180, 220];
-    let mut sum = 0;
-    let mut display = String::new();
-    let mut sprite_position = 1;
-    let mut display_position = 0;
+/// The value of the X register during each cycle, starting with the first.
+fn register_values(instructions: &[Instruction]) -> impl Iterator<Item = i32> + '_ {
+    let mut register = 1;
     let mut stored_add = None;
     let mut instruction_iter = instructions.iter().peekable();
-
-    for cycle in 1.. {
-        if instruction_iter.peek().is_none() {
-            break;
-        }
-        if CYCLES.contains(&cycle) {
-            sum += sprite_position * cycle;
+    iter::from_fn(move || {
+        instruction_iter.peek()?;
+        let value = register;
+        match stored_add.take() {
+            Some(v) => register += v,
+            None => {
+                if let Some(Instruction::Add(v)) = instruction_iter.next() {
+                    stored_add = Some(*v);
+                }
+            }
         }
+        Some(value)
+    })
+}
 
-        if display_position == 40 {
+/// The sum of the signal strengths during the 20th, 60th, ... 220th cycles.
+pub fn part_one(instructions: &[Instruction]) -> i32 {
+    const CYCLES: [i32; 6] = [20, 60, 100, 140, 180, 220];
+    register_values(instructions)
+        .zip(1..)
+        .filter(|(_, cycle)| CYCLES.contains(cycle))
+        .map(|(register, cycle)| register * cycle)
+        .sum()
+}
+
+/// The picture drawn on the display.
+pub fn part_two(instructions: &[Instruction]) -> String {
+    let mut display = String::new();
+    for (sprite_position, display_position) in register_values(instructions).zip((0..40).cycle()) {
+        if display_position == 0 && !display.is_empty() {
             display.push('\n');
-            display_position = 0;
         }
         if (display_position - sprite_position).abs() <= 1 {
             display.push('#');
         } else {
             display.push('.');
         }
-        display_position += 1;
+    }
+    display
+}
 
-        match stored_add.take() {
-            Some(v) => sprite_position += v,
-            None => match instruction_iter.next() {
-                Some(Instruction::Noop) => {}
-                Some(Instruction::Add(v)) => {
-                    stored_add = Some(*v);
-                }
-                None => unreachable!(),
-            },
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 10;
+
+    type Input<'a> = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        let display = part_two(input);
+        match ocr(&display) {
+            Ok(letters) => letters.into(),
+            Err(err) => Answer::None(format!("{err}\n\n{display}")),
         }
     }
-    (sum, display)
 }
 
 #[cfg(test)]
@@ -65,12 +95,12 @@ mod test {
     use super::*;
 
     #[test]
-    fn test_solve() {
-        let instructions = parse_input(INPUT);
-        let (part_one, part_two) = solve(&instructions);
-        assert_eq!(part_one, 15680);
+    fn test_input() {
+        let instructions = parse_input(INPUT).unwrap();
+        assert_eq!(part_one(&instructions), 15680);
+        let picture = part_two(&instructions);
         assert_eq!(
-            part_two,
+            picture,
             "####.####.###..####.#..#..##..#..#.###..\n\
              ...#.#....#..#.#....#..#.#..#.#..#.#..#.\n\
              ..#..###..###..###..####.#....#..#.#..#.\n\
@@ -78,5 +108,26 @@ mod test {
              #....#....#..#.#....#..#.#..#.#..#.#....\n\
              ####.#....###..#....#..#..###..##..#...."
         );
+        assert_eq!(ocr(&picture).unwrap(), "ZFBFHGUP");
+        assert_eq!(Day10::part_two(&instructions), Answer::from("ZFBFHGUP"));
+    }
+
+    #[test]
+    fn test_example() {
+        let instructions = parse_input(include_str!("../examples/1.txt")).unwrap();
+        assert_eq!(part_one(&instructions), 13140);
+        let picture = "##..##..##..##..##..##..##..##..##..##..\n\
+                       ###...###...###...###...###...###...###.\n\
+                       ####....####....####....####....####....\n\
+                       #####.....#####.....#####.....#####.....\n\
+                       ######......######......######......####\n\
+                       #######.......#######.......#######.....";
+        assert_eq!(part_two(&instructions), picture);
+        // The example draws stripes rather than letters, so part two has no
+        // answer, and the picture is shown instead.
+        let Answer::None(reason) = Day10::part_two(&instructions) else {
+            panic!("the stripes were read as letters");
+        };
+        assert!(reason.ends_with(picture), "{reason}");
     }
 }
@@ -1,14 +1,5 @@
-use year_2022_day_10::INPUT;
-use std::env;
+use year_2022_day_10::{Day10, INPUT};
 
 fn main() {
-    let parse_result = year_2022_day_10::parse_input(INPUT);
-    match env::args().nth(1).as_deref() {
-        Some("all") => {
-            let (part_one, part_two) = year_2022_day_10::solve(&parse_result);
-            println!("{part_one}\n{part_two}");
-        }
-        Some("parse") => {}
-        _ => println!("Invalid argument: must be one of all or parse"),
-    }
+    aoc_common::run::<Day10>(INPUT);
 }
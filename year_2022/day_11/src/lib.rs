@@ -1,4 +1,4 @@
-use std::collections::{btree_map::Entry, BTreeMap};
+use aoc_common::{find_cycle, lcm_all, Answer, Result, Solution};
 
 pub const INPUT: &str = include_str!("../input.txt");
 
@@ -31,47 +31,45 @@ pub fn part_one(monkeys: &mut Monkeys) -> u64 {
     for _ in 0..ROUNDS {
         round(monkeys, |v| v / 3);
     }
-    monkey_business_level(monkeys)
+    monkey_business_level(&get_inspection_counts(monkeys))
 }
 
 pub fn part_two(monkeys: &mut Monkeys) -> u64 {
-    const ROUNDS: u64 = 10_000;
-    let test_divisors_product = monkeys
-        .iter()
-        .map(|monkey| monkey.test.divisor)
-        .product::<u64>();
-    let mut seen = BTreeMap::default();
-    let mut previous_inspection_counts = vec![];
-    for round_number in 0..ROUNDS {
-        previous_inspection_counts.push(get_inspection_counts(monkeys));
-        let monkeys_held_items = monkeys
-            .iter()
-            .map(|m| m.held_items.clone())
-            .collect::<Vec<_>>();
-        match seen.entry(monkeys_held_items) {
-            Entry::Vacant(v) => v.insert(round_number),
-            Entry::Occupied(o) => {
-                let cycle_start = *o.get();
-                let cycle_len = round_number - cycle_start;
-                let remaining_rounds = ROUNDS - round_number;
-                let remaining_cycles = remaining_rounds / cycle_len;
-                let remainder = remaining_rounds % cycle_len;
-                let cycle_counts = &previous_inspection_counts[cycle_start as usize..];
-                let cycle_start_counts = &cycle_counts[0];
-                let cycle_end_counts = cycle_counts.last().unwrap();
-                let remainder_counts = &cycle_counts[remainder as usize];
-                for (i, monkey) in monkeys.iter_mut().enumerate() {
-                    let cycle_increment = cycle_end_counts[i] - cycle_start_counts[i];
-                    let remainder_increment = remainder_counts[i] - cycle_start_counts[i];
-                    monkey.inspection_count +=
-                        cycle_increment * remaining_cycles + remainder_increment;
-                }
-                break;
-            }
-        };
-        round(monkeys, |v| v % test_divisors_product);
+    const ROUNDS: usize = 10_000;
+    let inspection_counts = inspection_counts_after(monkeys, ROUNDS);
+    monkey_business_level(&inspection_counts)
+}
+
+/// The inspection counts after `rounds` rounds with the worry levels kept
+/// small, extrapolated from the first time every monkey holds the same items
+/// as in an earlier round.
+fn inspection_counts_after(monkeys: &mut Monkeys, rounds: usize) -> Vec<u64> {
+    let modulus = worry_modulus(monkeys);
+    let cycle = find_cycle(
+        monkeys,
+        |monkeys| round(monkeys, |v| v % modulus),
+        |monkeys| {
+            monkeys
+                .iter()
+                .map(|m| m.held_items.clone())
+                .collect::<Vec<_>>()
+        },
+        get_inspection_counts,
+        rounds,
+    );
+    match cycle {
+        Some(cycle) => (0..monkeys.len())
+            .map(|i| cycle.extrapolate(rounds, |counts| counts[i]))
+            .collect(),
+        // There was no cycle, so all the rounds were simulated
+        None => get_inspection_counts(monkeys),
     }
-    monkey_business_level(monkeys)
+}
+
+/// Worry levels can be kept modulo this without changing where any item is
+/// thrown, since every test divisor divides it.
+fn worry_modulus(monkeys: &Monkeys) -> u64 {
+    lcm_all(monkeys.iter().map(|monkey| monkey.test.divisor))
 }
 
 fn round<F>(monkeys: &mut Monkeys, map_operation_result: F)
@@ -93,7 +91,7 @@ where
                 Operation::Multiply(n) => item * n,
                 Operation::Square => item * item,
             });
-            let destination = if result % divisor == 0 {
+            let destination = if result.is_multiple_of(divisor) {
                 true_destination
             } else {
                 false_destination
@@ -103,8 +101,8 @@ where
     }
 }
 
-fn monkey_business_level(monkeys: &Monkeys) -> u64 {
-    let mut inspection_counts = get_inspection_counts(monkeys);
+fn monkey_business_level(inspection_counts: &[u64]) -> u64 {
+    let mut inspection_counts = inspection_counts.to_vec();
     inspection_counts.sort_unstable();
     inspection_counts.into_iter().rev().take(2).product()
 }
@@ -115,25 +113,47 @@ fn get_inspection_counts(monkeys: &Monkeys) -> Vec<u64> {
 
 pub mod parser {
     use super::{Monkey, Monkeys, Operation, Test};
-    use anyhow::{anyhow, Result};
+    use aoc_common::{ParseError, ParseResult};
     use nom::{
         branch::alt,
         bytes::complete::tag,
         character::complete::{char, line_ending, u64},
-        combinator::map,
+        combinator::{consumed, cut, map},
         multi::separated_list0,
         sequence::{pair, preceded, terminated, tuple},
         Finish, IResult,
     };
 
-    pub fn parse(input: &str) -> Result<Monkeys> {
+    /// The spans of a monkey's number and of the monkeys it throws to, so
+    /// that they can be checked once every monkey is parsed.
+    type Spans<'a> = (&'a str, [&'a str; 2]);
+
+    pub fn parse(input: &str) -> ParseResult<Monkeys> {
         let (rest, monkeys) = monkeys(input)
             .finish()
-            .map_err(|err| anyhow!(err.to_string()))?;
+            .map_err(|err| ParseError::from_nom(input, err))?;
         if !rest.is_empty() {
-            return Err(anyhow!("Unparsed input: {}", rest));
+            return Err(ParseError::new(input, rest, "expected a monkey"));
+        }
+        let count = monkeys.len();
+        for (id, (monkey, (id_span, destination_spans))) in monkeys.iter().enumerate() {
+            if id_span.parse() != Ok(id) {
+                let message = format!("expected monkey {id}");
+                return Err(ParseError::new(input, id_span, message));
+            }
+            let destinations = [monkey.test.true_destination, monkey.test.false_destination];
+            for (destination, span) in destinations.into_iter().zip(destination_spans) {
+                if destination >= count {
+                    let message = format!("there are only {count} monkeys");
+                    return Err(ParseError::new(input, span, message));
+                }
+                if destination == id {
+                    let message = "a monkey can't throw to itself";
+                    return Err(ParseError::new(input, span, message));
+                }
+            }
         }
-        Ok(monkeys)
+        Ok(monkeys.into_iter().map(|(monkey, _)| monkey).collect())
     }
 
     fn starting_items(input: &str) -> IResult<&str, Vec<u64>> {
@@ -153,56 +173,130 @@ pub mod parser {
         terminated(line, line_ending)(input)
     }
 
-    fn test(input: &str) -> IResult<&str, Test> {
+    fn test(input: &str) -> IResult<&str, (Test, [&str; 2])> {
         let divisible_by_line =
             terminated(preceded(tag("  Test: divisible by "), u64), line_ending);
         let if_true_line = terminated(
-            preceded(tag("    If true: throw to monkey "), u64),
+            preceded(tag("    If true: throw to monkey "), consumed(u64)),
             line_ending,
         );
         let if_false_line = terminated(
-            preceded(tag("    If false: throw to monkey "), u64),
+            preceded(tag("    If false: throw to monkey "), consumed(u64)),
             line_ending,
         );
         let lines = tuple((divisible_by_line, if_true_line, if_false_line));
-        let map_fn = |(divisor, true_destination, false_destination)| Test {
-            divisor,
-            true_destination: true_destination as usize,
-            false_destination: false_destination as usize,
+        let map_fn = |(divisor, (true_span, true_destination), (false_span, false_destination))| {
+            let test = Test {
+                divisor,
+                true_destination: true_destination as usize,
+                false_destination: false_destination as usize,
+            };
+            (test, [true_span, false_span])
         };
         map(lines, map_fn)(input)
     }
 
-    fn monkey(input: &str) -> IResult<&str, Monkey> {
-        let monkey_line = terminated(preceded(tag("Monkey "), u64), pair(char(':'), line_ending));
-        let lines = preceded(monkey_line, tuple((starting_items, operation, test)));
-        let map_fn = |(starting_items, operation, test)| Monkey {
-            held_items: starting_items,
-            operation,
-            test,
-            inspection_count: 0,
+    fn monkey(input: &str) -> IResult<&str, (Monkey, Spans<'_>)> {
+        let monkey_line = terminated(
+            preceded(tag("Monkey "), consumed(u64)),
+            pair(char(':'), line_ending),
+        );
+        // Once a monkey has started, errors are reported where they are
+        // rather than as the end of the list of monkeys.
+        let lines = pair(monkey_line, cut(tuple((starting_items, operation, test))));
+        let map_fn = |((id_span, _), (starting_items, operation, (test, destination_spans)))| {
+            let monkey = Monkey {
+                held_items: starting_items,
+                operation,
+                test,
+                inspection_count: 0,
+            };
+            (monkey, (id_span, destination_spans))
         };
         map(lines, map_fn)(input)
     }
 
-    fn monkeys(input: &str) -> IResult<&str, Monkeys> {
+    fn monkeys(input: &str) -> IResult<&str, Vec<(Monkey, Spans<'_>)>> {
         separated_list0(line_ending, monkey)(input)
     }
 }
 
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 11;
+
+    type Input<'a> = Monkeys;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parser::parse(input)?)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(&mut input.clone()).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(&mut input.clone()).into()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
 
+    const PART_ONE_CASES: [(&str, u64); 2] =
+        [(INPUT, 69918), (include_str!("../examples/1.txt"), 10605)];
+
+    const PART_TWO_CASES: [(&str, u64); 2] = [
+        (INPUT, 19_573_408_701),
+        (include_str!("../examples/1.txt"), 2_713_310_158),
+    ];
+
     #[test]
     fn test_part_one() {
-        let mut monkeys = parser::parse(INPUT).unwrap();
-        assert_eq!(part_one(&mut monkeys), 69918);
+        for (input, expected) in PART_ONE_CASES {
+            let mut monkeys = parser::parse(input).unwrap();
+            assert_eq!(part_one(&mut monkeys), expected);
+        }
     }
 
     #[test]
     fn test_part_two() {
+        for (input, expected) in PART_TWO_CASES {
+            let mut monkeys = parser::parse(input).unwrap();
+            assert_eq!(part_two(&mut monkeys), expected);
+        }
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = include_str!("../examples/1.txt").replacen("old * 19", "old ^ 19", 1);
+        let error = parser::parse(&input).unwrap_err();
+        assert_eq!((error.line(), error.column()), (3, 24));
+        let input =
+            include_str!("../examples/1.txt").replacen("throw to monkey 2", "throw to monkey 9", 1);
+        let error = parser::parse(&input).unwrap_err();
+        assert_eq!((error.line(), error.column()), (5, 30));
+        assert_eq!(error.message(), "there are only 4 monkeys");
+        let input = include_str!("../examples/1.txt").replacen("Monkey 1:", "Monkey 5:", 1);
+        let error = parser::parse(&input).unwrap_err();
+        assert_eq!((error.line(), error.column()), (8, 8));
+        assert_eq!(error.message(), "expected monkey 1");
+    }
+
+    #[test]
+    fn test_inspection_counts_after() {
         let mut monkeys = parser::parse(INPUT).unwrap();
-        assert_eq!(part_two(&mut monkeys), 19_573_408_701);
+        let modulus = worry_modulus(&monkeys);
+        let initial_monkeys = monkeys.clone();
+        for rounds in 0..=400 {
+            assert_eq!(
+                inspection_counts_after(&mut initial_monkeys.clone(), rounds),
+                get_inspection_counts(&monkeys)
+            );
+            round(&mut monkeys, |v| v % modulus);
+        }
     }
 }
@@ -1,43 +1,39 @@
-use std::collections::VecDeque;
+use aoc_common::{bfs_dense, Answer, Grid, ParseError, ParseResult, Position, Result, Solution};
 
 pub const INPUT: &str = include_str!("../input.txt");
 
 pub struct HeightMap {
-    width: usize,
-    height: usize,
-    heights: Vec<u8>,
-    initial_position: usize,
-    target_position: usize,
+    heights: Grid<u8>,
+    initial_position: Position,
+    target_position: Position,
 }
 
 impl HeightMap {
-    fn from_input(input: &str) -> Self {
-        let lines = input.lines().collect::<Vec<_>>();
-        let width = lines[0].len();
-        let height = lines.len();
-        let bytes = lines.into_iter().flat_map(str::bytes).collect::<Vec<_>>();
-        let initial_position = bytes.iter().position(|&b| b == b'S').unwrap();
-        let target_position = bytes.iter().position(|&b| b == b'E').unwrap();
-        let heights = bytes
-            .into_iter()
-            .map(|b| match b {
-                b'S' => 0,
-                b'E' => 25,
-                b if b.is_ascii_lowercase() => b - b'a',
-                _ => panic!("invalid height {b}"),
-            })
-            .collect::<Vec<_>>();
-        HeightMap {
-            width,
-            height,
+    fn from_input(input: &str) -> ParseResult<Self> {
+        let bytes = Grid::try_parse(input, |b| match b {
+            b'S' | b'E' | b'a'..=b'z' => Ok(b),
+            _ => Err(format!("invalid height {:?}", b as char)),
+        })?;
+        let initial_position = bytes
+            .position(|&b| b == b'S')
+            .ok_or_else(|| ParseError::at_end(input, "there is no starting position S"))?;
+        let target_position = bytes
+            .position(|&b| b == b'E')
+            .ok_or_else(|| ParseError::at_end(input, "there is no target position E"))?;
+        let heights = bytes.map(|&b| match b {
+            b'S' => 0,
+            b'E' => 25,
+            b => b - b'a',
+        });
+        Ok(HeightMap {
             heights,
             initial_position,
             target_position,
-        }
+        })
     }
 }
 
-pub fn parse_input(input: &str) -> HeightMap {
+pub fn parse_input(input: &str) -> ParseResult<HeightMap> {
     HeightMap::from_input(input)
 }
 
@@ -50,64 +46,97 @@ pub fn part_two(height_map: &HeightMap) -> usize {
         height_map
             .heights
             .iter()
-            .enumerate()
-            .filter_map(|(i, &h)| if h == 0 { Some(i) } else { None });
+            .filter_map(|(p, &h)| if h == 0 { Some(p) } else { None });
     find_shortest_path_len(height_map, starting_positions).unwrap()
 }
 
 fn find_shortest_path_len<I>(height_map: &HeightMap, starting_positions: I) -> Option<usize>
 where
-    I: IntoIterator<Item = usize>,
+    I: IntoIterator<Item = Position>,
 {
-    let mut queue = starting_positions
+    // Searching over indices into the cells rather than positions makes the
+    // search about a third faster. The neighbouring indices are worked out
+    // directly since mapping over `Grid::neighbours4` inside the search
+    // makes it over twice as slow.
+    let heights = &height_map.heights;
+    let cells = heights.cells();
+    let target_index = heights.index_of(height_map.target_position);
+    let starts = starting_positions.into_iter().map(|p| heights.index_of(p));
+    let neighbours = |index: usize| {
+        let max_height = cells[index] + 1;
+        let (x, y) = heights.position_of(index);
+        let width = heights.width();
+        [
+            (y > 0).then(|| index - width),
+            (x + 1 < width).then(|| index + 1),
+            (index + width < cells.len()).then(|| index + width),
+            (x > 0).then(|| index - 1),
+        ]
         .into_iter()
-        .map(|p| (p, 0))
-        .collect::<VecDeque<_>>();
-    let mut seen = vec![false; height_map.width * height_map.height];
-    for p in &queue {
-        seen[p.0] = true;
+        .flatten()
+        .filter(move |&i| cells[i] <= max_height)
+    };
+    bfs_dense(cells.len(), starts, neighbours, |index| {
+        index == target_index
+    })
+    .cost()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 12;
+
+    type Input<'a> = HeightMap;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse_input(input)?)
     }
-    while let Some((position, steps)) = queue.pop_front() {
-        if position == height_map.target_position {
-            return Some(steps);
-        }
-        let x = position % height_map.width;
-        let y = position / height_map.width;
-        let adjacent_positions = [
-            x.checked_sub(1).map(|x| x + y * height_map.width),
-            x.checked_add(1)
-                .filter(|&x| x < height_map.width)
-                .map(|x| x + y * height_map.width),
-            y.checked_sub(1).map(|y| x + y * height_map.width),
-            y.checked_add(1)
-                .filter(|&y| y < height_map.height)
-                .map(|y| x + y * height_map.width),
-        ];
-        for p in adjacent_positions {
-            if let Some(p) =
-                p.filter(|&p| !seen[p] && height_map.heights[p] <= height_map.heights[position] + 1)
-            {
-                seen[p] = true;
-                queue.push_back((p, steps + 1));
-            }
-        }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
     }
-    None
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
 
+    const PART_ONE_CASES: [(&str, usize); 2] =
+        [(INPUT, 472), (include_str!("../examples/1.txt"), 31)];
+
+    const PART_TWO_CASES: [(&str, usize); 2] =
+        [(INPUT, 465), (include_str!("../examples/1.txt"), 29)];
+
     #[test]
     fn test_part_one() {
-        let height_map = parse_input(INPUT);
-        assert_eq!(part_one(&height_map), 472);
+        for (input, expected) in PART_ONE_CASES {
+            let height_map = parse_input(input).unwrap();
+            assert_eq!(part_one(&height_map), expected);
+        }
     }
 
     #[test]
     fn test_part_two() {
-        let height_map = parse_input(INPUT);
-        assert_eq!(part_two(&height_map), 465);
+        for (input, expected) in PART_TWO_CASES {
+            let height_map = parse_input(input).unwrap();
+            assert_eq!(part_two(&height_map), expected);
+        }
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = parse_input("Sab\nc#E\n").err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 2: invalid height '#'\n  |\n2 | c#E\n  |  ^"
+        );
+        let error = parse_input("Sab\n").err().unwrap();
+        assert_eq!(error.message(), "there is no target position E");
     }
 }
@@ -1,3 +1,4 @@
+use aoc_common::{Answer, ParseError, ParseResult, Result, Solution};
 use std::cmp::Ordering;
 
 pub const INPUT: &str = include_str!("../input.txt");
@@ -95,18 +96,58 @@ impl<'a> Iterator for Values<'a> {
     }
 }
 
-pub fn parse_input(input: &str) -> Vec<Value> {
+pub fn parse_input(input: &str) -> ParseResult<Vec<Value<'_>>> {
     input
         .lines()
-        .filter_map(|line| {
-            if line.is_empty() {
-                return None;
-            }
-            Some(Value::new(line))
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            // Values are only read as they are compared, so they are checked
+            // up front to make sure that can't go wrong.
+            check_packet(input, line)?;
+            Ok(Value::new(line))
         })
         .collect()
 }
 
+fn check_packet(input: &str, line: &str) -> ParseResult<()> {
+    let error = |i, message| Err(ParseError::in_span(input, line, i, message));
+    let mut depth = 0usize;
+    // Whether the last byte ended a value, so a ',' or ']' can follow.
+    let mut after_value = false;
+    let mut number: Option<u32> = None;
+    for (i, b) in line.bytes().enumerate() {
+        match b {
+            b'0'..=b'9' if !after_value || number.is_some() => {
+                let digit = (b - b'0') as u32;
+                number = number
+                    .unwrap_or(0)
+                    .checked_mul(10)
+                    .and_then(|n| n.checked_add(digit));
+                if number.is_none() {
+                    return error(i, "the number is too large");
+                }
+                after_value = true;
+                continue;
+            }
+            b'[' if !after_value => depth += 1,
+            b']' if depth > 0 && (after_value || line.as_bytes()[i - 1] == b'[') => {
+                depth -= 1;
+                after_value = true;
+            }
+            b',' if depth > 0 && after_value => after_value = false,
+            _ if after_value && depth == 0 => return error(i, "expected the end of the packet"),
+            _ if after_value => return error(i, "expected ',' or ']'"),
+            _ => return error(i, "expected a number or a list"),
+        }
+        number = None;
+    }
+    match (after_value, depth) {
+        (true, 0) => Ok(()),
+        (true, _) => error(line.len(), "expected ',' or ']'"),
+        (false, _) => error(line.len(), "expected a number or a list"),
+    }
+}
+
 pub fn part_one(packets: &[Value]) -> usize {
     packets
         .chunks_exact(2)
@@ -129,19 +170,58 @@ pub fn part_two(packets: &[Value]) -> usize {
     indices.iter().product()
 }
 
+pub struct Day13;
+
+impl Solution for Day13 {
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 13;
+
+    type Input<'a> = Vec<Value<'a>>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
 
+    const PART_ONE_CASES: [(&str, usize); 2] =
+        [(INPUT, 6478), (include_str!("../examples/1.txt"), 13)];
+
+    const PART_TWO_CASES: [(&str, usize); 2] =
+        [(INPUT, 21_922), (include_str!("../examples/1.txt"), 140)];
+
     #[test]
     fn test_part_one() {
-        let packets = parse_input(INPUT);
-        assert_eq!(part_one(&packets), 6478);
+        for (input, expected) in PART_ONE_CASES {
+            let packets = parse_input(input).unwrap();
+            assert_eq!(part_one(&packets), expected);
+        }
     }
 
     #[test]
     fn test_part_two() {
-        let packets = parse_input(INPUT);
-        assert_eq!(part_two(&packets), 21_922);
+        for (input, expected) in PART_TWO_CASES {
+            let packets = parse_input(input).unwrap();
+            assert_eq!(part_two(&packets), expected);
+        }
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = parse_input("[1,[2]]\n[1,[2,]]\n").unwrap_err();
+        assert_eq!((error.line(), error.column()), (2, 7));
+        let error = parse_input("[1]]\n").unwrap_err();
+        assert_eq!(error.message(), "expected the end of the packet");
     }
 }
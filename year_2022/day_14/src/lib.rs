@@ -1,4 +1,4 @@
-use rustc_hash::{FxHashMap, FxHashSet};
+use aoc_common::{parse_str, Answer, Grid, ParseError, ParseResult, Position, Result, Solution};
 use std::cmp;
 use std::fmt::Display;
 use std::ops::RangeInclusive;
@@ -9,53 +9,101 @@ const SPAWN_POINT: Point = (500, 0);
 
 type Point = (usize, usize);
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Material {
+    Air,
     Rock,
     Sand,
 }
 
+/// The cave, stored densely. The grid is wide enough for the sand pile of
+/// part two, which spreads at most one column per row on either side of the
+/// spawn point, and `x_offset` maps puzzle x coordinates onto grid columns.
 #[derive(Debug, Clone)]
 pub struct World {
-    map: FxHashMap<Point, Material>,
+    map: Grid<Material>,
+    x_offset: usize,
     rock_bounds: (RangeInclusive<usize>, RangeInclusive<usize>),
 }
 
 impl World {
-    fn from_input(input: &str) -> Self {
-        let mut map = FxHashMap::default();
-        let mut points = vec![];
+    fn from_input(input: &str) -> ParseResult<Self> {
+        let mut paths = vec![];
+        let (spawn_x, spawn_y) = SPAWN_POINT;
+        let (mut min_x, mut max_x) = (spawn_x, spawn_x);
+        let (mut min_y, mut max_y) = (spawn_y, spawn_y);
         for line in input.lines() {
-            points.clear();
-            points.extend(line.split(" -> ").map(|point_str| {
-                let (left, right) = point_str.split_once(',').unwrap();
-                let x: usize = left.parse().unwrap();
-                let y: usize = right.parse().unwrap();
-                (x, y)
-            }));
+            let mut previous: Option<Point> = None;
+            let points = line
+                .split(" -> ")
+                .map(|point_str| {
+                    let (left, right) = point_str.split_once(',').ok_or_else(|| {
+                        ParseError::new(input, point_str, "expected a point like 498,4")
+                    })?;
+                    let x: usize = parse_str(input, left)?;
+                    let y: usize = parse_str(input, right)?;
+                    if previous.is_some_and(|(px, py)| px != x && py != y) {
+                        let message = "paths must be horizontal or vertical";
+                        return Err(ParseError::new(input, point_str, message));
+                    }
+                    previous = Some((x, y));
+                    min_x = cmp::min(min_x, x);
+                    max_x = cmp::max(max_x, x);
+                    min_y = cmp::min(min_y, y);
+                    max_y = cmp::max(max_y, y);
+                    Ok((x, y))
+                })
+                .collect::<ParseResult<Vec<_>>>()?;
+            paths.push(points);
+        }
+        let floor_y = max_y + 2;
+        let x_offset = cmp::min(min_x, spawn_x - floor_y);
+        let width = cmp::max(max_x, spawn_x + floor_y) - x_offset + 1;
+        let mut map = Grid::new(width, floor_y + 1, Material::Air);
+        for points in &paths {
             for (&(left_x, left_y), &(right_x, right_y)) in points.iter().zip(points.iter().skip(1))
             {
                 if left_x == right_x {
                     let start = cmp::min(left_y, right_y);
                     let end = cmp::max(left_y, right_y);
                     for y in start..=end {
-                        map.insert((left_x, y), Material::Rock);
+                        map[(left_x - x_offset, y)] = Material::Rock;
                     }
                 } else {
                     let start = cmp::min(left_x, right_x);
                     let end = cmp::max(left_x, right_x);
                     for x in start..=end {
-                        map.insert((x, left_y), Material::Rock);
+                        map[(x - x_offset, left_y)] = Material::Rock;
                     }
                 }
             }
         }
-        let rock_bounds = find_map_bounds(&map);
-        World { map, rock_bounds }
+        let rock_bounds = (min_x - x_offset..=max_x - x_offset, min_y..=max_y);
+        Ok(World {
+            map,
+            x_offset,
+            rock_bounds,
+        })
+    }
+
+    fn spawn_position(&self) -> Position {
+        let (x, y) = SPAWN_POINT;
+        (x - self.x_offset, y)
     }
 
     fn find_sand_bounds(&self) -> (RangeInclusive<usize>, RangeInclusive<usize>) {
-        find_map_bounds(&self.map)
+        let (spawn_x, spawn_y) = self.spawn_position();
+        let (mut min_x, mut max_x) = (spawn_x, spawn_x);
+        let (mut min_y, mut max_y) = (spawn_y, spawn_y);
+        for ((x, y), &material) in self.map.iter() {
+            if material != Material::Air {
+                min_x = cmp::min(min_x, x);
+                max_x = cmp::max(max_x, x);
+                min_y = cmp::min(min_y, y);
+                max_y = cmp::max(max_y, y);
+            }
+        }
+        (min_x..=max_x, min_y..=max_y)
     }
 }
 
@@ -64,11 +112,11 @@ impl Display for World {
         let (x_bounds, y_bounds) = self.find_sand_bounds();
         for y in y_bounds {
             for x in x_bounds.clone() {
-                let c = match self.map.get(&(x, y)) {
-                    Some(Material::Rock) => '#',
-                    Some(Material::Sand) => 'o',
-                    None if (x, y) == SPAWN_POINT => '+',
-                    None => '.',
+                let c = match self.map[(x, y)] {
+                    Material::Rock => '#',
+                    Material::Sand => 'o',
+                    Material::Air if (x, y) == self.spawn_position() => '+',
+                    Material::Air => '.',
                 };
                 write!(f, "{c}")?;
             }
@@ -78,16 +126,17 @@ impl Display for World {
     }
 }
 
-pub fn parse_input(input: &str) -> World {
+pub fn parse_input(input: &str) -> ParseResult<World> {
     World::from_input(input)
 }
 
 pub fn part_one(world: &mut World) -> usize {
     let (x_bounds, y_bounds) = &world.rock_bounds;
+    let spawn_position = world.spawn_position();
     let mut settled_sand_unit_count = 0;
-    let mut stack = vec![SPAWN_POINT];
-    let mut seen = FxHashSet::default();
-    seen.insert(SPAWN_POINT);
+    let mut stack = vec![spawn_position];
+    let mut seen = Grid::new(world.map.width(), world.map.height(), false);
+    seen[spawn_position] = true;
     while let Some(point @ (x, y)) = stack.pop() {
         let next_points = [(x, y + 1), (x - 1, y + 1), (x + 1, y + 1)];
         if next_points
@@ -96,16 +145,16 @@ pub fn part_one(world: &mut World) -> usize {
         {
             break;
         }
-        if next_points.iter().all(|p| world.map.contains_key(p)) {
+        if next_points.iter().all(|&p| world.map[p] != Material::Air) {
             settled_sand_unit_count += 1;
-            world.map.insert(point, Material::Sand);
+            world.map[point] = Material::Sand;
             continue;
         }
         stack.push(point);
         for point in next_points.into_iter().rev() {
-            if !world.map.contains_key(&point) && !seen.contains(&point) {
+            if world.map[point] == Material::Air && !seen[point] {
                 stack.push(point);
-                seen.insert(point);
+                seen[point] = true;
             }
         }
     }
@@ -114,49 +163,78 @@ pub fn part_one(world: &mut World) -> usize {
 
 pub fn part_two(world: &mut World) -> usize {
     let (_, y_bounds) = &world.rock_bounds;
+    let floor_y = *y_bounds.end() + 2;
+    let spawn_position = world.spawn_position();
     let mut settled_sand_unit_count = 0;
-    let mut stack = vec![SPAWN_POINT];
-    let mut seen = FxHashSet::default();
-    seen.insert(SPAWN_POINT);
+    let mut stack = vec![spawn_position];
+    let mut seen = Grid::new(world.map.width(), world.map.height(), false);
+    seen[spawn_position] = true;
     while let Some(point @ (x, y)) = stack.pop() {
         settled_sand_unit_count += 1;
-        world.map.insert(point, Material::Sand);
+        world.map[point] = Material::Sand;
         let next_points = [(x, y + 1), (x - 1, y + 1), (x + 1, y + 1)];
         for next_point @ (_x, y) in next_points {
-            if y != *y_bounds.end() + 2
-                && !world.map.contains_key(&next_point)
-                && !seen.contains(&next_point)
-            {
+            if y != floor_y && world.map[next_point] == Material::Air && !seen[next_point] {
                 stack.push(next_point);
-                seen.insert(next_point);
+                seen[next_point] = true;
             }
         }
     }
     settled_sand_unit_count
 }
 
-fn find_map_bounds<T>(map: &FxHashMap<Point, T>) -> (RangeInclusive<usize>, RangeInclusive<usize>) {
-    let (spawn_x, spawn_y) = SPAWN_POINT;
-    let min_x = cmp::min(spawn_x, *map.keys().map(|(x, _)| x).min().unwrap());
-    let max_x = cmp::max(spawn_x, *map.keys().map(|(x, _)| x).max().unwrap());
-    let min_y = cmp::min(spawn_y, *map.keys().map(|(_, y)| y).min().unwrap());
-    let max_y = cmp::max(spawn_y, *map.keys().map(|(_, y)| y).max().unwrap());
-    (min_x..=max_x, min_y..=max_y)
+pub struct Day14;
+
+impl Solution for Day14 {
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 14;
+
+    type Input<'a> = World;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(&mut input.clone()).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(&mut input.clone()).into()
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
 
+    const PART_ONE_CASES: [(&str, usize); 2] =
+        [(INPUT, 683), (include_str!("../examples/1.txt"), 24)];
+
+    const PART_TWO_CASES: [(&str, usize); 2] =
+        [(INPUT, 28_821), (include_str!("../examples/1.txt"), 93)];
+
     #[test]
     fn test_part_one() {
-        let mut world = parse_input(INPUT);
-        assert_eq!(part_one(&mut world), 683);
+        for (input, expected) in PART_ONE_CASES {
+            let mut world = parse_input(input).unwrap();
+            assert_eq!(part_one(&mut world), expected);
+        }
     }
 
     #[test]
     fn test_part_two() {
-        let mut world = parse_input(INPUT);
-        assert_eq!(part_two(&mut world), 28_821);
+        for (input, expected) in PART_TWO_CASES {
+            let mut world = parse_input(input).unwrap();
+            assert_eq!(part_two(&mut world), expected);
+        }
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = parse_input("498,4 -> 498,6 -> 496,6\n503,4 -> 502,x\n").unwrap_err();
+        assert_eq!((error.line(), error.column()), (2, 14));
+        let error = parse_input("498,4 -> 496,6\n").unwrap_err();
+        assert_eq!(error.message(), "paths must be horizontal or vertical");
     }
 }
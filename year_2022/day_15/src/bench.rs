@@ -1,5 +1,5 @@
-use year_2022_day_15::INPUT;
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use year_2022_day_15::INPUT;
 
 fn criterion_benchmark(c: &mut Criterion) {
     let sensors = year_2022_day_15::parser::parse(INPUT).unwrap();
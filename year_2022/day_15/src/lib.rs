@@ -1,51 +1,45 @@
+use aoc_common::{Answer, IntervalSet, Point2, Result, Solution};
 use rustc_hash::FxHashSet;
-use std::{cmp, ops::RangeInclusive};
+use std::ops::RangeInclusive;
 
 pub const INPUT: &str = include_str!("../input.txt");
 
-type Coordinate = (i32, i32);
-
 #[derive(Debug)]
 pub struct Sensor {
-    position: Coordinate,
-    nearest_beacon_distance: i32,
+    position: Point2,
+    nearest_beacon_position: Point2,
+    nearest_beacon_distance: i64,
 }
 
-pub fn part_one(sensors: &[Sensor]) -> i32 {
-    const ROW: i32 = 2_000_000;
-    let mut ranges = sensors
+pub fn part_one(sensors: &[Sensor]) -> i64 {
+    const ROW: i64 = 2_000_000;
+    let covered = sensors
         .iter()
         .filter_map(|sensor| {
-            let (x, y) = sensor.position;
+            let Point2 { x, y } = sensor.position;
             let max_distance = sensor.nearest_beacon_distance;
             let dy = (y - ROW).abs();
             if dy > max_distance {
                 return None;
             }
             let max_dx = (max_distance - dy).abs();
-            Some(x - max_dx..=x + max_dx)
+            Some(x - max_dx..x + max_dx + 1)
         })
-        .collect::<Vec<_>>();
-    ranges.sort_by_key(|r| *r.start());
-    let mut iter = ranges.into_iter();
-    let mut merged_ranges = vec![iter.next().unwrap()];
-    for range in iter {
-        let last_range = merged_ranges.last_mut().unwrap();
-        if range.start() <= last_range.end() {
-            *last_range = *last_range.start()..=cmp::max(*last_range.end(), *range.end());
-        } else {
-            merged_ranges.push(range);
-        }
-    }
-    merged_ranges.into_iter().map(|r| r.end() - r.start()).sum()
+        .collect::<IntervalSet<_>>();
+    let beacons = sensors
+        .iter()
+        .map(|sensor| sensor.nearest_beacon_position)
+        .filter(|beacon| beacon.y == ROW)
+        .collect::<FxHashSet<_>>();
+    covered.len() - beacons.len() as i64
 }
 
 pub fn part_two(sensors: &[Sensor]) -> usize {
-    const BOUNDS: RangeInclusive<i32> = 0..=4_000_000;
+    const BOUNDS: RangeInclusive<i64> = 0..=4_000_000;
     let mut ascending_line_coefficients = FxHashSet::default();
     let mut descending_line_coefficients = FxHashSet::default();
     for sensor in sensors {
-        let (x, y) = sensor.position;
+        let Point2 { x, y } = sensor.position;
         let radius = sensor.nearest_beacon_distance;
         ascending_line_coefficients.extend([y - x + radius + 1, y - x - radius - 1]);
         descending_line_coefficients.extend([x + y + radius + 1, x + y - radius - 1]);
@@ -59,7 +53,8 @@ pub fn part_two(sensors: &[Sensor]) -> usize {
                 if BOUNDS.contains(&x)
                     && BOUNDS.contains(&y)
                     && sensors.iter().all(|sensor| {
-                        manhattan_distance(sensor.position, (x, y)) > sensor.nearest_beacon_distance
+                        sensor.position.manhattan_distance(Point2::new(x, y))
+                            > sensor.nearest_beacon_distance as u64
                     })
                 {
                     return Some(x as usize * 4_000_000 + y as usize);
@@ -70,47 +65,44 @@ pub fn part_two(sensors: &[Sensor]) -> usize {
         .unwrap()
 }
 
-fn manhattan_distance((left_x, left_y): Coordinate, (right_x, right_y): Coordinate) -> i32 {
-    (left_x - right_x).abs() + (left_y - right_y).abs()
-}
-
 pub mod parser {
     use super::*;
-    use anyhow::{anyhow, Result};
+    use aoc_common::{ParseError, ParseResult};
     use nom::{
         bytes::complete::tag,
-        character::complete::{i32, line_ending},
-        combinator::map,
+        character::complete::{i64, line_ending},
+        combinator::{cut, map},
         multi::many0,
         sequence::{preceded, separated_pair, terminated},
         Finish, IResult,
     };
 
-    pub fn parse(input: &str) -> Result<Vec<Sensor>> {
+    pub fn parse(input: &str) -> ParseResult<Vec<Sensor>> {
         let (rest, sensors) = sensors(input)
             .finish()
-            .map_err(|err| anyhow!(err.to_string()))?;
+            .map_err(|err| ParseError::from_nom(input, err))?;
         if !rest.is_empty() {
-            return Err(anyhow!("Unparsed input: {}", rest));
+            return Err(ParseError::new(input, rest, "expected a sensor"));
         }
         Ok(sensors)
     }
 
-    fn coordinate(input: &str) -> IResult<&str, Coordinate> {
-        separated_pair(preceded(tag("x="), i32), tag(", y="), i32)(input)
+    fn coordinate(input: &str) -> IResult<&str, Point2> {
+        let coordinates = separated_pair(preceded(tag("x="), i64), tag(", y="), i64);
+        map(coordinates, Point2::from)(input)
     }
 
     fn sensor(input: &str) -> IResult<&str, Sensor> {
-        let positions = separated_pair(
-            preceded(tag("Sensor at "), coordinate),
-            tag(": closest beacon is at "),
-            coordinate,
-        );
-        let line = terminated(positions, line_ending);
-        let map_fn = |(position, nearest_beacon_position)| {
-            let nearest_beacon_distance = manhattan_distance(position, nearest_beacon_position);
+        let positions = separated_pair(coordinate, tag(": closest beacon is at "), coordinate);
+        // Once a sensor has started, errors are reported where they are
+        // rather than as the end of the list of sensors.
+        let line = preceded(tag("Sensor at "), cut(terminated(positions, line_ending)));
+        let map_fn = |(position, nearest_beacon_position): (Point2, Point2)| {
+            let nearest_beacon_distance =
+                position.manhattan_distance(nearest_beacon_position) as i64;
             Sensor {
                 position,
+                nearest_beacon_position,
                 nearest_beacon_distance,
             }
         };
@@ -122,6 +114,27 @@ pub mod parser {
     }
 }
 
+pub struct Day15;
+
+impl Solution for Day15 {
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 15;
+
+    type Input<'a> = Vec<Sensor>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parser::parse(input)?)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
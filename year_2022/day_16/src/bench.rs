@@ -18,11 +18,15 @@ fn criterion_benchmark(c: &mut Criterion) {
     });
 
     c.bench_function("year_2022_day_16::part_one", |b| {
-        b.iter(|| year_2022_day_16::part_one(black_box(&processed_valves), black_box(&initial_distances)));
+        b.iter(|| {
+            year_2022_day_16::part_one(black_box(&processed_valves), black_box(&initial_distances))
+        });
     });
 
     c.bench_function("year_2022_day_16::part_two", |b| {
-        b.iter(|| year_2022_day_16::part_two(black_box(&processed_valves), black_box(&initial_distances)));
+        b.iter(|| {
+            year_2022_day_16::part_two(black_box(&processed_valves), black_box(&initial_distances))
+        });
     });
 }
 
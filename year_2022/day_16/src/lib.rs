@@ -1,10 +1,14 @@
+use aoc_common::{AllPairs, Answer, Result, Solution};
 use bitvec::prelude::*;
 use rustc_hash::FxHashMap as HashMap;
-use std::cmp::{self, Reverse};
+use std::{
+    cell::OnceCell,
+    cmp::{self, Reverse},
+};
 
 pub const INPUT: &str = include_str!("../input.txt");
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Valve {
     id: String,
     flow_rate: u32,
@@ -32,18 +36,25 @@ pub fn preprocess(mut valves: Vec<Valve>) -> (Vec<ProcessedValve>, Vec<u32>) {
         .enumerate()
         .collect::<Vec<_>>();
     let valves_with_flow_count = valves_with_flow.len();
+    // Unreachable valves are treated as infinitely far away
+    let distances_from = |i| {
+        distances.distances_from(i)[..valves_with_flow_count]
+            .iter()
+            .map(|distance| distance.unwrap_or(u32::MAX))
+            .collect::<Vec<_>>()
+    };
 
     // Distances from the starting point to each valve
     let initial_distances = valves
         .iter()
         .position(|valve| valve.id == "AA")
-        .map(|i| distances[i][..valves_with_flow_count].to_vec())
+        .map(distances_from)
         .unwrap();
 
     let processed_valves = valves_with_flow
         .into_iter()
         .map(|(i, flow_rate)| {
-            let distances = distances[i][..valves_with_flow_count].to_vec();
+            let distances = distances_from(i);
             ProcessedValve {
                 flow_rate,
                 distances,
@@ -80,33 +91,20 @@ pub fn part_two(valves: &[ProcessedValve], initial_distances: &[u32]) -> u32 {
         .unwrap()
 }
 
-// Floyd-Warshall algorithm
-// https://en.wikipedia.org/wiki/Floyd%E2%80%93Warshall_algorithm
-fn find_all_pairs_shortest_paths(valves: &[Valve]) -> Vec<Vec<u32>> {
+fn find_all_pairs_shortest_paths(valves: &[Valve]) -> AllPairs<u32> {
     let id_to_index = valves
         .iter()
         .enumerate()
         .map(|(i, valve)| (&valve.id, i))
         .collect::<HashMap<_, _>>();
-    let mut distances = vec![vec![u32::MAX; valves.len()]; valves.len()];
-    for (i, valve) in valves.iter().enumerate() {
-        distances[i][i] = 0;
-        for tunnel_id in &valve.tunnel_ids {
-            let j = id_to_index[tunnel_id];
-            distances[i][j] = 1;
-        }
-    }
-    for k in 0..valves.len() {
-        for i in 0..valves.len() {
-            for j in 0..valves.len() {
-                distances[i][j] = cmp::min(
-                    distances[i][j],
-                    distances[i][k].saturating_add(distances[k][j]),
-                );
-            }
-        }
-    }
-    distances
+    let tunnels = valves.iter().enumerate().flat_map(|(i, valve)| {
+        let id_to_index = &id_to_index;
+        valve
+            .tunnel_ids
+            .iter()
+            .map(move |tunnel_id| (i, id_to_index[tunnel_id], 1))
+    });
+    AllPairs::new(valves.len(), tunnels)
 }
 
 /// Iterator over possible solutions to opening valves
@@ -211,23 +209,49 @@ fn calculate_remaining_pressure_upper_bound(valves: &[ProcessedValve], state: &S
 
 pub mod parser {
     use super::Valve;
-    use anyhow::{anyhow, Result};
+    use aoc_common::{ParseError, ParseResult};
     use nom::{
         branch::alt,
         bytes::complete::{tag, take},
         character::complete::{line_ending, u32},
-        combinator::map,
+        combinator::{cut, map},
         multi::{many1, separated_list1},
-        sequence::{preceded, terminated, Tuple},
+        sequence::{pair, preceded, terminated, Tuple},
         Finish, IResult,
     };
+    use rustc_hash::FxHashSet;
 
-    pub fn parse(input: &str) -> Result<Vec<Valve>> {
+    pub fn parse(input: &str) -> ParseResult<Vec<Valve>> {
         let (rest, valves) = valves(input)
             .finish()
-            .map_err(|err| anyhow!(err.to_string()))?;
+            .map_err(|err| ParseError::from_nom(input, err))?;
         if !rest.is_empty() {
-            return Err(anyhow!("Unparsed input: {}", rest));
+            return Err(ParseError::new(input, rest, "expected a valve"));
+        }
+        // Each valve is on its own line, so the lines point at the valves
+        // with tunnels to nowhere.
+        let ids = valves
+            .iter()
+            .map(|valve| valve.id.as_str())
+            .collect::<FxHashSet<_>>();
+        for (valve, line) in valves.iter().zip(input.lines()) {
+            if let Some(id) = valve
+                .tunnel_ids
+                .iter()
+                .find(|id| !ids.contains(id.as_str()))
+            {
+                return Err(ParseError::new(
+                    input,
+                    line,
+                    format!("there is no valve {id}"),
+                ));
+            }
+        }
+        if !ids.contains("AA") {
+            return Err(ParseError::at_end(
+                input,
+                "there is no valve AA to start at",
+            ));
         }
         Ok(valves)
     }
@@ -245,7 +269,10 @@ pub mod parser {
             separated_list1(tag(", "), valve_id),
         );
         let tunnel_ids = alt((single_tunnel_id, multiple_tunnel_ids));
-        let (input, (id, flow_rate, tunnel_ids)) = (id, flow_rate, tunnel_ids).parse(input)?;
+        // Once a valve has started, errors are reported where they are
+        // rather than as the end of the list of valves.
+        let rest = cut(pair(flow_rate, tunnel_ids));
+        let (input, (id, (flow_rate, tunnel_ids))) = (id, rest).parse(input)?;
         let valve = Valve {
             id,
             flow_rate,
@@ -283,21 +310,93 @@ pub mod parser {
     }
 }
 
+pub struct Input {
+    valves: Vec<Valve>,
+    preprocessed: OnceCell<(Vec<ProcessedValve>, Vec<u32>)>,
+}
+
+impl Input {
+    /// The valves with a flow rate and the distances to them from AA, worked
+    /// out by `preprocess` or else on first use.
+    fn preprocessed(&self) -> (&[ProcessedValve], &[u32]) {
+        let (valves, initial_distances) = self
+            .preprocessed
+            .get_or_init(|| preprocess(self.valves.clone()));
+        (valves, initial_distances)
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 16;
+
+    type Input<'a> = Input;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        let valves = parser::parse(input)?;
+        Ok(Input {
+            valves,
+            preprocessed: OnceCell::new(),
+        })
+    }
+
+    fn preprocess(input: &mut Self::Input<'_>) {
+        input.preprocessed();
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        let (valves, initial_distances) = input.preprocessed();
+        part_one(valves, initial_distances).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        let (valves, initial_distances) = input.preprocessed();
+        part_two(valves, initial_distances).into()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
 
+    const PART_ONE_CASES: [(&str, u32); 2] =
+        [(INPUT, 2320), (include_str!("../examples/1.txt"), 1651)];
+
+    // The example is left out because part two assumes that neither you nor
+    // the elephant can open every valve alone, which only holds for real
+    // inputs.
+    const PART_TWO_CASES: [(&str, u32); 1] = [(INPUT, 2967)];
+
     #[test]
     fn test_part_one() {
-        let valves = parser::parse(INPUT).unwrap();
-        let (processed_valves, initial_distances) = preprocess(valves);
-        assert_eq!(part_one(&processed_valves, &initial_distances), 2320);
+        for (input, expected) in PART_ONE_CASES {
+            let valves = parser::parse(input).unwrap();
+            let (processed_valves, initial_distances) = preprocess(valves);
+            assert_eq!(part_one(&processed_valves, &initial_distances), expected);
+        }
     }
 
     #[test]
     fn test_part_two() {
-        let valves = parser::parse(INPUT).unwrap();
-        let (processed_valves, initial_distances) = preprocess(valves);
-        assert_eq!(part_two(&processed_valves, &initial_distances), 2967);
+        for (input, expected) in PART_TWO_CASES {
+            let valves = parser::parse(input).unwrap();
+            let (processed_valves, initial_distances) = preprocess(valves);
+            assert_eq!(part_two(&processed_valves, &initial_distances), expected);
+        }
+    }
+
+    #[test]
+    fn test_without_preprocess() {
+        let input = Day16::parse(include_str!("../examples/1.txt")).unwrap();
+        assert_eq!(Day16::part_one(&input), Answer::from(1651));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = include_str!("../examples/1.txt").replacen("valves DD", "valves XX", 1);
+        let error = parser::parse(&input).unwrap_err();
+        assert_eq!((error.line(), error.message()), (1, "there is no valve XX"));
     }
 }
@@ -1,14 +1,8 @@
-use rustc_hash::FxHashMap as HashMap;
-use std::{
-    array, cmp,
-    iter::{Cycle, Enumerate, Peekable},
-    slice,
-};
+use aoc_common::{find_cycle, Answer, BitGrid, ParseError, ParseResult, Point2, Result, Solution};
+use std::cmp;
 
 pub const INPUT: &str = include_str!("../input.txt");
 
-type Point = (usize, usize);
-
 #[derive(Debug)]
 pub enum Motion {
     Left,
@@ -25,17 +19,18 @@ enum Shape {
 }
 
 impl Shape {
-    fn points(&self) -> Box<[Point]> {
+    /// The offsets of the rock's cells from its bottom left corner.
+    fn points(&self) -> &'static [(i64, i64)] {
         match self {
-            Shape::HorizontalLine => Box::new([(0, 0), (1, 0), (2, 0), (3, 0)]),
-            Shape::Plus => Box::new([(0, 1), (1, 0), (1, 1), (1, 2), (2, 1)]),
-            Shape::ReverseL => Box::new([(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)]),
-            Shape::VerticalLine => Box::new([(0, 0), (0, 1), (0, 2), (0, 3)]),
-            Shape::Square => Box::new([(0, 0), (0, 1), (1, 0), (1, 1)]),
+            Shape::HorizontalLine => &[(0, 0), (1, 0), (2, 0), (3, 0)],
+            Shape::Plus => &[(0, 1), (1, 0), (1, 1), (1, 2), (2, 1)],
+            Shape::ReverseL => &[(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)],
+            Shape::VerticalLine => &[(0, 0), (0, 1), (0, 2), (0, 3)],
+            Shape::Square => &[(0, 0), (0, 1), (1, 0), (1, 1)],
         }
     }
 
-    fn width(&self) -> u8 {
+    fn width(&self) -> i64 {
         match self {
             Shape::HorizontalLine => 4,
             Shape::Plus => 3,
@@ -45,7 +40,7 @@ impl Shape {
         }
     }
 
-    fn height(&self) -> u8 {
+    fn height(&self) -> i64 {
         match self {
             Shape::HorizontalLine => 1,
             Shape::Plus => 3,
@@ -56,98 +51,97 @@ impl Shape {
     }
 }
 
-const COLUMNS: usize = 7;
+const COLUMNS: i64 = 7;
+const SHAPES: [Shape; 5] = [
+    Shape::HorizontalLine,
+    Shape::Plus,
+    Shape::ReverseL,
+    Shape::VerticalLine,
+    Shape::Square,
+];
+
 struct Simulation<'a> {
-    columns: [Vec<bool>; COLUMNS],
-    max_height: usize,
-    shapes_iter: Peekable<Cycle<array::IntoIter<Shape, 5>>>,
-    motions_iter: Peekable<Cycle<Enumerate<slice::Iter<'a, Motion>>>>,
+    rocks: BitGrid,
+    max_height: i64,
+    motions: &'a [Motion],
+    next_shape_index: usize,
+    next_motion_index: usize,
 }
 
 impl<'a> Simulation<'a> {
     fn new(motions: &'a [Motion]) -> Self {
-        let shapes = [
-            Shape::HorizontalLine,
-            Shape::Plus,
-            Shape::ReverseL,
-            Shape::VerticalLine,
-            Shape::Square,
-        ];
         Self {
-            columns: Default::default(),
+            rocks: BitGrid::new(),
             max_height: 0,
-            shapes_iter: shapes.into_iter().cycle().peekable(),
-            motions_iter: motions.iter().enumerate().cycle().peekable(),
+            motions,
+            next_shape_index: 0,
+            next_motion_index: 0,
         }
     }
 
-    fn peek_next_shape(&mut self) -> Shape {
-        self.shapes_iter.peek().copied().unwrap()
-    }
-
-    fn peek_next_motion_index(&mut self) -> usize {
-        self.motions_iter.peek().map(|(index, _)| *index).unwrap()
-    }
-
-    fn offsets(&self) -> Vec<usize> {
-        self.columns
-            .iter()
-            .map(|column| self.max_height - column.iter().rposition(|&v| v).unwrap_or(0))
+    fn offsets(&self) -> Vec<i64> {
+        (0..COLUMNS)
+            .map(|x| {
+                let top = (0..self.max_height)
+                    .rev()
+                    .find(|&y| self.rocks.contains(Point2::new(x, y)))
+                    .unwrap_or(0);
+                self.max_height - top
+            })
             .collect::<Vec<_>>()
     }
 
     fn drop_next_rock(&mut self) {
-        let shape = self.shapes_iter.next().unwrap();
-        let min_len = self.max_height + 3 + shape.height() as usize;
-        if self.columns[0].len() < min_len {
-            for column in self.columns.iter_mut() {
-                column.resize(min_len, false);
-            }
-        }
-        let (mut x, mut y) = (2usize, self.max_height + 3);
+        let shape = SHAPES[self.next_shape_index];
+        self.next_shape_index = (self.next_shape_index + 1) % SHAPES.len();
+        let mut position = Point2::new(2, self.max_height + 3);
         loop {
-            let (_, motion) = self.motions_iter.next().unwrap();
+            let motion = &self.motions[self.next_motion_index];
+            self.next_motion_index = (self.next_motion_index + 1) % self.motions.len();
             let next_x = match motion {
-                Motion::Left => x.saturating_sub(1),
-                Motion::Right => cmp::min(x + 1, COLUMNS - shape.width() as usize),
+                Motion::Left => cmp::max(position.x - 1, 0),
+                Motion::Right => cmp::min(position.x + 1, COLUMNS - shape.width()),
             };
-            let blocked = shape
-                .points()
-                .iter()
-                .any(|(x_offset, y_offset)| self.columns[next_x + x_offset][y + y_offset]);
-            if !blocked {
-                x = next_x;
+            let next_position = Point2::new(next_x, position.y);
+            if !self.collides(shape, next_position) {
+                position = next_position;
             }
-            if y == 0 {
+            if position.y == 0 {
                 break;
             }
-            let next_y = y - 1;
-            let blocked = shape
-                .points()
-                .iter()
-                .any(|(x_offset, y_offset)| self.columns[x + x_offset][next_y + y_offset]);
-            if blocked {
+            let next_position = Point2::new(position.x, position.y - 1);
+            if self.collides(shape, next_position) {
                 break;
             }
-            y = next_y;
+            position = next_position;
         }
-        for (x_offset, y_offset) in shape.points().iter() {
-            self.columns[x + x_offset][y + y_offset] = true;
+        for &offset in shape.points() {
+            self.rocks.insert(position + Point2::from(offset));
         }
-        self.max_height = cmp::max(self.max_height, y + shape.height() as usize);
+        self.max_height = cmp::max(self.max_height, position.y + shape.height());
+    }
+
+    fn collides(&self, shape: Shape, position: Point2) -> bool {
+        shape
+            .points()
+            .iter()
+            .any(|&offset| self.rocks.contains(position + Point2::from(offset)))
     }
 }
 
-pub fn parse_input(input: &str) -> Vec<Motion> {
-    input
-        .trim()
-        .bytes()
-        .map(|b| match b {
-            b'<' => Motion::Left,
-            b'>' => Motion::Right,
-            _ => panic!("Unknown motion"),
+pub fn parse_input(input: &str) -> ParseResult<Vec<Motion>> {
+    let jets = input.trim();
+    if jets.is_empty() {
+        return Err(ParseError::at_end(input, "expected a jet pattern"));
+    }
+    jets.bytes()
+        .enumerate()
+        .map(|(i, b)| match b {
+            b'<' => Ok(Motion::Left),
+            b'>' => Ok(Motion::Right),
+            _ => Err(ParseError::in_span(input, jets, i, "expected < or >")),
         })
-        .collect::<Vec<_>>()
+        .collect()
 }
 
 pub fn part_one(motions: &[Motion]) -> u64 {
@@ -159,48 +153,93 @@ pub fn part_one(motions: &[Motion]) -> u64 {
 }
 
 pub fn part_two(motions: &[Motion]) -> u64 {
-    const ROCK_COUNT: usize = 1_000_000_000_000;
+    tower_height(motions, 1_000_000_000_000)
+}
+
+/// The height of the tower after `rock_count` rocks, found by extrapolating
+/// from the first time the falling rock, the jet and the shape of the top of
+/// the tower repeat.
+fn tower_height(motions: &[Motion], rock_count: usize) -> u64 {
     let mut simulation = Simulation::new(motions);
-    let mut max_heights = vec![];
-    let mut seen = HashMap::default();
-    for i in 0..ROCK_COUNT {
-        max_heights.push(simulation.max_height);
-        let shape = simulation.peek_next_shape();
-        let motion_index = simulation.peek_next_motion_index();
-        let offsets = simulation.offsets();
-        let key = (shape, motion_index, offsets);
-        if let Some(&cycle_start) = seen.get(&key) {
-            let cycle_end_height = simulation.max_height as u64;
-            let cycle_end = i;
-            let cycle_len = cycle_end - cycle_start;
-            let remaining_rock_count = ROCK_COUNT - cycle_end;
-            let remaining_cycles = (remaining_rock_count / cycle_len) as u64;
-            let remainder = remaining_rock_count % cycle_len;
-            let cycle_start_height = max_heights[cycle_start] as u64;
-            let cycle_height_diff = cycle_end_height - cycle_start_height;
-            let remainder_height = max_heights[cycle_start + remainder] as u64;
-            let remainder_height_diff = remainder_height - cycle_start_height;
-            return cycle_end_height + cycle_height_diff * remaining_cycles + remainder_height_diff;
-        }
-        seen.insert(key, i);
-        simulation.drop_next_rock();
+    let cycle = find_cycle(
+        &mut simulation,
+        Simulation::drop_next_rock,
+        |simulation| {
+            (
+                simulation.next_shape_index,
+                simulation.next_motion_index,
+                simulation.offsets(),
+            )
+        },
+        |simulation| simulation.max_height as u64,
+        rock_count,
+    );
+    match cycle {
+        Some(cycle) => cycle.extrapolate(rock_count, |&height| height),
+        // There was no cycle, so all the rocks were dropped
+        None => simulation.max_height as u64,
+    }
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 17;
+
+    type Input<'a> = Vec<Motion>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
     }
-    panic!("no cycle")
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
 
+    const PART_ONE_CASES: [(&str, u64); 2] =
+        [(INPUT, 3130), (include_str!("../examples/1.txt"), 3068)];
+
+    const PART_TWO_CASES: [(&str, u64); 2] = [
+        (INPUT, 1_556_521_739_139),
+        (include_str!("../examples/1.txt"), 1_514_285_714_288),
+    ];
+
     #[test]
     fn test_part_one() {
-        let motions = parse_input(INPUT);
-        assert_eq!(part_one(&motions), 3130);
+        for (input, expected) in PART_ONE_CASES {
+            let motions = parse_input(input).unwrap();
+            assert_eq!(part_one(&motions), expected);
+        }
     }
 
     #[test]
     fn test_part_two() {
-        let motions = parse_input(INPUT);
-        assert_eq!(part_two(&motions), 1_556_521_739_139);
+        for (input, expected) in PART_TWO_CASES {
+            let motions = parse_input(input).unwrap();
+            assert_eq!(part_two(&motions), expected);
+        }
+    }
+
+    #[test]
+    fn test_tower_height() {
+        let motions = parse_input(include_str!("../examples/1.txt")).unwrap();
+        let mut simulation = Simulation::new(&motions);
+        for rock_count in 0..=500 {
+            assert_eq!(
+                tower_height(&motions, rock_count),
+                simulation.max_height as u64
+            );
+            simulation.drop_next_rock();
+        }
     }
 }
@@ -1,16 +1,25 @@
+use aoc_common::{parse_str, Answer, ParseError, ParseResult, Result, Solution};
 use rustc_hash::FxHashSet as HashSet;
 
 pub const INPUT: &str = include_str!("../input.txt");
 
 type Position = (i32, i32, i32);
 
-pub fn parse_input(input: &str) -> HashSet<Position> {
-    let values = input
-        .trim()
-        .split(['\n', ','])
-        .map(|s| s.parse().unwrap())
-        .collect::<Vec<_>>();
-    values.chunks_exact(3).map(|c| (c[0], c[1], c[2])).collect()
+pub fn parse_input(input: &str) -> ParseResult<HashSet<Position>> {
+    let positions = input
+        .lines()
+        .map(|line| {
+            let mut values = line.split(',').map(|s| parse_str(input, s));
+            match (values.next(), values.next(), values.next(), values.next()) {
+                (Some(x), Some(y), Some(z), None) => Ok((x?, y?, z?)),
+                _ => Err(ParseError::new(input, line, "expected a cube like 2,2,2")),
+            }
+        })
+        .collect::<ParseResult<HashSet<_>>>()?;
+    if positions.is_empty() {
+        return Err(ParseError::at_end(input, "expected a cube"));
+    }
+    Ok(positions)
 }
 
 pub fn part_one(positions: &HashSet<Position>) -> usize {
@@ -68,19 +77,50 @@ pub fn part_two(positions: &HashSet<Position>) -> usize {
     surface_area
 }
 
+pub struct Day18;
+
+impl Solution for Day18 {
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 18;
+
+    type Input<'a> = HashSet<Position>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
 
+    const PART_ONE_CASES: [(&str, usize); 2] =
+        [(INPUT, 3586), (include_str!("../examples/1.txt"), 64)];
+
+    const PART_TWO_CASES: [(&str, usize); 2] =
+        [(INPUT, 2072), (include_str!("../examples/1.txt"), 58)];
+
     #[test]
     fn test_part_one() {
-        let positions = parse_input(INPUT);
-        assert_eq!(part_one(&positions), 3586);
+        for (input, expected) in PART_ONE_CASES {
+            let positions = parse_input(input).unwrap();
+            assert_eq!(part_one(&positions), expected);
+        }
     }
 
     #[test]
     fn test_part_two() {
-        let positions = parse_input(INPUT);
-        assert_eq!(part_two(&positions), 2072);
+        for (input, expected) in PART_TWO_CASES {
+            let positions = parse_input(input).unwrap();
+            assert_eq!(part_two(&positions), expected);
+        }
     }
 }
@@ -1,57 +1,62 @@
+use aoc_common::{parse_str, Answer, ParseError, ParseResult, Result, Solution};
 use regex::Regex;
 use rustc_hash::FxHashSet as HashSet;
 use std::cmp;
 
 pub const INPUT: &str = include_str!("../input.txt");
 
-pub fn parse_input(input: &str) -> Vec<Blueprint> {
+pub fn parse_input(input: &str) -> ParseResult<Vec<Blueprint>> {
     let regex_str = concat!(
-        r"Blueprint (\d+): ",
+        r"^Blueprint (\d+): ",
         r"Each ore robot costs (\d+) ore. ",
         r"Each clay robot costs (\d+) ore. ",
         r"Each obsidian robot costs (\d+) ore and (\d+) clay. ",
-        r"Each geode robot costs (\d+) ore and (\d+) obsidian."
+        r"Each geode robot costs (\d+) ore and (\d+) obsidian.$"
     );
     let regex = Regex::new(regex_str).unwrap();
-    regex
-        .captures_iter(input)
-        .map(|captures| {
-            let id = captures[1].parse().unwrap();
+    input
+        .lines()
+        .map(|line| {
+            let captures = regex
+                .captures(line)
+                .ok_or_else(|| ParseError::new(input, line, "expected a blueprint"))?;
+            let number = |i: usize| parse_str(input, &captures[i]);
+            let id = number(1)?;
             let ore_collector_cost = Cost {
-                amount: captures[2].parse().unwrap(),
+                amount: number(2)?,
                 resource: Resource::Ore,
             };
             let clay_collector_cost = Cost {
-                amount: captures[3].parse().unwrap(),
+                amount: number(3)?,
                 resource: Resource::Ore,
             };
             let obsidian_collector_costs = [
                 Cost {
-                    amount: captures[4].parse().unwrap(),
+                    amount: number(4)?,
                     resource: Resource::Ore,
                 },
                 Cost {
-                    amount: captures[5].parse().unwrap(),
+                    amount: number(5)?,
                     resource: Resource::Clay,
                 },
             ];
             let geode_collector_costs = [
                 Cost {
-                    amount: captures[6].parse().unwrap(),
+                    amount: number(6)?,
                     resource: Resource::Ore,
                 },
                 Cost {
-                    amount: captures[7].parse().unwrap(),
+                    amount: number(7)?,
                     resource: Resource::Obsidian,
                 },
             ];
-            Blueprint::new(
+            Ok(Blueprint::new(
                 id,
                 Box::new([ore_collector_cost]),
                 Box::new([clay_collector_cost]),
                 Box::new(obsidian_collector_costs),
                 Box::new(geode_collector_costs),
-            )
+            ))
         })
         .collect()
 }
@@ -90,7 +95,7 @@ fn find_max_geode_count(blueprint: &Blueprint, time_limit: u32) -> u32 {
                 collector_costs,
                 max_useful_collectors,
             } = blueprint.resource(r);
-            let capped = max_useful_collectors.map_or(false, |c| collector_count >= c);
+            let capped = max_useful_collectors.is_some_and(|c| collector_count >= c);
             if capped {
                 continue;
             }
@@ -266,7 +271,7 @@ impl State {
                     return None;
                 }
                 let needed = c.amount - amount;
-                let t = if needed % collector_count == 0 {
+                let t = if needed.is_multiple_of(collector_count) {
                     needed / collector_count
                 } else {
                     needed / collector_count + 1
@@ -286,19 +291,50 @@ impl State {
     }
 }
 
+pub struct Day19;
+
+impl Solution for Day19 {
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 19;
+
+    type Input<'a> = Vec<Blueprint>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
 
+    const PART_ONE_CASES: [(&str, u32); 2] =
+        [(INPUT, 1659), (include_str!("../examples/1.txt"), 33)];
+
+    const PART_TWO_CASES: [(&str, u32); 2] =
+        [(INPUT, 6804), (include_str!("../examples/1.txt"), 3472)];
+
     #[test]
     fn test_part_one() {
-        let blueprints = parse_input(INPUT);
-        assert_eq!(part_one(&blueprints), 1659);
+        for (input, expected) in PART_ONE_CASES {
+            let blueprints = parse_input(input).unwrap();
+            assert_eq!(part_one(&blueprints), expected);
+        }
     }
 
     #[test]
     fn test_part_two() {
-        let blueprints = parse_input(INPUT);
-        assert_eq!(part_two(&blueprints), 6804);
+        for (input, expected) in PART_TWO_CASES {
+            let blueprints = parse_input(input).unwrap();
+            assert_eq!(part_two(&blueprints), expected);
+        }
     }
 }
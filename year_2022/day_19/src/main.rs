@@ -1,24 +1,5 @@
-use year_2022_day_19::INPUT;
-use std::env;
+use year_2022_day_19::{Day19, INPUT};
 
 fn main() {
-    let parse_result = year_2022_day_19::parse_input(INPUT);
-    match env::args().nth(1).as_deref() {
-        Some("all") => {
-            let part_one = year_2022_day_19::part_one(&parse_result);
-            println!("{part_one}");
-            let part_two = year_2022_day_19::part_two(&parse_result);
-            println!("{part_two}");
-        }
-        Some("parse") => {}
-        Some("one") => {
-            let part_one = year_2022_day_19::part_one(&parse_result);
-            println!("{part_one}");
-        }
-        Some("two") => {
-            let part_two = year_2022_day_19::part_two(&parse_result);
-            println!("{part_two}");
-        }
-        _ => println!("Invalid argument: must be one of all, parse, one, or two"),
-    }
+    aoc_common::run::<Day19>(INPUT);
 }
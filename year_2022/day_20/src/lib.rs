@@ -1,9 +1,18 @@
+use aoc_common::{parse_str, Answer, ParseError, ParseResult, Result, Solution};
 use std::ptr;
 
 pub const INPUT: &str = include_str!("../input.txt");
 
-pub fn parse_input(input: &str) -> Vec<i64> {
-    input.lines().map(|line| line.parse().unwrap()).collect()
+pub fn parse_input(input: &str) -> ParseResult<Vec<i64>> {
+    let numbers = input
+        .lines()
+        .map(|line| parse_str(input, line))
+        .collect::<ParseResult<Vec<i64>>>()?;
+    // The coordinates are counted from the 0.
+    if !numbers.contains(&0) {
+        return Err(ParseError::at_end(input, "expected a 0 among the numbers"));
+    }
+    Ok(numbers)
 }
 
 pub fn part_one(numbers: &[i64]) -> i64 {
@@ -42,19 +51,52 @@ fn coordinates_sum(references: &[&i64]) -> i64 {
         .sum()
 }
 
+pub struct Day20;
+
+impl Solution for Day20 {
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 20;
+
+    type Input<'a> = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
 
+    const PART_ONE_CASES: [(&str, i64); 2] =
+        [(INPUT, 15297), (include_str!("../examples/1.txt"), 3)];
+
+    const PART_TWO_CASES: [(&str, i64); 2] = [
+        (INPUT, 2_897_373_276_210),
+        (include_str!("../examples/1.txt"), 1_623_178_306),
+    ];
+
     #[test]
     fn test_part_one() {
-        let numbers = parse_input(INPUT);
-        assert_eq!(part_one(&numbers), 15297);
+        for (input, expected) in PART_ONE_CASES {
+            let numbers = parse_input(input).unwrap();
+            assert_eq!(part_one(&numbers), expected);
+        }
     }
 
     #[test]
     fn test_part_two() {
-        let numbers = parse_input(INPUT);
-        assert_eq!(part_two(&numbers), 2_897_373_276_210);
+        for (input, expected) in PART_TWO_CASES {
+            let numbers = parse_input(input).unwrap();
+            assert_eq!(part_two(&numbers), expected);
+        }
     }
 }
@@ -1,3 +1,4 @@
+use aoc_common::{Answer, ParseError, ParseResult, Result, Solution};
 use rustc_hash::FxHashMap as HashMap;
 
 pub const INPUT: &str = include_str!("../input.txt");
@@ -18,28 +19,33 @@ pub enum Operation {
 
 type Monkeys = HashMap<String, Value>;
 
-pub fn parse_input(input: &str) -> Monkeys {
+pub fn parse_input(input: &str) -> ParseResult<Monkeys> {
     input
         .lines()
         .map(|line| {
-            let (name_str, value_str) = line.split_once(": ").unwrap();
+            let (name_str, value_str) = line.split_once(": ").ok_or_else(|| {
+                ParseError::new(input, line, "expected a monkey like \"abcd: 5\"")
+            })?;
             let name = name_str.to_owned();
             if let Ok(number) = value_str.parse::<i64>() {
-                return (name, Value::Number(number));
+                return Ok((name, Value::Number(number)));
             }
-            let mut parts = value_str.split_whitespace();
-            let left = parts.next().unwrap().to_owned();
-            let op_str = parts.next().unwrap();
+            let mut parts = value_str.split(' ');
+            let (Some(left), Some(op_str), Some(right), None) =
+                (parts.next(), parts.next(), parts.next(), parts.next())
+            else {
+                let message = "expected a number or an operation like \"abcd + efgh\"";
+                return Err(ParseError::new(input, value_str, message));
+            };
             let op = match op_str {
                 "+" => Operation::Add,
                 "-" => Operation::Sub,
                 "*" => Operation::Mul,
                 "/" => Operation::Div,
-                _ => panic!("Unknown operator {}", op_str),
+                _ => return Err(ParseError::new(input, op_str, "expected +, -, * or /")),
             };
-            let right = parts.next().unwrap().to_owned();
-            let value = Value::BinaryOp(left, op, right);
-            (name, value)
+            let value = Value::BinaryOp(left.to_owned(), op, right.to_owned());
+            Ok((name, value))
         })
         .collect()
 }
@@ -137,19 +143,54 @@ impl Value {
         }
     }
 }
+pub struct Day21;
+
+impl Solution for Day21 {
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 21;
+
+    type Input<'a> = Monkeys;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
 
+    const PART_ONE_CASES: [(&str, i64); 2] = [
+        (INPUT, 364_367_103_397_416),
+        (include_str!("../examples/1.txt"), 152),
+    ];
+
+    const PART_TWO_CASES: [(&str, i64); 2] = [
+        (INPUT, 3_782_852_515_583),
+        (include_str!("../examples/1.txt"), 301),
+    ];
+
     #[test]
     fn test_part_one() {
-        let monkeys = parse_input(INPUT);
-        assert_eq!(part_one(&monkeys), 364_367_103_397_416);
+        for (input, expected) in PART_ONE_CASES {
+            let monkeys = parse_input(input).unwrap();
+            assert_eq!(part_one(&monkeys), expected);
+        }
     }
 
     #[test]
     fn test_part_two() {
-        let monkeys = parse_input(INPUT);
-        assert_eq!(part_two(&monkeys), 3_782_852_515_583);
+        for (input, expected) in PART_TWO_CASES {
+            let monkeys = parse_input(input).unwrap();
+            assert_eq!(part_two(&monkeys), expected);
+        }
     }
 }
@@ -2,7 +2,7 @@ use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use year_2022_day_22::INPUT;
 
 fn criterion_benchmark(c: &mut Criterion) {
-    let (map_regions, path) = year_2022_day_22::parse_input(INPUT);
+    let (map_regions, path) = year_2022_day_22::parse_input(INPUT).unwrap();
 
     c.bench_function("year_2022_day_22::parse_input", |b| {
         b.iter(|| year_2022_day_22::parse_input(black_box(INPUT)));
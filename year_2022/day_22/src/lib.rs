@@ -1,12 +1,16 @@
+use aoc_common::{parse_str, Answer, Direction, ParseError, ParseResult, Point2, Result, Solution};
+
 pub const INPUT: &str = include_str!("../input.txt");
 
 const REGION_SIZE: usize = 50;
 
-pub fn parse_input(input: &str) -> ([MapRegion; 6], Vec<PathStep>) {
-    let (map_str, path_str) = input.split_once("\n\n").unwrap();
-    let map_regions = parse_map_regions(map_str);
-    let path = parse_path(path_str);
-    (map_regions, path)
+pub fn parse_input(input: &str) -> ParseResult<([MapRegion; 6], Vec<PathStep>)> {
+    let (map_str, path_str) = input.split_once("\n\n").ok_or_else(|| {
+        ParseError::at_end(input, "expected a blank line between the map and the path")
+    })?;
+    let map_regions = parse_map_regions(input, map_str)?;
+    let path = parse_path(input, path_str)?;
+    Ok((map_regions, path))
 }
 
 pub fn part_one(map_regions: &[MapRegion; 6], path: &[PathStep]) -> usize {
@@ -19,69 +23,71 @@ pub fn part_two(map_regions: &[MapRegion; 6], path: &[PathStep]) -> usize {
     solve(&map, path)
 }
 
-fn parse_map_regions(map_str: &str) -> [MapRegion; 6] {
-    let lines = map_str
-        .lines()
-        .map(|l| l.bytes().collect::<Vec<_>>())
-        .collect::<Vec<_>>();
-    lines
-        .chunks_exact(REGION_SIZE)
-        .enumerate()
-        .flat_map(|(region_y, chunk)| {
-            let width = chunk[0].len();
-            (0..width / REGION_SIZE)
-                .filter(|&region_x| {
-                    let region_start_x = region_x * REGION_SIZE;
-                    !chunk[0][region_start_x].is_ascii_whitespace()
-                })
-                .map(move |region_x| {
-                    let position = (region_x, region_y);
-                    let region_start_x = region_x * REGION_SIZE;
-                    let region_end_x = region_start_x + REGION_SIZE;
-                    let tiles = chunk
-                        .iter()
-                        .map(|bytes| {
-                            bytes[region_start_x..region_end_x]
-                                .iter()
-                                .map(|b| match b {
-                                    b'.' => Tile::Open,
-                                    b'#' => Tile::Wall,
-                                    _ => unreachable!(),
-                                })
-                                .collect::<Vec<_>>()
-                                .try_into()
-                                .unwrap()
-                        })
-                        .collect::<Vec<_>>()
-                        .try_into()
-                        .unwrap();
-                    MapRegion { position, tiles }
-                })
-        })
-        .collect::<Vec<_>>()
-        .try_into()
-        .unwrap()
+fn parse_map_regions(input: &str, map_str: &str) -> ParseResult<[MapRegion; 6]> {
+    let lines = map_str.lines().collect::<Vec<_>>();
+    if lines.len() % REGION_SIZE != 0 {
+        let message = format!("the map must be a multiple of {REGION_SIZE} rows high");
+        return Err(ParseError::new(input, map_str, message));
+    }
+    let mut regions = vec![];
+    for (region_y, chunk) in lines.chunks_exact(REGION_SIZE).enumerate() {
+        let width = chunk[0].len();
+        for region_x in 0..width / REGION_SIZE {
+            let region_start_x = region_x * REGION_SIZE;
+            if chunk[0].as_bytes()[region_start_x].is_ascii_whitespace() {
+                continue;
+            }
+            let region_end_x = region_start_x + REGION_SIZE;
+            let mut tiles = [[Tile::Open; REGION_SIZE]; REGION_SIZE];
+            for (row, &line) in tiles.iter_mut().zip(chunk) {
+                let bytes = line
+                    .as_bytes()
+                    .get(region_start_x..region_end_x)
+                    .ok_or_else(|| ParseError::new(input, line, "the row ends inside a region"))?;
+                for (x, (tile, &b)) in row.iter_mut().zip(bytes).enumerate() {
+                    *tile = match b {
+                        b'.' => Tile::Open,
+                        b'#' => Tile::Wall,
+                        _ => {
+                            let offset = region_start_x + x;
+                            return Err(ParseError::in_span(
+                                input,
+                                line,
+                                offset,
+                                "expected . or #",
+                            ));
+                        }
+                    };
+                }
+            }
+            let position = (region_x, region_y);
+            regions.push(MapRegion { position, tiles });
+        }
+    }
+    let region_count = regions.len();
+    regions.try_into().map_err(|_| {
+        let message = format!("the map has {region_count} faces instead of 6");
+        ParseError::new(input, map_str, message)
+    })
 }
 
-fn parse_path(mut path_str: &str) -> Vec<PathStep> {
-    path_str = path_str.trim();
+fn parse_path(input: &str, path_str: &str) -> ParseResult<Vec<PathStep>> {
+    let mut path_str = path_str.trim();
     let mut path = vec![];
     while !path_str.is_empty() {
         let end_index = path_str.find(['L', 'R']).unwrap_or(path_str.len());
-        let forward = PathStep::Forward(path_str[..end_index].parse().unwrap());
+        let forward = PathStep::Forward(parse_str(input, &path_str[..end_index])?);
         path.push(forward);
         path_str = &path_str[end_index..];
-        if !path_str.is_empty() {
-            let turn = match path_str.chars().next().unwrap() {
-                'L' => PathStep::Left,
-                'R' => PathStep::Right,
-                _ => unreachable!(),
-            };
-            path.push(turn);
-            path_str = &path_str[1..];
+        if let Some(rest) = path_str.strip_prefix('L') {
+            path.push(PathStep::Left);
+            path_str = rest;
+        } else if let Some(rest) = path_str.strip_prefix('R') {
+            path.push(PathStep::Right);
+            path_str = rest;
         }
     }
-    path
+    Ok(path)
 }
 
 fn solve(map: &Map, path: &[PathStep]) -> usize {
@@ -197,18 +203,19 @@ impl<'a> Map<'a> {
     fn link_as_cube(regions: &'a [MapRegion; 6]) -> Self {
         let mut region_links: [RegionLinks; 6] = Default::default();
         let mut linked_cubes = vec![vec![]; 6];
+        // The order edges are listed in decides ties between equally close
+        // edges below.
+        let edge_directions = [
+            Direction::Up,
+            Direction::Left,
+            Direction::Down,
+            Direction::Right,
+        ];
         let mut unlinked_region_edges = (0..6)
             .flat_map(|id| {
-                Direction::iter().map(move |direction| {
+                edge_directions.into_iter().map(move |direction| {
                     let (x, y) = regions[id].position;
-                    let (mut x, mut y) = (x as i32, y as i32);
-                    match direction {
-                        Direction::Up => y -= 1,
-                        Direction::Left => x -= 1,
-                        Direction::Right => x += 1,
-                        Direction::Down => y += 1,
-                    }
-                    let position = (x, y);
+                    let position = Point2::new(x as i64, y as i64) + direction.offset();
                     (id, direction, position)
                 })
             })
@@ -219,8 +226,8 @@ impl<'a> Map<'a> {
                 unlinked_region_edges
                     .iter()
                     .filter(move |&&(b_id, _, b_position)| {
-                        let a_position = (a_position.0 as usize, a_position.1 as usize);
-                        let b_position = (b_position.0 as usize, b_position.1 as usize);
+                        let a_position = (a_position.x as usize, a_position.y as usize);
+                        let b_position = (b_position.x as usize, b_position.y as usize);
                         a_id < b_id
                             && regions[a_id].position == b_position
                             && regions[b_id].position == a_position
@@ -240,7 +247,7 @@ impl<'a> Map<'a> {
                 unlinked_region_edges.first().copied().unwrap(),
                 unlinked_region_edges.last().copied().unwrap(),
             );
-            let mut best_distance = manhattan_distance(best_pair.0 .2, best_pair.1 .2);
+            let mut best_distance = best_pair.0 .2.manhattan_distance(best_pair.1 .2);
             for &a @ (a_id, _, a_position) in &unlinked_region_edges {
                 for &b @ (b_id, _, b_position) in &unlinked_region_edges {
                     if a_id == b_id
@@ -249,7 +256,7 @@ impl<'a> Map<'a> {
                     {
                         continue;
                     }
-                    let distance = manhattan_distance(a_position, b_position);
+                    let distance = a_position.manhattan_distance(b_position);
                     if distance < best_distance {
                         best_pair = (a, b);
                         best_distance = distance;
@@ -269,7 +276,7 @@ impl<'a> Map<'a> {
         }
     }
 
-    fn cursor(&self) -> MapCursor {
+    fn cursor(&self) -> MapCursor<'_> {
         MapCursor {
             map: self,
             region_index: 0,
@@ -389,50 +396,25 @@ impl<'a> MapCursor<'a> {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
-enum Direction {
-    #[default]
-    Up,
-    Left,
-    Right,
-    Down,
-}
+pub struct Day22;
 
-impl Direction {
-    fn turn_left(&self) -> Self {
-        match self {
-            Direction::Up => Direction::Left,
-            Direction::Left => Direction::Down,
-            Direction::Down => Direction::Right,
-            Direction::Right => Direction::Up,
-        }
-    }
+impl Solution for Day22 {
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 22;
 
-    fn turn_right(&self) -> Self {
-        match self {
-            Direction::Up => Direction::Right,
-            Direction::Right => Direction::Down,
-            Direction::Down => Direction::Left,
-            Direction::Left => Direction::Up,
-        }
-    }
+    type Input<'a> = ([MapRegion; 6], Vec<PathStep>);
 
-    fn reverse(&self) -> Self {
-        match self {
-            Direction::Up => Direction::Down,
-            Direction::Down => Direction::Up,
-            Direction::Left => Direction::Right,
-            Direction::Right => Direction::Left,
-        }
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse_input(input)?)
     }
 
-    fn iter() -> impl Iterator<Item = Direction> {
-        [Self::Up, Self::Left, Self::Down, Self::Right].into_iter()
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(&input.0, &input.1).into()
     }
-}
 
-fn manhattan_distance((x1, y1): (i32, i32), (x2, y2): (i32, i32)) -> i32 {
-    (x1 - x2).abs() + (y1 - y2).abs()
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(&input.0, &input.1).into()
+    }
 }
 
 #[cfg(test)]
@@ -441,7 +423,7 @@ mod test {
 
     #[test]
     fn test_part_one() {
-        let (map, path) = parse_input(INPUT);
+        let (map, path) = parse_input(INPUT).unwrap();
         assert_eq!(part_one(&map, &path), 65368);
     }
 
@@ -0,0 +1,134 @@
+use aoc_common::{Answer, Error, ParseError, ParseResult, Result, Solution};
+
+pub const INPUT: &str = include_str!("../input.txt");
+
+const WORD_DIGIT_PAIRS: [(&str, u8); 9] = [
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+
+pub fn parse_input(input: &str) -> ParseResult<Vec<&str>> {
+    input
+        .lines()
+        .map(|line| match first_digit(line) {
+            Some(_) => Ok(line),
+            None => Err(ParseError::new(input, line, "expected a digit")),
+        })
+        .collect()
+}
+
+pub fn part_one(lines: &[&str]) -> Result<u32> {
+    lines
+        .iter()
+        .map(|line| {
+            let left_digit = line.bytes().find(u8::is_ascii_digit)? - b'0';
+            let right_digit = line.bytes().rfind(u8::is_ascii_digit)? - b'0';
+            Some((10 * left_digit + right_digit) as u32)
+        })
+        .sum::<Option<u32>>()
+        .ok_or_else(|| Error::msg("a line only has spelled out digits"))
+}
+
+pub fn part_two(lines: &[&str]) -> Result<u32> {
+    lines
+        .iter()
+        .map(|line| Some((10 * first_digit(line)? + last_digit(line)?) as u32))
+        .sum::<Option<u32>>()
+        .ok_or_else(|| Error::msg("a line has no digit"))
+}
+
+/// The first digit in the line, which may be spelled out.
+fn first_digit(line: &str) -> Option<u8> {
+    (0..line.len()).find_map(|i| {
+        let s = &line[i..];
+        if let Some(b) = s.bytes().next().filter(u8::is_ascii_digit) {
+            return Some(b - b'0');
+        }
+        for &(word, digit) in &WORD_DIGIT_PAIRS {
+            if s.starts_with(word) {
+                return Some(digit);
+            }
+        }
+        None
+    })
+}
+
+/// The last digit in the line, which may be spelled out.
+fn last_digit(line: &str) -> Option<u8> {
+    (0..line.len()).rev().find_map(|i| {
+        let s = &line[..=i];
+        if let Some(b) = s.bytes().next_back().filter(u8::is_ascii_digit) {
+            return Some(b - b'0');
+        }
+        for &(word, digit) in &WORD_DIGIT_PAIRS {
+            if s.ends_with(word) {
+                return Some(digit);
+            }
+        }
+        None
+    })
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u32 = 2023;
+    const DAY: u32 = 1;
+
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PART_ONE_CASES: [(&str, u32); 2] =
+        [(INPUT, 54_632), (include_str!("../examples/1.txt"), 142)];
+
+    const PART_TWO_CASES: [(&str, u32); 2] =
+        [(INPUT, 54_019), (include_str!("../examples/2.txt"), 281)];
+
+    #[test]
+    fn test_part_one() {
+        for (input, expected) in PART_ONE_CASES {
+            let lines = parse_input(input).unwrap();
+            assert_eq!(part_one(&lines).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_part_two() {
+        for (input, expected) in PART_TWO_CASES {
+            let lines = parse_input(input).unwrap();
+            assert_eq!(part_two(&lines).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_missing_digits() {
+        let error = parse_input("1abc2\nabc\n").unwrap_err();
+        assert_eq!((error.line(), error.message()), (2, "expected a digit"));
+        let lines = parse_input("1abc2\nxtwone\n").unwrap();
+        assert!(part_one(&lines).is_err());
+        assert_eq!(part_two(&lines).unwrap(), 12 + 21);
+    }
+}
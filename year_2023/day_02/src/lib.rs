@@ -0,0 +1,115 @@
+use aoc_common::{parse_str, Answer, ParseError, ParseResult, Result, Solution};
+use std::cmp;
+
+pub const INPUT: &str = include_str!("../input.txt");
+
+pub struct Game {
+    id: u32,
+    max_red: u32,
+    max_green: u32,
+    max_blue: u32,
+}
+
+pub fn parse_input(input: &str) -> ParseResult<Vec<Game>> {
+    input
+        .lines()
+        .map(|line| {
+            let invalid =
+                || ParseError::new(input, line, "expected a game like \"Game 1: 3 blue\"");
+            let rest = line.strip_prefix("Game ").ok_or_else(invalid)?;
+            let (id, rest) = rest.split_once(": ").ok_or_else(invalid)?;
+            let id = parse_str(input, id)?;
+            let mut max_red = 0;
+            let mut max_green = 0;
+            let mut max_blue = 0;
+            for cubes in rest.split("; ").flat_map(|s| s.split(", ")) {
+                let (count, color) = cubes.split_once(' ').ok_or_else(|| {
+                    ParseError::new(input, cubes, "expected a count and a color like \"3 blue\"")
+                })?;
+                let count: u32 = parse_str(input, count)?;
+                match color {
+                    "red" => max_red = cmp::max(max_red, count),
+                    "green" => max_green = cmp::max(max_green, count),
+                    "blue" => max_blue = cmp::max(max_blue, count),
+                    _ => return Err(ParseError::new(input, color, "expected red, green or blue")),
+                };
+            }
+            Ok(Game {
+                id,
+                max_red,
+                max_green,
+                max_blue,
+            })
+        })
+        .collect()
+}
+
+pub fn part_one(games: &[Game]) -> u32 {
+    const RED_LIMIT: u32 = 12;
+    const GREEN_LIMIT: u32 = 13;
+    const BLUE_LIMIT: u32 = 14;
+    games
+        .iter()
+        .filter(|game| {
+            game.max_red <= RED_LIMIT
+                && game.max_green <= GREEN_LIMIT
+                && game.max_blue <= BLUE_LIMIT
+        })
+        .map(|game| game.id)
+        .sum()
+}
+
+pub fn part_two(games: &[Game]) -> u32 {
+    games
+        .iter()
+        .map(|game| game.max_red * game.max_green * game.max_blue)
+        .sum()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u32 = 2023;
+    const DAY: u32 = 2;
+
+    type Input<'a> = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PART_ONE_CASES: [(&str, u32); 2] =
+        [(INPUT, 3_059), (include_str!("../examples/1.txt"), 8)];
+
+    const PART_TWO_CASES: [(&str, u32); 2] =
+        [(INPUT, 65_371), (include_str!("../examples/1.txt"), 2_286)];
+
+    #[test]
+    fn test_part_one() {
+        for (input, expected) in PART_ONE_CASES {
+            let games = parse_input(input).unwrap();
+            assert_eq!(part_one(&games), expected);
+        }
+    }
+
+    #[test]
+    fn test_part_two() {
+        for (input, expected) in PART_TWO_CASES {
+            let games = parse_input(input).unwrap();
+            assert_eq!(part_two(&games), expected);
+        }
+    }
+}
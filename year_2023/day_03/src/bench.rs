@@ -0,0 +1,21 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use year_2023_day_03::INPUT;
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let schematic = year_2023_day_03::parse_input(INPUT).unwrap();
+
+    c.bench_function("year_2023_day_03::parse_input", |b| {
+        b.iter(|| year_2023_day_03::parse_input(black_box(INPUT)));
+    });
+
+    c.bench_function("year_2023_day_03::part_one", |b| {
+        b.iter(|| year_2023_day_03::part_one(black_box(&schematic)));
+    });
+
+    c.bench_function("year_2023_day_03::part_two", |b| {
+        b.iter(|| year_2023_day_03::part_two(black_box(&schematic)));
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,167 @@
+use aoc_common::{Answer, Grid, ParseResult, Position, Result, Solution};
+use std::cmp;
+
+pub const INPUT: &str = include_str!("../input.txt");
+
+pub fn parse_input(input: &str) -> ParseResult<Grid<u8>> {
+    Grid::parse(input, |b| b)
+}
+
+pub fn part_one(schematic: &Grid<u8>) -> u32 {
+    let mut sum = 0;
+    for (y, line) in schematic.rows().enumerate() {
+        let mut number_start = None;
+        let mut adjacent_to_symbol = false;
+        for (x, &b) in line.iter().enumerate() {
+            match b {
+                b'0'..=b'9' => {
+                    if number_start.is_none() {
+                        number_start = Some(x);
+                        adjacent_to_symbol = x
+                            .checked_sub(1)
+                            .map(|x_sub| {
+                                is_symbol(line[x_sub])
+                                    || is_adjacent_to_symbol(schematic, (x_sub, y))
+                            })
+                            .unwrap_or(false);
+                    }
+                    adjacent_to_symbol |= is_adjacent_to_symbol(schematic, (x, y))
+                }
+                b'.' => {
+                    if let Some(start) = number_start {
+                        adjacent_to_symbol |= is_adjacent_to_symbol(schematic, (x, y));
+                        if adjacent_to_symbol {
+                            sum += parse_number(&line[start..x]);
+                        }
+                        number_start = None;
+                        adjacent_to_symbol = false;
+                    }
+                }
+                _ => {
+                    if let Some(start) = number_start {
+                        sum += parse_number(&line[start..x]);
+                        number_start = None;
+                        adjacent_to_symbol = false;
+                    }
+                }
+            }
+        }
+        if let Some(start) = number_start.filter(|_| adjacent_to_symbol) {
+            sum += parse_number(&line[start..]);
+        }
+    }
+    sum
+}
+
+fn is_adjacent_to_symbol(schematic: &Grid<u8>, (x, y): Position) -> bool {
+    (y > 0 && is_symbol(schematic[(x, y - 1)]))
+        || (y + 1 < schematic.height() && is_symbol(schematic[(x, y + 1)]))
+}
+
+fn is_symbol(b: u8) -> bool {
+    b != b'.' && !b.is_ascii_digit()
+}
+
+fn parse_number(digits: &[u8]) -> u32 {
+    digits
+        .iter()
+        .fold(0, |number, &digit| number * 10 + u32::from(digit - b'0'))
+}
+
+pub fn part_two(schematic: &Grid<u8>) -> u32 {
+    let mut sum = 0;
+    for (y, line) in schematic.rows().enumerate() {
+        for (x, _) in line.iter().enumerate().filter(|&(_, &b)| b == b'*') {
+            if let Some(product) = two_adjacents_product(schematic, (x, y)) {
+                sum += product;
+            }
+        }
+    }
+    sum
+}
+
+fn two_adjacents_product(schematic: &Grid<u8>, (x, y): Position) -> Option<u32> {
+    let mut adjacent_number_count = 0;
+    let mut product = 1;
+    let rows = y.saturating_sub(1)..cmp::min(y + 2, schematic.height());
+    for line in rows.map(|y| schematic.row(y)) {
+        let mut column = x.saturating_sub(1);
+        while column < cmp::min(x + 2, line.len()) {
+            if !line[column].is_ascii_digit() {
+                column += 1;
+                continue;
+            }
+            adjacent_number_count += 1;
+            if adjacent_number_count > 2 {
+                return None;
+            }
+            let start = line[..column]
+                .iter()
+                .rposition(|b| !b.is_ascii_digit())
+                .map(|i| i + 1)
+                .unwrap_or(0);
+            let end = line[column + 1..]
+                .iter()
+                .position(|b| !b.is_ascii_digit())
+                .map(|i| i + column + 1)
+                .unwrap_or(line.len());
+            product *= parse_number(&line[start..end]);
+            column = end;
+        }
+    }
+    if adjacent_number_count == 2 {
+        Some(product)
+    } else {
+        None
+    }
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u32 = 2023;
+    const DAY: u32 = 3;
+
+    type Input<'a> = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PART_ONE_CASES: [(&str, u32); 2] =
+        [(INPUT, 544_664), (include_str!("../examples/1.txt"), 4_361)];
+
+    const PART_TWO_CASES: [(&str, u32); 2] = [
+        (INPUT, 84_495_585),
+        (include_str!("../examples/1.txt"), 467_835),
+    ];
+
+    #[test]
+    fn test_part_one() {
+        for (input, expected) in PART_ONE_CASES {
+            let schematic = parse_input(input).unwrap();
+            assert_eq!(part_one(&schematic), expected);
+        }
+    }
+
+    #[test]
+    fn test_part_two() {
+        for (input, expected) in PART_TWO_CASES {
+            let schematic = parse_input(input).unwrap();
+            assert_eq!(part_two(&schematic), expected);
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use aoc_common::{parse_str, Answer, ParseError, ParseResult, Result, Solution};
+
+pub const INPUT: &str = include_str!("../input.txt");
+
+pub fn parse_input(input: &str) -> ParseResult<Vec<u8>> {
+    let mut winning_numbers: Vec<u8> = Vec::with_capacity(10);
+    input
+        .lines()
+        .map(|line| {
+            let (winning, held) = line
+                .split_once(": ")
+                .and_then(|(_, numbers)| numbers.split_once(" | "))
+                .ok_or_else(|| {
+                    ParseError::new(
+                        input,
+                        line,
+                        "expected a card like \"Card 1: 41 48 | 83 86\"",
+                    )
+                })?;
+            winning_numbers.clear();
+            for number in winning.split_ascii_whitespace() {
+                winning_numbers.push(parse_str(input, number)?);
+            }
+            let mut count = 0;
+            for number in held.split_ascii_whitespace() {
+                if winning_numbers.contains(&parse_str(input, number)?) {
+                    count += 1;
+                }
+            }
+            Ok(count)
+        })
+        .collect()
+}
+
+pub fn part_one(win_counts: &[u8]) -> u32 {
+    win_counts
+        .iter()
+        .map(|&count| {
+            if count > 0 {
+                2u32.pow(count as u32 - 1)
+            } else {
+                0
+            }
+        })
+        .sum()
+}
+
+pub fn part_two(win_counts: &[u8]) -> u32 {
+    let mut card_counts = vec![1; win_counts.len()];
+    for (index, &win_count) in win_counts.iter().enumerate() {
+        let card_count = card_counts[index];
+        let start = index + 1;
+        let end = start + win_count as usize;
+        for later_card_count in card_counts[start..end].iter_mut() {
+            *later_card_count += card_count;
+        }
+    }
+    card_counts.iter().sum()
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u32 = 2023;
+    const DAY: u32 = 4;
+
+    type Input<'a> = Vec<u8>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let match_counts = parse_input(INPUT).unwrap();
+        assert_eq!(part_one(&match_counts), 22_897);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let match_counts = parse_input(INPUT).unwrap();
+        assert_eq!(part_two(&match_counts), 5_095_824);
+    }
+}
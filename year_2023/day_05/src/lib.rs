@@ -0,0 +1,156 @@
+use aoc_common::{parse_str, Answer, IntervalSet, ParseError, ParseResult, Result, Solution};
+use std::ops::Range;
+
+pub const INPUT: &str = include_str!("../input.txt");
+
+pub struct Almanac {
+    seeds: Vec<u64>,
+    maps: Vec<Vec<(Range<u64>, Range<u64>)>>,
+}
+
+pub fn parse_input(input: &str) -> ParseResult<Almanac> {
+    let mut sections = input.split("\n\n");
+    let seeds_line = sections.next().unwrap_or_default().trim_end();
+    let seeds = seeds_line
+        .strip_prefix("seeds: ")
+        .ok_or_else(|| ParseError::new(input, seeds_line, "expected seeds like \"seeds: 79 14\""))?
+        .split_ascii_whitespace()
+        .map(|s| parse_str(input, s))
+        .collect::<ParseResult<Vec<_>>>()?;
+    if seeds.is_empty() {
+        return Err(ParseError::new(
+            input,
+            seeds_line,
+            "expected at least one seed",
+        ));
+    }
+    let maps = sections
+        .filter(|section| !section.trim().is_empty())
+        .map(|section| {
+            let mut lines = section.lines();
+            let header = lines.next().unwrap_or_default();
+            if !header.ends_with(" map:") {
+                let message = "expected the name of a map like \"seed-to-soil map:\"";
+                return Err(ParseError::new(input, header, message));
+            }
+            lines.map(|line| parse_range(input, line)).collect()
+        })
+        .collect::<ParseResult<_>>()?;
+    Ok(Almanac { seeds, maps })
+}
+
+fn parse_range(input: &str, line: &str) -> ParseResult<(Range<u64>, Range<u64>)> {
+    let mut numbers = line.split_ascii_whitespace().map(|s| parse_str(input, s));
+    let (Some(destination_range_start), Some(source_range_start), Some(range_length), None) = (
+        numbers.next(),
+        numbers.next(),
+        numbers.next(),
+        numbers.next(),
+    ) else {
+        return Err(ParseError::new(
+            input,
+            line,
+            "expected a range like \"50 98 2\"",
+        ));
+    };
+    let (destination_range_start, source_range_start, range_length): (u64, u64, u64) =
+        (destination_range_start?, source_range_start?, range_length?);
+    let source_range = source_range_start..source_range_start + range_length;
+    let destination_range = destination_range_start..destination_range_start + range_length;
+    Ok((source_range, destination_range))
+}
+
+pub fn part_one(almanac: &Almanac) -> u64 {
+    almanac
+        .seeds
+        .iter()
+        .map(|&seed| {
+            let mut value = seed;
+            for map in almanac.maps.iter() {
+                for (source_range, destination_range) in map {
+                    if source_range.contains(&value) {
+                        if source_range.start <= destination_range.start {
+                            value += destination_range.start - source_range.start;
+                        } else {
+                            value -= source_range.start - destination_range.start;
+                        }
+                        break;
+                    }
+                }
+            }
+            value
+        })
+        .min()
+        .unwrap()
+}
+
+pub fn part_two(almanac: &Almanac) -> u64 {
+    let seeds = almanac
+        .seeds
+        .chunks_exact(2)
+        .map(|chunk| {
+            let seed_range_start = chunk[0];
+            let seed_range_length = chunk[1];
+            seed_range_start..seed_range_start + seed_range_length
+        })
+        .collect::<IntervalSet<_>>();
+    let locations = almanac.maps.iter().fold(seeds, |values, map| {
+        let mapping = map
+            .iter()
+            .map(|(source, destination)| (source.clone(), destination.start));
+        values.remap(mapping)
+    });
+    locations.min().unwrap()
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u32 = 2023;
+    const DAY: u32 = 5;
+
+    type Input<'a> = Almanac;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PART_ONE_CASES: [(&str, u64); 2] = [
+        (INPUT, 324_724_204),
+        (include_str!("../examples/1.txt"), 35),
+    ];
+
+    const PART_TWO_CASES: [(&str, u64); 2] = [
+        (INPUT, 104_070_862),
+        (include_str!("../examples/1.txt"), 46),
+    ];
+
+    #[test]
+    fn test_part_one() {
+        for (input, expected) in PART_ONE_CASES {
+            let almanac = parse_input(input).unwrap();
+            assert_eq!(part_one(&almanac), expected);
+        }
+    }
+
+    #[test]
+    fn test_part_two() {
+        for (input, expected) in PART_TWO_CASES {
+            let almanac = parse_input(input).unwrap();
+            assert_eq!(part_two(&almanac), expected);
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use aoc_common::{parse_str, Answer, ParseError, ParseResult, Result, Solution};
+
+pub const INPUT: &str = include_str!("../input.txt");
+
+pub struct Input {
+    part_one_races: Vec<Race>,
+    part_two_race: Race,
+}
+
+pub struct Race {
+    time: u64,
+    distance: u64,
+}
+
+pub fn parse_input(input: &str) -> ParseResult<Input> {
+    let mut lines = input.lines();
+    let (time_str, times) = parse_line(input, lines.next(), "Time:")?;
+    let (distance_str, distances) = parse_line(input, lines.next(), "Distance:")?;
+    if times.len() != distances.len() {
+        let message = format!("expected {} distances, one for each time", times.len());
+        return Err(ParseError::new(input, distance_str, message));
+    }
+    let part_one_races = times
+        .into_iter()
+        .zip(distances)
+        .map(|(time, distance)| Race { time, distance })
+        .collect();
+    let part_two_race = Race {
+        time: parse_digits(time_str),
+        distance: parse_digits(distance_str),
+    };
+    Ok(Input {
+        part_one_races,
+        part_two_race,
+    })
+}
+
+/// Parses a line of numbers starting with `label`, returning them along with
+/// the text they are in.
+fn parse_line<'a>(
+    input: &str,
+    line: Option<&'a str>,
+    label: &str,
+) -> ParseResult<(&'a str, Vec<u64>)> {
+    let message = || format!("expected a line starting with {label:?}");
+    let line = line.ok_or_else(|| ParseError::at_end(input, message()))?;
+    let numbers_str = line
+        .strip_prefix(label)
+        .ok_or_else(|| ParseError::new(input, line, message()))?;
+    let numbers = numbers_str
+        .split_ascii_whitespace()
+        .map(|s| parse_str(input, s))
+        .collect::<ParseResult<_>>()?;
+    Ok((numbers_str, numbers))
+}
+
+fn parse_digits(s: &str) -> u64 {
+    let mut value = 0;
+    for b in s.bytes() {
+        if b.is_ascii_digit() {
+            value = value * 10 + (b - b'0') as u64;
+        }
+    }
+    value
+}
+
+pub fn part_one(input: &Input) -> u64 {
+    input
+        .part_one_races
+        .iter()
+        .map(|race| {
+            let min_winning_time = min_winning_time(race).unwrap();
+            race.time - (2 * min_winning_time - 1)
+        })
+        .product()
+}
+
+pub fn part_two(input: &Input) -> u64 {
+    let race = &input.part_two_race;
+    let min_winning_time = min_winning_time(race).unwrap();
+    race.time - (2 * min_winning_time - 1)
+}
+
+fn min_winning_time(race: &Race) -> Option<u64> {
+    let t = race.time as f64;
+    let d = race.distance as f64;
+    let discriminant = t * t - 4.0 * d;
+    if discriminant >= 0.0 {
+        [
+            (t + discriminant.sqrt()) / 2.0,
+            (t - discriminant.sqrt()) / 2.0,
+        ]
+        .into_iter()
+        .filter_map(|root| {
+            if root >= 0.0 && root <= t {
+                Some(root.ceil() as u64)
+            } else {
+                None
+            }
+        })
+        .min()
+    } else {
+        None
+    }
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u32 = 2023;
+    const DAY: u32 = 6;
+
+    type Input<'a> = Input;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = parse_input(INPUT).unwrap();
+        assert_eq!(part_one(&input), 1_413_720);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = parse_input(INPUT).unwrap();
+        assert_eq!(part_two(&input), 30_565_288);
+    }
+}
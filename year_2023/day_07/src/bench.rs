@@ -0,0 +1,29 @@
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use year_2023_day_07::INPUT;
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let hands = year_2023_day_07::parse_input(INPUT).unwrap();
+
+    c.bench_function("year_2023_day_07::parse_input", |b| {
+        b.iter(|| year_2023_day_07::parse_input(black_box(INPUT)));
+    });
+
+    c.bench_function("year_2023_day_07::part_one", |b| {
+        b.iter_batched(
+            || hands.clone(),
+            |mut hands| year_2023_day_07::part_one(black_box(&mut hands)),
+            BatchSize::SmallInput,
+        );
+    });
+
+    c.bench_function("year_2023_day_07::part_two", |b| {
+        b.iter_batched(
+            || hands.clone(),
+            |mut hands| year_2023_day_07::part_two(black_box(&mut hands)),
+            BatchSize::SmallInput,
+        );
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
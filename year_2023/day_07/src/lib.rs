@@ -0,0 +1,203 @@
+use aoc_common::{parse_str, Answer, ParseError, ParseResult, Result, Solution};
+
+pub const INPUT: &str = include_str!("../input.txt");
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum Card {
+    Joker,
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Jack,
+    Queen,
+    King,
+    Ace,
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+impl HandType {
+    fn from_cards(cards: &[Card; 5]) -> Self {
+        let mut card_counts = [0; 14];
+        let mut max_count = 0;
+        let mut second_max_count = 0;
+        for &card in cards {
+            let count = &mut card_counts[card as usize];
+            *count += 1;
+            if card == Card::Joker {
+                continue;
+            }
+            if *count > max_count {
+                max_count = *count;
+            } else if *count > second_max_count {
+                second_max_count = *count;
+            }
+        }
+        max_count += card_counts[Card::Joker as usize];
+        match (max_count, second_max_count) {
+            (5, _) => HandType::FiveOfAKind,
+            (4, _) => HandType::FourOfAKind,
+            (3, 2) => HandType::FullHouse,
+            (3, _) => HandType::ThreeOfAKind,
+            (2, 2) => HandType::TwoPair,
+            (2, _) => HandType::OnePair,
+            _ => HandType::HighCard,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hand {
+    cards: [Card; 5],
+    bid: u32,
+    hand_type: HandType,
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        (self.hand_type, &self.cards).cmp(&(other.hand_type, &other.cards))
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+pub fn parse_input(input: &str) -> ParseResult<Vec<Hand>> {
+    input
+        .lines()
+        .map(|line| {
+            let (cards_str, bid) = line
+                .split_once(' ')
+                .filter(|(cards_str, _)| cards_str.len() == 5)
+                .ok_or_else(|| {
+                    ParseError::new(input, line, "expected a hand like \"32T3K 765\"")
+                })?;
+            let mut cards = [Card::Joker; 5];
+            for (i, (card, b)) in cards.iter_mut().zip(cards_str.bytes()).enumerate() {
+                *card = match b {
+                    b'A' => Card::Ace,
+                    b'K' => Card::King,
+                    b'Q' => Card::Queen,
+                    b'J' => Card::Jack,
+                    b'T' => Card::Ten,
+                    b'9' => Card::Nine,
+                    b'8' => Card::Eight,
+                    b'7' => Card::Seven,
+                    b'6' => Card::Six,
+                    b'5' => Card::Five,
+                    b'4' => Card::Four,
+                    b'3' => Card::Three,
+                    b'2' => Card::Two,
+                    _ => return Err(ParseError::in_span(input, cards_str, i, "expected a card")),
+                };
+            }
+            let bid = parse_str(input, bid)?;
+            let hand_type = HandType::from_cards(&cards);
+            Ok(Hand {
+                cards,
+                bid,
+                hand_type,
+            })
+        })
+        .collect()
+}
+
+pub fn part_one(hands: &mut [Hand]) -> u32 {
+    hands.sort_unstable();
+    hands
+        .iter()
+        .zip(1..)
+        .map(|(hand, rank)| hand.bid * rank)
+        .sum()
+}
+
+pub fn part_two(hands: &mut [Hand]) -> u32 {
+    for hand in hands.iter_mut() {
+        let mut changed_cards = false;
+        for card in hand.cards.iter_mut() {
+            if *card == Card::Jack {
+                *card = Card::Joker;
+                changed_cards = true;
+            }
+        }
+        if changed_cards {
+            hand.hand_type = HandType::from_cards(&hand.cards);
+        }
+    }
+    hands.sort_unstable();
+    hands
+        .iter()
+        .zip(1..)
+        .map(|(hand, rank)| hand.bid * rank)
+        .sum()
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: u32 = 2023;
+    const DAY: u32 = 7;
+
+    type Input<'a> = Vec<Hand>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(&mut input.clone()).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(&mut input.clone()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PART_ONE_CASES: [(&str, u32); 2] = [
+        (INPUT, 253_866_470),
+        (include_str!("../examples/1.txt"), 6_440),
+    ];
+
+    const PART_TWO_CASES: [(&str, u32); 2] = [
+        (INPUT, 254_494_947),
+        (include_str!("../examples/1.txt"), 5_905),
+    ];
+
+    #[test]
+    fn test_part_one() {
+        for (input, expected) in PART_ONE_CASES {
+            let mut hands = parse_input(input).unwrap();
+            assert_eq!(part_one(&mut hands), expected);
+        }
+    }
+
+    #[test]
+    fn test_part_two() {
+        for (input, expected) in PART_TWO_CASES {
+            let mut hands = parse_input(input).unwrap();
+            assert_eq!(part_two(&mut hands), expected);
+        }
+    }
+}
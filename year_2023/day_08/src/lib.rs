@@ -0,0 +1,280 @@
+use aoc_common::{crt, Answer, Error, ParseError, ParseResult, Result, Solution};
+
+pub const INPUT: &str = include_str!("../input.txt");
+
+/// Node names are three digits or capital letters, which `hash` packs into a
+/// base 36 number.
+const SIZE: usize = 36usize.pow(3);
+/// The map entry of a node that isn't defined.
+const UNDEFINED: u32 = u32::MAX;
+pub type Map = [u32; SIZE];
+
+pub fn parse_input(input: &str) -> ParseResult<(&str, Map, Vec<u32>)> {
+    let mut lines = input.lines();
+    let turns_line = lines.next().unwrap_or_default();
+    if turns_line.is_empty() {
+        return Err(ParseError::at(input, 0, "expected the turns"));
+    }
+    if let Some(i) = turns_line.bytes().position(|b| b != b'L' && b != b'R') {
+        return Err(ParseError::in_span(input, turns_line, i, "expected L or R"));
+    }
+    lines.next();
+    let mut map = [UNDEFINED; SIZE];
+    let mut starts = vec![];
+    let mut targets = vec![];
+    for line in lines {
+        let (name, left, right) = line
+            .strip_suffix(')')
+            .and_then(|line| line.split_once(" = ("))
+            .and_then(|(name, targets)| {
+                let (left, right) = targets.split_once(", ")?;
+                Some((name, left, right))
+            })
+            .ok_or_else(|| {
+                ParseError::new(input, line, "expected a node like \"AAA = (BBB, CCC)\"")
+            })?;
+        let id = parse_node(input, name)?;
+        if map[id as usize] != UNDEFINED {
+            return Err(ParseError::new(input, name, "the node is already defined"));
+        }
+        let left_id = parse_node(input, left)?;
+        let right_id = parse_node(input, right)?;
+        map[id as usize] = left_id << 16 | right_id;
+        targets.extend([(left, left_id), (right, right_id)]);
+        if id % 36 == encode(b'A') {
+            starts.push(id);
+        }
+    }
+    if let Some(&(name, _)) = targets
+        .iter()
+        .find(|&&(_, id)| map[id as usize] == UNDEFINED)
+    {
+        return Err(ParseError::new(input, name, "the node is not defined"));
+    }
+    Ok((turns_line, map, starts))
+}
+
+fn parse_node(input: &str, name: &str) -> ParseResult<u32> {
+    if name.len() != 3
+        || !name
+            .bytes()
+            .all(|b| b.is_ascii_uppercase() || b.is_ascii_digit())
+    {
+        let message = "node names must be three capital letters or digits";
+        return Err(ParseError::new(input, name, message));
+    }
+    Ok(hash(name))
+}
+
+const fn hash(s: &str) -> u32 {
+    let bytes = s.as_bytes();
+    (encode(bytes[0]) * 36 + encode(bytes[1])) * 36 + encode(bytes[2])
+}
+
+const fn encode(b: u8) -> u32 {
+    match b {
+        b'0'..=b'9' => (b - b'0') as u32 + 26,
+        _ => (b - b'A') as u32,
+    }
+}
+
+pub fn part_one(turns: &str, map: &Map) -> Result<u64> {
+    if map[hash("AAA") as usize] == UNDEFINED {
+        return Err(Error::msg("there is no node AAA"));
+    }
+    steps_to_target(turns, map, hash("AAA"), |h| h == hash("ZZZ"))
+        .ok_or_else(|| Error::msg("ZZZ can't be reached from AAA"))
+}
+
+pub fn part_two(turns: &str, map: &Map, starts: &[u32]) -> Result<u64> {
+    if starts.is_empty() {
+        return Err(Error::msg("there are no nodes ending in A"));
+    }
+    let is_target = |h: u32| h % 36 == encode(b'Z');
+    let cycles = starts
+        .iter()
+        .map(|&start| target_cycle(turns, map, start, is_target))
+        .collect::<Result<Vec<_>>>()?;
+    let last_first_arrival = cycles
+        .iter()
+        .map(|&(first, _)| first)
+        .max()
+        .unwrap_or_default();
+    // In the puzzle inputs every ghost first reaches its target after exactly
+    // one cycle, so this is the lcm of the cycle lengths, but the arrivals
+    // needn't line up like that.
+    let (steps, period) =
+        crt(cycles).ok_or_else(|| Error::msg("the ghosts are never all on targets at once"))?;
+    if steps >= last_first_arrival {
+        Ok(steps)
+    } else {
+        Ok(steps + (last_first_arrival - steps).div_ceil(period) * period)
+    }
+}
+
+/// The number of steps until a ghost first reaches a target, and the interval
+/// at which it keeps reaching targets after that. In the puzzle inputs it
+/// comes back to the same target at the same point in the turns each time,
+/// but any walk that lands on targets at a regular interval will do.
+fn target_cycle<F>(turns: &str, map: &Map, start: u32, is_target: F) -> Result<(u64, u64)>
+where
+    F: Fn(u32) -> bool,
+{
+    let max_steps = max_steps(turns);
+    let turns = turns.as_bytes();
+    let mut current = start;
+    let mut turn = 0;
+    let mut first_arrival = None;
+    let mut interval = None;
+    let mut last_arrival = 0;
+    for steps in 1..=max_steps {
+        current = next(map, current, turns[turn]);
+        turn = if turn + 1 == turns.len() { 0 } else { turn + 1 };
+        if !is_target(current) {
+            continue;
+        }
+        match first_arrival {
+            None => first_arrival = Some((steps, current, turn)),
+            Some((first_steps, target, target_turn)) => {
+                let gap = steps - last_arrival;
+                if *interval.get_or_insert(gap) != gap {
+                    return Err(Error::msg("a ghost reaches targets at irregular intervals"));
+                }
+                // From here on the walk repeats, and with it the arrivals.
+                if current == target && turn == target_turn {
+                    return Ok((first_steps, gap));
+                }
+            }
+        }
+        last_arrival = steps;
+    }
+    match first_arrival {
+        None => Err(Error::msg("a ghost never reaches a target")),
+        Some(_) => Err(Error::msg("a ghost never comes back to its first target")),
+    }
+}
+
+fn steps_to_target<F>(turns: &str, map: &Map, start: u32, is_target: F) -> Option<u64>
+where
+    F: Fn(u32) -> bool,
+{
+    let max_steps = max_steps(turns);
+    let mut turns = turns.bytes().cycle();
+    let mut current = start;
+    (1..=max_steps).find(|_| {
+        current = next(map, current, turns.next().unwrap());
+        is_target(current)
+    })
+}
+
+/// The number of distinct states, a node and a point in the turns, so a walk
+/// that hasn't reached a target after this many steps is going in circles.
+fn max_steps(turns: &str) -> u64 {
+    (SIZE * turns.len()) as u64
+}
+
+fn next(map: &Map, current: u32, turn: u8) -> u32 {
+    let value = map[current as usize];
+    match turn {
+        b'L' => value >> 16,
+        b'R' => value & 0xFFFF,
+        b => panic!("invalid turn {b}"),
+    }
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u32 = 2023;
+    const DAY: u32 = 8;
+
+    type Input<'a> = (&'a str, Map, Vec<u32>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input.0, &input.1).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input.0, &input.1, &input.2).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PART_ONE_CASES: [(&str, u64); 3] = [
+        (INPUT, 20_093),
+        (include_str!("../examples/1.txt"), 2),
+        (include_str!("../examples/2.txt"), 6),
+    ];
+
+    const PART_TWO_CASES: [(&str, u64); 2] = [
+        (INPUT, 22_103_062_509_257),
+        (include_str!("../examples/3.txt"), 6),
+    ];
+
+    #[test]
+    fn test_part_one() {
+        for (input, expected) in PART_ONE_CASES {
+            let (turns, map, _) = parse_input(input).unwrap();
+            assert_eq!(part_one(turns, &map).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_part_two() {
+        for (input, expected) in PART_TWO_CASES {
+            let (turns, map, starts) = parse_input(input).unwrap();
+            assert_eq!(part_two(turns, &map, &starts).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_part_two_unaligned() {
+        // The first ghost is on BBZ after 1, 3, 5, ... steps and the second
+        // on FFZ after 2, 5, 8, ... steps.
+        let input = "L\n\n\
+            AAA = (BBZ, BBZ)\n\
+            BBZ = (CCC, CCC)\n\
+            CCC = (BBZ, BBZ)\n\
+            DDA = (EEE, EEE)\n\
+            EEE = (FFZ, FFZ)\n\
+            FFZ = (GGG, GGG)\n\
+            GGG = (HHH, HHH)\n\
+            HHH = (FFZ, FFZ)\n";
+        let (turns, map, starts) = parse_input(input).unwrap();
+        assert_eq!(part_two(turns, &map, &starts).unwrap(), 5);
+    }
+
+    #[test]
+    fn test_part_two_no_solution() {
+        // The first ghost is on BBZ after odd numbers of steps and the second
+        // on FFZ after even numbers of steps.
+        let input = "L\n\n\
+            AAA = (BBZ, BBZ)\n\
+            BBZ = (CCC, CCC)\n\
+            CCC = (BBZ, BBZ)\n\
+            DDA = (EEE, EEE)\n\
+            EEE = (FFZ, FFZ)\n\
+            FFZ = (EEE, EEE)\n";
+        let (turns, map, starts) = parse_input(input).unwrap();
+        assert_eq!(
+            Day08::part_two(&(turns, map, starts)),
+            Answer::None(String::from("the ghosts are never all on targets at once"))
+        );
+        let (turns, map, _) = parse_input("L\n\nAAA = (AAA, AAA)\n").unwrap();
+        assert!(part_one(turns, &map).is_err());
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = parse_input("LR\n\nAAA = (BBB, ZZZ)\nZZZ = (ZZZ, ZZZ)\n").unwrap_err();
+        assert_eq!((error.line(), error.column()), (3, 8));
+        assert_eq!(error.message(), "the node is not defined");
+    }
+}
@@ -0,0 +1,143 @@
+use aoc_common::{parse_str, Answer, ParseError, ParseResult, Result, Solution};
+use std::mem;
+
+pub const INPUT: &str = include_str!("../input.txt");
+
+#[derive(Debug)]
+pub struct Input {
+    history_values: Vec<i32>,
+    history_length: usize,
+}
+
+impl Input {
+    pub fn histories(&self) -> impl Iterator<Item = &[i32]> {
+        self.history_values.chunks_exact(self.history_length)
+    }
+}
+
+pub fn parse_input(input: &str) -> ParseResult<Input> {
+    let mut history_values = vec![];
+    let mut history_length = 0;
+    for (i, line) in input.lines().enumerate() {
+        let line_start = history_values.len();
+        for s in line.split_ascii_whitespace() {
+            history_values.push(parse_str::<i32>(input, s)?);
+        }
+        let length = history_values.len() - line_start;
+        if i == 0 {
+            history_length = length;
+        }
+        if length == 0 {
+            return Err(ParseError::new(input, line, "expected a history"));
+        }
+        // The histories are stored back to back, so they must all be the
+        // same length.
+        if length != history_length {
+            let message = format!("expected {history_length} values like the first history");
+            return Err(ParseError::new(input, line, message));
+        }
+    }
+    if history_length == 0 {
+        return Err(ParseError::at_end(input, "expected a history"));
+    }
+    Ok(Input {
+        history_values,
+        history_length,
+    })
+}
+
+pub fn part_one(input: &Input) -> i32 {
+    let mut sequence = Vec::with_capacity(input.history_length);
+    let mut next_sequence = Vec::with_capacity(input.history_length);
+    input
+        .histories()
+        .map(|history| {
+            sequence.clear();
+            sequence.extend_from_slice(history);
+            let mut sum = *history.last().unwrap();
+            while sequence.iter().any(|&value| value != sequence[0]) {
+                next_sequence.clear();
+                next_sequence.extend(sequence.windows(2).map(|window| window[1] - window[0]));
+                sum += *next_sequence.last().unwrap();
+                mem::swap(&mut sequence, &mut next_sequence);
+            }
+            sum
+        })
+        .sum()
+}
+
+pub fn part_two(input: &Input) -> i32 {
+    let mut sequence = Vec::with_capacity(input.history_length);
+    let mut next_sequence = Vec::with_capacity(input.history_length);
+    let mut firsts = Vec::with_capacity(input.history_length);
+    input
+        .histories()
+        .map(|history| {
+            sequence.clear();
+            sequence.extend_from_slice(history);
+            firsts.clear();
+            firsts.push(*history.first().unwrap());
+            while sequence.iter().any(|&value| value != sequence[0]) {
+                next_sequence.clear();
+                next_sequence.extend(sequence.windows(2).map(|window| window[1] - window[0]));
+                firsts.push(next_sequence[0]);
+                mem::swap(&mut sequence, &mut next_sequence);
+            }
+            firsts
+                .iter()
+                .copied()
+                .rev()
+                .reduce(|accumulator, element| element - accumulator)
+                .unwrap()
+        })
+        .sum()
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: u32 = 2023;
+    const DAY: u32 = 9;
+
+    type Input<'a> = Input;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PART_ONE_CASES: [(&str, i32); 2] = [
+        (INPUT, 1_916_822_650),
+        (include_str!("../examples/1.txt"), 114),
+    ];
+
+    const PART_TWO_CASES: [(&str, i32); 2] = [(INPUT, 966), (include_str!("../examples/1.txt"), 2)];
+
+    #[test]
+    fn test_part_one() {
+        for (input, expected) in PART_ONE_CASES {
+            let input = parse_input(input).unwrap();
+            assert_eq!(part_one(&input), expected);
+        }
+    }
+
+    #[test]
+    fn test_part_two() {
+        for (input, expected) in PART_TWO_CASES {
+            let input = parse_input(input).unwrap();
+            assert_eq!(part_two(&input), expected);
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use year_2023_day_10::INPUT;
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let input = year_2023_day_10::parse_input(INPUT).unwrap();
+    let pipe_tiles = year_2023_day_10::find_pipe_tiles(&input);
+
+    c.bench_function("year_2023_day_10::parse_input", |b| {
+        b.iter(|| year_2023_day_10::parse_input(black_box(INPUT)));
+    });
+
+    c.bench_function("year_2023_day_10::find_pipe_tiles", |b| {
+        b.iter(|| year_2023_day_10::find_pipe_tiles(black_box(&input)));
+    });
+
+    c.bench_function("year_2023_day_10::part_one", |b| {
+        b.iter(|| year_2023_day_10::part_one(black_box(&pipe_tiles)));
+    });
+
+    c.bench_function("year_2023_day_10::part_two", |b| {
+        b.iter(|| year_2023_day_10::part_two(black_box(&input), black_box(&pipe_tiles)));
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,220 @@
+use aoc_common::{Answer, Direction, Grid, ParseError, ParseResult, Position, Result, Solution};
+use std::cell::OnceCell;
+
+pub const INPUT: &str = include_str!("../input.txt");
+
+pub struct Input {
+    start: Position,
+    tiles: Grid<u8>,
+}
+
+pub struct PositionSet {
+    data: Grid<bool>,
+    len: usize,
+}
+
+impl PositionSet {
+    fn new(width: usize, height: usize) -> Self {
+        Self {
+            data: Grid::new(width, height, false),
+            len: 0,
+        }
+    }
+
+    fn contains(&self, position: Position) -> bool {
+        self.data[position]
+    }
+
+    fn insert(&mut self, position: Position) {
+        self.data[position] = true;
+        self.len += 1;
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+pub fn parse_input(input: &str) -> ParseResult<Input> {
+    let tiles = Grid::try_parse(input, |b| match b {
+        b'|' | b'-' | b'L' | b'J' | b'7' | b'F' | b'.' | b'S' => Ok(b),
+        _ => Err("expected a pipe, . or S"),
+    })?;
+    let start = tiles
+        .position(|&b| b == b'S')
+        .ok_or_else(|| ParseError::at_end(input, "there is no starting position S"))?;
+    Ok(Input { start, tiles })
+}
+
+pub fn find_pipe_tiles(input: &Input) -> PositionSet {
+    use Direction::*;
+    let tiles = &input.tiles;
+    let mut pipe_tiles = PositionSet::new(tiles.width(), tiles.height());
+    pipe_tiles.insert(input.start);
+    let (mut x, mut y) = input.start;
+    let mut facing = if x > 0 && b"-LF".contains(&tiles[(x - 1, y)]) {
+        x -= 1;
+        Left
+    } else if y > 0 && b"|7F".contains(&tiles[(x, y - 1)]) {
+        y -= 1;
+        Up
+    } else {
+        x += 1;
+        Right
+    };
+    let mut tile = tiles[(x, y)];
+    while tile != b'S' {
+        pipe_tiles.insert((x, y));
+        facing = match (tile, facing) {
+            (b'|', _) | (b'-', _) => facing,
+            (b'L', Down) => Right,
+            (b'L', Left) => Up,
+            (b'J', Down) => Left,
+            (b'J', Right) => Up,
+            (b'7', Up) => Left,
+            (b'7', Right) => Down,
+            (b'F', Up) => Right,
+            (b'F', Left) => Down,
+            (p, _) => panic!("invalid pipe {p}"),
+        };
+        match facing {
+            Up => y -= 1,
+            Right => x += 1,
+            Left => x -= 1,
+            Down => y += 1,
+        };
+        tile = tiles[(x, y)];
+    }
+    pipe_tiles
+}
+
+pub fn part_one(pipe_tiles: &PositionSet) -> usize {
+    pipe_tiles.len() / 2
+}
+
+pub fn part_two(input: &Input, pipe_tiles: &PositionSet) -> usize {
+    let mut enclosed_tile_count = 0;
+    for (y, line) in input.tiles.rows().enumerate() {
+        let mut blocked_top = false;
+        let mut blocked_bottom = false;
+        let mut inside = false;
+        for (x, &b) in line.iter().enumerate() {
+            if !pipe_tiles.contains((x, y)) {
+                blocked_top = false;
+                blocked_bottom = false;
+                if inside {
+                    enclosed_tile_count += 1;
+                }
+                continue;
+            }
+            let connected_left = b"-7J".contains(&b)
+                || (b == b'S'
+                    && x > 0
+                    && pipe_tiles.contains((x - 1, y))
+                    && b"-LF".contains(&line[x - 1]));
+            let connected_up = b"|JL".contains(&b)
+                || (b == b'S'
+                    && y > 0
+                    && pipe_tiles.contains((x, y - 1))
+                    && b"|7F".contains(&input.tiles[(x, y - 1)]));
+            let connected_down = b"|7F".contains(&b)
+                || (b == b'S'
+                    && pipe_tiles.contains((x, y + 1))
+                    && b"|LJ".contains(&input.tiles[(x, y + 1)]));
+            if connected_left {
+                blocked_top |= connected_up;
+                blocked_bottom |= connected_down;
+            } else {
+                blocked_top = connected_up;
+                blocked_bottom = connected_down;
+            }
+            if blocked_top && blocked_bottom {
+                blocked_top = false;
+                blocked_bottom = false;
+                inside = !inside;
+            }
+        }
+    }
+    enclosed_tile_count
+}
+
+pub struct Sketch {
+    input: Input,
+    pipe_tiles: OnceCell<PositionSet>,
+}
+
+impl Sketch {
+    /// The tiles of the loop, found by `preprocess` or else on first use.
+    fn pipe_tiles(&self) -> &PositionSet {
+        self.pipe_tiles.get_or_init(|| find_pipe_tiles(&self.input))
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u32 = 2023;
+    const DAY: u32 = 10;
+
+    type Input<'a> = Sketch;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(Sketch {
+            input: parse_input(input)?,
+            pipe_tiles: OnceCell::new(),
+        })
+    }
+
+    fn preprocess(sketch: &mut Self::Input<'_>) {
+        sketch.pipe_tiles();
+    }
+
+    fn part_one(sketch: &Self::Input<'_>) -> Answer {
+        part_one(sketch.pipe_tiles()).into()
+    }
+
+    fn part_two(sketch: &Self::Input<'_>) -> Answer {
+        part_two(&sketch.input, sketch.pipe_tiles()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PART_ONE_CASES: [(&str, usize); 3] = [
+        (INPUT, 6613),
+        (include_str!("../examples/1.txt"), 4),
+        (include_str!("../examples/2.txt"), 8),
+    ];
+
+    const PART_TWO_CASES: [(&str, usize); 3] = [
+        (INPUT, 511),
+        (include_str!("../examples/1.txt"), 1),
+        (include_str!("../examples/3.txt"), 4),
+    ];
+
+    #[test]
+    fn test_without_preprocess() {
+        let sketch = Day10::parse(include_str!("../examples/2.txt")).unwrap();
+        assert_eq!(Day10::part_one(&sketch), Answer::from(8));
+    }
+
+    #[test]
+    fn test_part_one() {
+        for (input, expected) in PART_ONE_CASES {
+            let input = parse_input(input).unwrap();
+            let pipe_tiles = find_pipe_tiles(&input);
+            assert_eq!(part_one(&pipe_tiles), expected);
+        }
+    }
+
+    #[test]
+    fn test_part_two() {
+        for (input, expected) in PART_TWO_CASES {
+            let input = parse_input(input).unwrap();
+            let pipe_tiles = find_pipe_tiles(&input);
+            assert_eq!(part_two(&input, &pipe_tiles), expected);
+        }
+    }
+}
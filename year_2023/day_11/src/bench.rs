@@ -0,0 +1,27 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use year_2023_day_11::INPUT;
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let base_positions = year_2023_day_11::parse_input(INPUT).unwrap();
+    let (part_one_positions, part_two_positions) =
+        year_2023_day_11::expand_positions(&base_positions);
+
+    c.bench_function("year_2023_day_11::parse_input", |b| {
+        b.iter(|| year_2023_day_11::parse_input(black_box(INPUT)));
+    });
+
+    c.bench_function("year_2023_day_11::expand_positions", |b| {
+        b.iter(|| year_2023_day_11::expand_positions(black_box(&base_positions)));
+    });
+
+    c.bench_function("year_2023_day_11::part_one", |b| {
+        b.iter(|| year_2023_day_11::part_one(black_box(&part_one_positions)));
+    });
+
+    c.bench_function("year_2023_day_11::part_two", |b| {
+        b.iter(|| year_2023_day_11::part_two(black_box(&part_two_positions)));
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
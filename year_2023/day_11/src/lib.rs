@@ -0,0 +1,177 @@
+use aoc_common::{Answer, ParseError, ParseResult, Result, Solution};
+use std::cell::OnceCell;
+
+pub const INPUT: &str = include_str!("../input.txt");
+
+pub type Position = (usize, usize);
+
+pub fn parse_input(input: &str) -> ParseResult<Vec<Position>> {
+    let mut positions = vec![];
+    for (y, line) in input.lines().enumerate() {
+        for (x, b) in line.bytes().enumerate() {
+            match b {
+                b'#' => positions.push((x, y)),
+                b'.' => {}
+                _ => return Err(ParseError::in_span(input, line, x, "expected # or .")),
+            }
+        }
+    }
+    Ok(positions)
+}
+
+pub fn part_one(positions: &[Position]) -> usize {
+    let mut sum = 0;
+    for &position @ (x, y) in positions {
+        for &other_position @ (other_x, other_y) in positions {
+            if position < other_position {
+                sum += x.abs_diff(other_x) + y.abs_diff(other_y);
+            }
+        }
+    }
+    sum
+}
+
+pub fn part_two(positions: &[Position]) -> usize {
+    let mut sum = 0;
+    for &position @ (x, y) in positions {
+        for &other_position @ (other_x, other_y) in positions {
+            if position < other_position {
+                sum += x.abs_diff(other_x) + y.abs_diff(other_y);
+            }
+        }
+    }
+    sum
+}
+
+pub fn expand_positions(positions: &[(usize, usize)]) -> (Vec<Position>, Vec<Position>) {
+    let max_x = *positions.iter().map(|(x, _)| x).max().unwrap();
+    let max_y = *positions.iter().map(|(_, y)| y).max().unwrap();
+    let mut column_has_galaxy = vec![false; max_x + 1];
+    let mut row_has_galaxy = vec![false; max_y + 1];
+    for &(x, y) in positions {
+        column_has_galaxy[x] = true;
+        row_has_galaxy[y] = true;
+    }
+    let mut column_expansion_amounts = Vec::with_capacity(max_x + 1);
+    let mut expansion_amount = 0;
+    for has_galaxy in column_has_galaxy {
+        if !has_galaxy {
+            expansion_amount += 1;
+        }
+        column_expansion_amounts.push(expansion_amount);
+    }
+    let mut row_expansion_amounts = Vec::with_capacity(max_y + 1);
+    expansion_amount = 0;
+    for has_galaxy in row_has_galaxy {
+        if !has_galaxy {
+            expansion_amount += 1;
+        }
+        row_expansion_amounts.push(expansion_amount);
+    }
+    let part_one_positions = positions
+        .iter()
+        .map(|&(x, y)| {
+            (
+                x + column_expansion_amounts[x],
+                y + row_expansion_amounts[y],
+            )
+        })
+        .collect();
+    for amount in column_expansion_amounts.iter_mut() {
+        *amount *= 999_999;
+    }
+    for amount in row_expansion_amounts.iter_mut() {
+        *amount *= 999_999;
+    }
+    let part_two_positions = positions
+        .iter()
+        .map(|&(x, y)| {
+            (
+                x + column_expansion_amounts[x],
+                y + row_expansion_amounts[y],
+            )
+        })
+        .collect();
+    (part_one_positions, part_two_positions)
+}
+
+pub struct Image {
+    positions: Vec<Position>,
+    expanded_positions: OnceCell<(Vec<Position>, Vec<Position>)>,
+}
+
+impl Image {
+    /// The positions expanded for each part, by `preprocess` or else on
+    /// first use.
+    fn expanded_positions(&self) -> &(Vec<Position>, Vec<Position>) {
+        self.expanded_positions
+            .get_or_init(|| expand_positions(&self.positions))
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u32 = 2023;
+    const DAY: u32 = 11;
+
+    type Input<'a> = Image;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(Image {
+            positions: parse_input(input)?,
+            expanded_positions: OnceCell::new(),
+        })
+    }
+
+    fn preprocess(image: &mut Self::Input<'_>) {
+        image.expanded_positions();
+    }
+
+    fn part_one(image: &Self::Input<'_>) -> Answer {
+        let (part_one_positions, _) = image.expanded_positions();
+        part_one(part_one_positions).into()
+    }
+
+    fn part_two(image: &Self::Input<'_>) -> Answer {
+        let (_, part_two_positions) = image.expanded_positions();
+        part_two(part_two_positions).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PART_ONE_CASES: [(&str, usize); 2] =
+        [(INPUT, 9_563_821), (include_str!("../examples/1.txt"), 374)];
+
+    const PART_TWO_CASES: [(&str, usize); 2] = [
+        (INPUT, 827_009_909_817),
+        (include_str!("../examples/1.txt"), 82_000_210),
+    ];
+
+    #[test]
+    fn test_without_preprocess() {
+        let image = Day11::parse(include_str!("../examples/1.txt")).unwrap();
+        assert_eq!(Day11::part_one(&image), Answer::from(374));
+    }
+
+    #[test]
+    fn test_part_one() {
+        for (input, expected) in PART_ONE_CASES {
+            let base_positions = parse_input(input).unwrap();
+            let (positions, _) = expand_positions(&base_positions);
+            assert_eq!(part_one(&positions), expected);
+        }
+    }
+
+    #[test]
+    fn test_part_two() {
+        for (input, expected) in PART_TWO_CASES {
+            let base_positions = parse_input(input).unwrap();
+            let (_, positions) = expand_positions(&base_positions);
+            assert_eq!(part_two(&positions), expected);
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use aoc_common::{parse_str, Answer, ParseError, ParseResult, Result, Solution};
+
+pub const INPUT: &str = include_str!("../input.txt");
+
+pub fn parse_input(input: &str) -> ParseResult<Vec<(&str, Vec<usize>)>> {
+    input
+        .lines()
+        .map(|line| {
+            let (row, group_sizes) = line.split_once(' ').ok_or_else(|| {
+                ParseError::new(input, line, "expected a row like \"???.### 1,1,3\"")
+            })?;
+            if let Some(i) = row.bytes().position(|b| !b"?#.".contains(&b)) {
+                return Err(ParseError::in_span(input, row, i, "expected ?, # or ."));
+            }
+            let group_sizes = group_sizes
+                .split(',')
+                .map(|s| parse_str(input, s))
+                .collect::<ParseResult<_>>()?;
+            Ok((row, group_sizes))
+        })
+        .collect()
+}
+
+pub fn part_one(input: &[(&str, Vec<usize>)]) -> usize {
+    let mut arrangement_count = 0;
+    for (row, specified_group_sizes) in input {
+        let mut candidates = vec![vec![]];
+        for b in row.bytes() {
+            dbg!(candidates.len());
+            if b == b'?' {
+                let mut candidates_with_damaged = candidates.clone();
+                for candidate in candidates_with_damaged.iter_mut() {
+                    candidate.push(b'#');
+                }
+                for candidate in candidates.iter_mut() {
+                    candidate.push(b'.');
+                }
+                candidates.extend(candidates_with_damaged);
+                candidates.retain(|candidate| {
+                    let candidate_group_sizes = group_sizes(candidate);
+                    for (candidate_group_size, specified_group_size) in candidate_group_sizes
+                        .iter()
+                        .zip(specified_group_sizes.iter())
+                    {
+                        if candidate_group_size == specified_group_size {
+                            continue;
+                        }
+                        return *candidate.last().unwrap() == b'#'
+                            && candidate_group_size < specified_group_size;
+                    }
+                    return true;
+                });
+            } else {
+                for candidate in candidates.iter_mut() {
+                    candidate.push(b);
+                }
+            }
+        }
+        arrangement_count += candidates
+            .iter()
+            .filter(|c| &group_sizes(c) == specified_group_sizes)
+            .count();
+    }
+    arrangement_count
+}
+
+fn group_sizes(bytes: &[u8]) -> Vec<usize> {
+    let mut group_sizes = vec![];
+    let mut current_group_size = 0;
+    for &b in bytes {
+        if b == b'#' {
+            current_group_size += 1;
+        } else if current_group_size > 0 {
+            group_sizes.push(current_group_size);
+            current_group_size = 0;
+        }
+    }
+    if current_group_size > 0 {
+        group_sizes.push(current_group_size);
+    }
+    group_sizes
+}
+
+pub fn part_two(input: &[(&str, Vec<usize>)]) -> u32 {
+    todo!()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u32 = 2023;
+    const DAY: u32 = 12;
+
+    type Input<'a> = Vec<(&'a str, Vec<usize>)>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = parse_input(INPUT).unwrap();
+        assert_eq!(part_one(&input), todo!());
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = parse_input(INPUT).unwrap();
+        assert_eq!(part_two(&input), todo!());
+    }
+}
@@ -0,0 +1,109 @@
+use aoc_common::{parse_str, Answer, ParseError, ParseResult, Result, Solution};
+use std::collections::HashMap;
+
+pub const INPUT: &str = include_str!("../input.txt");
+
+pub struct Input {
+    left_list: Vec<u32>,
+    right_list: Vec<u32>,
+}
+
+pub fn parse_input(input: &str) -> ParseResult<Input> {
+    let mut left_list = vec![];
+    let mut right_list = vec![];
+    for line in input.lines() {
+        let mut parts = line.split_whitespace();
+        let (Some(left), Some(right), None) = (parts.next(), parts.next(), parts.next()) else {
+            return Err(ParseError::new(input, line, "expected two numbers"));
+        };
+        left_list.push(parse_str(input, left)?);
+        right_list.push(parse_str(input, right)?);
+    }
+    Ok(Input {
+        left_list,
+        right_list,
+    })
+}
+
+pub fn part_one(input: &Input) -> u32 {
+    let mut sorted_left_list = input.left_list.clone();
+    let mut sorted_right_list = input.right_list.clone();
+    sorted_left_list.sort_unstable();
+    sorted_right_list.sort_unstable();
+    sorted_left_list
+        .into_iter()
+        .zip(sorted_right_list)
+        .map(|(l, r)| l.abs_diff(r))
+        .sum()
+}
+
+pub fn part_two(input: &Input) -> u32 {
+    let mut right_counts: HashMap<u32, u32> = HashMap::new();
+    for &v in &input.right_list {
+        *right_counts.entry(v).or_insert(0) += 1;
+    }
+    input
+        .left_list
+        .iter()
+        .map(|l| l * right_counts.get(l).copied().unwrap_or(0))
+        .sum()
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 1;
+
+    type Input<'a> = Input;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PART_ONE_CASES: [(&str, u32); 2] =
+        [(INPUT, 1_666_427), (include_str!("../examples/1.txt"), 11)];
+
+    const PART_TWO_CASES: [(&str, u32); 2] =
+        [(INPUT, 24_316_233), (include_str!("../examples/1.txt"), 31)];
+
+    #[test]
+    fn test_part_one() {
+        for (input, expected) in PART_ONE_CASES {
+            let input = parse_input(input).unwrap();
+            assert_eq!(part_one(&input), expected);
+        }
+    }
+
+    #[test]
+    fn test_part_two() {
+        for (input, expected) in PART_TWO_CASES {
+            let input = parse_input(input).unwrap();
+            assert_eq!(part_two(&input), expected);
+        }
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = parse_input("3   4\n4   x3\n").err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 5: invalid digit found in string: \"x3\"\n  |\n2 | 4   x3\n  |     ^^"
+        );
+        let error = parse_input("3   4\n4\n").err().unwrap();
+        assert_eq!(error.message(), "expected two numbers");
+    }
+}